@@ -0,0 +1,35 @@
+//! Measures parsing text components from JSON, using a corpus of real-world components: server list MOTDs, death and advancement messages, and hover-heavy plugin output.
+//!
+//! Run with `cargo bench --bench chat_deserialize`. Collecting `extra` and `with` lists at their final size, parsing translation arguments and list-form components without going through `serde_json::Value`, and parsing colors without allocating reduced the allocations for the corpus from 479 to 298 and the time for `corpus_limited` from about 67 µs to 36 µs on the machine used at the time, while `corpus` stayed within noise since it's dominated by allocating the text of each component.
+
+use {
+    criterion::{
+        Criterion,
+        Throughput,
+        black_box,
+        criterion_group,
+        criterion_main,
+    },
+    minecraft::chat::Chat,
+};
+
+const CORPUS: &str = include_str!("chat_corpus.jsonl");
+
+fn from_json(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_json");
+    group.throughput(Throughput::Bytes(CORPUS.len() as u64));
+    group.bench_function("corpus", |b| b.iter(|| {
+        for line in CORPUS.lines() {
+            black_box(Chat::from_json(black_box(line)).expect("corpus contains valid components"));
+        }
+    }));
+    group.bench_function("corpus_limited", |b| b.iter(|| {
+        for line in CORPUS.lines() {
+            black_box(Chat::from_json_limited(black_box(line), 10_000).expect("corpus contains valid components"));
+        }
+    }));
+    group.finish();
+}
+
+criterion_group!(benches, from_json);
+criterion_main!(benches);
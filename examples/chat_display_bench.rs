@@ -0,0 +1,77 @@
+//! Counts the allocations made when formatting a large text component with [`Display`](std::fmt::Display), and times it.
+//!
+//! Run with `cargo run --release --example chat_display_bench`. This made 12 allocations while the JSON was serialized into a `String` first, and only the 2 growing the traversal stack since it's written directly into the formatter.
+
+use {
+    std::{
+        alloc::{
+            GlobalAlloc,
+            Layout,
+            System,
+        },
+        fmt::{
+            self,
+            Write as _,
+        },
+        sync::atomic::{
+            AtomicUsize,
+            Ordering::SeqCst,
+        },
+        time::Instant,
+    },
+    minecraft::chat::{
+        Chat,
+        ClickEvent,
+        HoverEvent,
+        NamedColor,
+    },
+};
+
+/// Counts the number of allocations.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, SeqCst);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Discards everything written to it, so only the allocations made by formatting are counted.
+struct Sink;
+
+impl fmt::Write for Sink {
+    fn write_str(&mut self, _: &str) -> fmt::Result { Ok(()) }
+}
+
+const ITERATIONS: u32 = 1000;
+
+fn main() {
+    let chat = Chat::from("Players online: ").with_extras((0..1000).map(|idx| Chat::from(format!("player{} ", idx))
+        .with_color(NamedColor::Gold)
+        .with_click(ClickEvent::SuggestCommand(format!("/msg player{} ", idx)))
+        .with_hover(HoverEvent::ShowText(Box::new(Chat::from("Click to send a private message"))))
+    ));
+    let before = ALLOCATIONS.load(SeqCst);
+    write!(Sink, "{}", chat).expect("failed to format text component");
+    let allocations = ALLOCATIONS.load(SeqCst) - before;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        write!(Sink, "{}", chat).expect("failed to format text component");
+    }
+    println!("{} bytes of JSON: {} allocations, {:?} per iteration", chat.to_string().len(), allocations, start.elapsed() / ITERATIONS);
+}
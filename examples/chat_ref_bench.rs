@@ -0,0 +1,40 @@
+//! Compares deserializing a large hover-heavy text component into [`Chat`] and [`ChatRef`].
+//!
+//! Run with `cargo run --release --example chat_ref_bench`.
+
+use {
+    std::time::{
+        Duration,
+        Instant,
+    },
+    minecraft::chat::{
+        Chat,
+        borrowed::ChatRef,
+    },
+};
+
+const ITERATIONS: u32 = 200;
+
+fn component_json(parts: usize) -> String {
+    let extra = (0..parts).map(|i| format!(
+        r#"{{"text":"Message part number {i} with some padding text","color":"gold","clickEvent":{{"action":"open_url","value":"https://wurstmineberg.de/people/{i}"}},"hoverEvent":{{"action":"show_text","contents":{{"text":"Hover text for part {i}","extra":[{{"translate":"chat.type.text","with":["player{i}","hello world"]}}]}}}}}}"#,
+    )).collect::<Vec<_>>();
+    format!(r#"{{"text":"","extra":[{}]}}"#, extra.join(","))
+}
+
+fn time(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS { f() }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let json = component_json(1000);
+    let owned = time(|| { Chat::from_json(&json).expect("failed to parse owned"); });
+    let borrowed = time(|| { serde_json::from_str::<ChatRef<'_>>(&json).expect("failed to parse borrowed"); });
+    let converted = time(|| { ChatRef::to_owned(&serde_json::from_str::<ChatRef<'_>>(&json).expect("failed to parse borrowed")); });
+    println!("input size: {} bytes, {} iterations", json.len(), ITERATIONS);
+    println!("Chat:                {:?}", owned);
+    println!("ChatRef:             {:?}", borrowed);
+    println!("ChatRef + to_owned:  {:?}", converted);
+}
@@ -0,0 +1,24 @@
+//! Checks that text components survive conversion to JSON and legacy formatting codes and back.
+//!
+//! Run with `cargo fuzz run chat_roundtrip` from the repository root.
+
+#![no_main]
+
+use {
+    libfuzzer_sys::fuzz_target,
+    minecraft::chat::Chat,
+};
+
+fuzz_target!(|chat: Chat| {
+    let json = chat.to_json().expect("generated components are serializable");
+    let parsed = Chat::from_json(&json).unwrap_or_else(|e| panic!("failed to parse {}: {}", json, e));
+    assert_eq!(parsed.to_json().expect("parsed components are serializable"), json);
+    // `§` in the text is read as a formatting code, so only the second round trip is guaranteed to be exact
+    let has_section_sign = chat.find(|component| component.text.contains('§')).is_some();
+    for &hex in &[false, true] {
+        let legacy = chat.to_legacy(hex);
+        let once = Chat::from_legacy(&legacy).to_legacy(hex);
+        if !has_section_sign { assert_eq!(once, legacy) }
+        assert_eq!(Chat::from_legacy(&once).to_legacy(hex), once);
+    }
+});
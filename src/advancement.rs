@@ -0,0 +1,141 @@
+//! This module contains types for [advancements](https://minecraft.fandom.com/wiki/Advancement).
+
+use {
+    std::collections::BTreeMap,
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::{
+        chat::{
+            self,
+            Chat,
+            NamedColor,
+        },
+        identifier::Identifier,
+        item::ItemStack,
+    },
+};
+
+/// The frame shape of an advancement's icon, which also determines the color and wording of its announcement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)] // variants are obvious
+pub enum FrameType {
+    #[default]
+    Task,
+    Goal,
+    Challenge,
+}
+
+impl FrameType {
+    /// The lowercase name used in advancement JSON, e.g. `task`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FrameType::Task => "task",
+            FrameType::Goal => "goal",
+            FrameType::Challenge => "challenge",
+        }
+    }
+
+    /// The color of the advancement title in chat announcements and toasts.
+    pub fn color(&self) -> NamedColor {
+        match self {
+            FrameType::Task | FrameType::Goal => NamedColor::Green,
+            FrameType::Challenge => NamedColor::DarkPurple,
+        }
+    }
+}
+
+fn default_true() -> bool { true }
+
+/// How an advancement is shown in the advancements screen, toasts, and chat, as in the `display` object of advancement JSON.
+///
+/// The title and description may also be given as plain strings.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AdvancementDisplay {
+    /// The item shown in the frame. Icons in the format used before 1.20.5 (`{item, nbt}`) are read without their NBT.
+    pub icon: ItemStack,
+    /// The name of the advancement.
+    #[serde(deserialize_with = "chat::deserialize_text_component")]
+    pub title: Chat,
+    /// The text shown below the title in the advancements screen and on hover in chat.
+    #[serde(deserialize_with = "chat::deserialize_text_component")]
+    pub description: Chat,
+    /// The shape of the icon frame.
+    #[serde(default)]
+    pub frame: FrameType,
+    /// The background texture of the tab, only used for root advancements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<Identifier>,
+    /// Whether a toast is shown when the advancement is completed.
+    #[serde(default = "default_true")]
+    pub show_toast: bool,
+    /// Whether completing the advancement is announced in chat.
+    #[serde(default = "default_true")]
+    pub announce_to_chat: bool,
+    /// Whether the advancement is hidden in the advancements screen until completed.
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+/// What the player gets for completing an advancement, as in the `rewards` object of advancement JSON.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+pub struct AdvancementRewards {
+    /// The amount of experience points awarded.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub experience: i32,
+    /// Loot tables which are rolled and given to the player.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub loot: Vec<Identifier>,
+    /// Recipes which are unlocked.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipes: Vec<Identifier>,
+    /// A function which is run as the player.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function: Option<Identifier>,
+}
+
+fn is_zero(n: &i32) -> bool { *n == 0 }
+
+/// An advancement as defined in a data pack.
+///
+/// Criteria conditions are kept as raw JSON since their schema depends on the trigger.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Advancement {
+    /// The parent advancement. Advancements without a parent are the roots of tabs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Identifier>,
+    /// How the advancement is shown. Advancements without a display are invisible, e.g. recipe unlocks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<AdvancementDisplay>,
+    /// The criteria by name, each an object with a `trigger` and optional `conditions`.
+    pub criteria: BTreeMap<String, serde_json::Value>,
+    /// Which criteria must be completed: all of the inner lists, each by completing any one of its criteria.
+    ///
+    /// If empty, all criteria are required.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requirements: Vec<Vec<String>>,
+    /// What the player gets for completing the advancement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rewards: Option<AdvancementRewards>,
+    /// Whether completing the advancement sends a telemetry event.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub sends_telemetry_event: bool,
+}
+
+impl Advancement {
+    /// The effective requirements, i.e. [`requirements`](Advancement::requirements), or each criterion on its own if that's empty.
+    pub fn effective_requirements(&self) -> Vec<Vec<String>> {
+        if self.requirements.is_empty() {
+            self.criteria.keys().map(|name| vec![name.clone()]).collect()
+        } else {
+            self.requirements.clone()
+        }
+    }
+
+    /// Whether the advancement is completed given the names of the completed criteria.
+    pub fn is_completed(&self, mut completed: impl FnMut(&str) -> bool) -> bool {
+        self.effective_requirements().iter().all(|any| any.iter().any(|name| completed(name)))
+    }
+}
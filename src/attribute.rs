@@ -0,0 +1,420 @@
+//! This module contains types for [attributes](https://minecraft.fandom.com/wiki/Attribute) and attribute modifiers.
+
+use {
+    std::{
+        convert::TryFrom,
+        fmt,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::{
+            self,
+            Visitor,
+        },
+        ser::SerializeMap,
+    },
+    uuid::Uuid,
+    crate::{
+        identifier::Identifier,
+        util::uuid_ints,
+    },
+};
+
+registry_enum! {
+    /// An attribute, identified by its namespaced ID.
+    ///
+    /// The `generic.`, `player.`, and `zombie.` prefixes used before 1.21.2 are accepted when parsing.
+    #[allow(missing_docs)] // variants are obvious
+    pub enum Attribute {
+        Armor => "armor" | "generic.armor",
+        ArmorToughness => "armor_toughness" | "generic.armor_toughness",
+        AttackDamage => "attack_damage" | "generic.attack_damage",
+        AttackKnockback => "attack_knockback" | "generic.attack_knockback",
+        AttackSpeed => "attack_speed" | "generic.attack_speed",
+        BlockBreakSpeed => "block_break_speed" | "player.block_break_speed",
+        BlockInteractionRange => "block_interaction_range" | "player.block_interaction_range",
+        BurningTime => "burning_time" | "generic.burning_time",
+        ExplosionKnockbackResistance => "explosion_knockback_resistance" | "generic.explosion_knockback_resistance",
+        EntityInteractionRange => "entity_interaction_range" | "player.entity_interaction_range",
+        FallDamageMultiplier => "fall_damage_multiplier" | "generic.fall_damage_multiplier",
+        FlyingSpeed => "flying_speed" | "generic.flying_speed",
+        FollowRange => "follow_range" | "generic.follow_range",
+        Gravity => "gravity" | "generic.gravity",
+        JumpStrength => "jump_strength" | "generic.jump_strength" | "horse.jump_strength",
+        KnockbackResistance => "knockback_resistance" | "generic.knockback_resistance",
+        Luck => "luck" | "generic.luck",
+        MaxAbsorption => "max_absorption" | "generic.max_absorption",
+        MaxHealth => "max_health" | "generic.max_health",
+        MiningEfficiency => "mining_efficiency" | "player.mining_efficiency",
+        MovementEfficiency => "movement_efficiency" | "generic.movement_efficiency",
+        MovementSpeed => "movement_speed" | "generic.movement_speed",
+        OxygenBonus => "oxygen_bonus" | "generic.oxygen_bonus",
+        SafeFallDistance => "safe_fall_distance" | "generic.safe_fall_distance",
+        Scale => "scale" | "generic.scale",
+        SneakingSpeed => "sneaking_speed" | "player.sneaking_speed",
+        SpawnReinforcements => "spawn_reinforcements" | "zombie.spawn_reinforcements",
+        StepHeight => "step_height" | "generic.step_height",
+        SubmergedMiningSpeed => "submerged_mining_speed" | "player.submerged_mining_speed",
+        SweepingDamageRatio => "sweeping_damage_ratio" | "player.sweeping_damage_ratio",
+        TemptRange => "tempt_range",
+        WaterMovementEfficiency => "water_movement_efficiency" | "generic.water_movement_efficiency",
+    }
+}
+
+impl Attribute {
+    /// The default base value of this attribute, or `None` for [`Attribute::Other`].
+    ///
+    /// Some entities override this, e.g. players have a base attack damage of 1 and a base movement speed of 0.1.
+    pub fn default_base(&self) -> Option<f64> {
+        Some(match self {
+            Attribute::Armor | Attribute::ArmorToughness | Attribute::AttackKnockback | Attribute::ExplosionKnockbackResistance
+            | Attribute::KnockbackResistance | Attribute::Luck | Attribute::MaxAbsorption | Attribute::MiningEfficiency
+            | Attribute::MovementEfficiency | Attribute::OxygenBonus | Attribute::SpawnReinforcements | Attribute::SweepingDamageRatio
+            | Attribute::WaterMovementEfficiency => 0.0,
+            Attribute::AttackDamage => 2.0,
+            Attribute::AttackSpeed => 4.0,
+            Attribute::BlockBreakSpeed | Attribute::BurningTime | Attribute::FallDamageMultiplier | Attribute::Scale => 1.0,
+            Attribute::BlockInteractionRange => 4.5,
+            Attribute::EntityInteractionRange | Attribute::SafeFallDistance => 3.0,
+            Attribute::FlyingSpeed => 0.4,
+            Attribute::FollowRange => 32.0,
+            Attribute::Gravity => 0.08,
+            Attribute::JumpStrength => 0.42,
+            Attribute::MaxHealth => 20.0,
+            Attribute::MovementSpeed => 0.7,
+            Attribute::SneakingSpeed => 0.3,
+            Attribute::StepHeight => 0.6,
+            Attribute::SubmergedMiningSpeed => 0.2,
+            Attribute::TemptRange => 10.0,
+            Attribute::Other(_) => return None,
+        })
+    }
+}
+
+/// How an [attribute modifier](AttributeModifier) changes the attribute's value.
+///
+/// Serializes as the snake_case name. Deserializes from the name or the numeric ID used before 1.20.5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    /// Adds the amount to the base value.
+    AddValue,
+    /// Adds the base value multiplied by the amount.
+    AddMultipliedBase,
+    /// Multiplies the value by one plus the amount, after all other operations.
+    AddMultipliedTotal,
+}
+
+impl Operation {
+    /// The numeric ID used in NBT before 1.20.5.
+    pub fn id(&self) -> i32 {
+        match self {
+            Operation::AddValue => 0,
+            Operation::AddMultipliedBase => 1,
+            Operation::AddMultipliedTotal => 2,
+        }
+    }
+
+    /// Looks up an operation by the numeric ID used in NBT before 1.20.5.
+    pub fn from_id(id: i32) -> Option<Operation> {
+        match id {
+            0 => Some(Operation::AddValue),
+            1 => Some(Operation::AddMultipliedBase),
+            2 => Some(Operation::AddMultipliedTotal),
+            _ => None,
+        }
+    }
+
+    /// The snake_case name used since 1.20.5, e.g. `add_value`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Operation::AddValue => "add_value",
+            Operation::AddMultipliedBase => "add_multiplied_base",
+            Operation::AddMultipliedTotal => "add_multiplied_total",
+        }
+    }
+}
+
+impl Serialize for Operation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+struct OperationVisitor;
+
+impl<'de> Visitor<'de> for OperationVisitor {
+    type Value = Operation;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an attribute modifier operation name or numeric ID")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Operation, E> {
+        [Operation::AddValue, Operation::AddMultipliedBase, Operation::AddMultipliedTotal].iter()
+            .find(|operation| operation.name() == v)
+            .copied()
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Operation, E> {
+        i32::try_from(v).ok().and_then(Operation::from_id).ok_or_else(|| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Operation, E> {
+        i32::try_from(v).ok().and_then(Operation::from_id).ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Operation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Operation, D::Error> {
+        deserializer.deserialize_any(OperationVisitor)
+    }
+}
+
+/// The equipment slots in which an item must be for its attribute modifiers to apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)] // variants are obvious
+pub enum EquipmentSlotGroup {
+    #[default]
+    Any,
+    MainHand,
+    OffHand,
+    Hand,
+    Feet,
+    Legs,
+    Chest,
+    Head,
+    Armor,
+    Body,
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// Identifies an [`AttributeModifier`], so that modifiers with the same ID don't stack.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ModifierId {
+    /// A namespaced ID, used since 1.21.
+    Id(Identifier),
+    /// A UUID with a human-readable name, used before 1.21.
+    Legacy {
+        /// The UUID identifying the modifier.
+        uuid: Uuid,
+        /// A name for the modifier, which has no effect in-game.
+        name: String,
+    },
+}
+
+impl From<Identifier> for ModifierId {
+    fn from(id: Identifier) -> ModifierId {
+        ModifierId::Id(id)
+    }
+}
+
+/// An attribute modifier, as stored on entities and, together with an [`Attribute`], on items.
+///
+/// The serialization format depends on the [`ModifierId`]: modifiers with a namespaced ID use the format introduced in 1.21 (`id`, `amount`, `operation`), while modifiers with a UUID use the earlier NBT format (`UUID`, `Name`, `Amount`, `Operation`). Deserialization accepts either, as well as the 1.20.5 format (`uuid`, `name`) and the pre-1.16 `UUIDMost`/`UUIDLeast` pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeModifier {
+    /// Identifies this modifier, so that modifiers with the same ID don't stack.
+    pub id: ModifierId,
+    /// The amount by which to change the attribute.
+    pub amount: f64,
+    /// How the amount is applied.
+    pub operation: Operation,
+    /// The slots in which the item applies this modifier. Not used for modifiers on entities.
+    pub slot: EquipmentSlotGroup,
+}
+
+impl AttributeModifier {
+    /// Creates a modifier with a namespaced ID which applies in any slot.
+    pub fn new(id: impl Into<ModifierId>, amount: f64, operation: Operation) -> AttributeModifier {
+        AttributeModifier {
+            id: id.into(),
+            slot: EquipmentSlotGroup::Any,
+            amount, operation,
+        }
+    }
+
+    fn serialize_entries<M: SerializeMap>(&self, map: &mut M) -> Result<(), M::Error> {
+        #[derive(Serialize)]
+        #[serde(transparent)]
+        struct UuidInts<'a>(#[serde(with = "uuid_ints")] &'a Uuid);
+
+        match &self.id {
+            ModifierId::Id(id) => {
+                map.serialize_entry("id", id)?;
+                map.serialize_entry("amount", &self.amount)?;
+                map.serialize_entry("operation", &self.operation)?;
+                if !is_default(&self.slot) { map.serialize_entry("slot", &self.slot)? }
+            }
+            ModifierId::Legacy { uuid, name } => {
+                map.serialize_entry("UUID", &UuidInts(uuid))?;
+                map.serialize_entry("Name", name)?;
+                map.serialize_entry("Amount", &self.amount)?;
+                map.serialize_entry("Operation", &self.operation.id())?;
+                if !is_default(&self.slot) { map.serialize_entry("Slot", &self.slot)? }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for AttributeModifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        self.serialize_entries(&mut map)?;
+        map.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(transparent)]
+struct UuidRepr(#[serde(with = "uuid_ints")] Uuid);
+
+#[derive(Deserialize)]
+struct AttributeModifierRepr {
+    id: Option<Identifier>,
+    #[serde(rename = "UUID", alias = "uuid")]
+    uuid: Option<UuidRepr>,
+    #[serde(rename = "UUIDMost")]
+    uuid_most: Option<i64>,
+    #[serde(rename = "UUIDLeast")]
+    uuid_least: Option<i64>,
+    #[serde(alias = "Name")]
+    name: Option<String>,
+    #[serde(alias = "Amount")]
+    amount: f64,
+    #[serde(alias = "Operation")]
+    operation: Operation,
+    #[serde(default, alias = "Slot")]
+    slot: EquipmentSlotGroup,
+}
+
+impl TryFrom<AttributeModifierRepr> for AttributeModifier {
+    type Error = &'static str;
+
+    fn try_from(AttributeModifierRepr { id, uuid, uuid_most, uuid_least, name, amount, operation, slot }: AttributeModifierRepr) -> Result<AttributeModifier, &'static str> {
+        let id = if let Some(id) = id {
+            ModifierId::Id(id)
+        } else {
+            let uuid = match (uuid, uuid_most, uuid_least) {
+                (Some(UuidRepr(uuid)), _, _) => uuid,
+                (None, Some(most), Some(least)) => Uuid::from_u128(u128::from(most as u64) << 64 | u128::from(least as u64)),
+                (None, _, _) => return Err("attribute modifier has neither id nor UUID"),
+            };
+            ModifierId::Legacy { uuid, name: name.unwrap_or_default() }
+        };
+        Ok(AttributeModifier { id, amount, operation, slot })
+    }
+}
+
+impl<'de> Deserialize<'de> for AttributeModifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<AttributeModifier, D::Error> {
+        AttributeModifier::try_from(AttributeModifierRepr::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Computes the value of an attribute from its base value and modifiers, in the same order as vanilla.
+///
+/// First, all [`Operation::AddValue`] amounts are added to the base. Then each [`Operation::AddMultipliedBase`] modifier adds that sum multiplied by its amount. Finally, the result is multiplied by one plus the amount of each [`Operation::AddMultipliedTotal`] modifier.
+///
+/// Unlike vanilla, modifiers with the same ID are not deduplicated and the result is not clamped to the attribute's valid range.
+///
+/// # Examples
+///
+/// A player holding a diamond sword:
+///
+/// ```
+/// use minecraft::{
+///     attribute::{
+///         self,
+///         AttributeModifier,
+///         Operation,
+///     },
+///     identifier::Identifier,
+/// };
+///
+/// let damage = AttributeModifier::new(Identifier::minecraft("base_attack_damage"), 6.0, Operation::AddValue);
+/// assert_eq!(attribute::apply(1.0, &[damage]), 7.0);
+/// let speed = AttributeModifier::new(Identifier::minecraft("base_attack_speed"), -2.4, Operation::AddValue);
+/// assert!((attribute::apply(4.0, &[speed]) - 1.6).abs() < 1e-9);
+/// ```
+pub fn apply<'a>(base: f64, modifiers: impl IntoIterator<Item = &'a AttributeModifier>) -> f64 {
+    let mut add = 0.0;
+    let mut multiply_base = 0.0;
+    let mut multiply_total = 1.0;
+    for modifier in modifiers {
+        match modifier.operation {
+            Operation::AddValue => add += modifier.amount,
+            Operation::AddMultipliedBase => multiply_base += modifier.amount,
+            Operation::AddMultipliedTotal => multiply_total *= 1.0 + modifier.amount,
+        }
+    }
+    let value = base + add;
+    (value + value * multiply_base) * multiply_total
+}
+
+/// An attribute modifier together with the attribute it modifies, as stored in the `attribute_modifiers` item component or the pre-1.20.5 `AttributeModifiers` item tag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemAttributeModifier {
+    /// The attribute this modifies.
+    pub attribute: Attribute,
+    /// The modifier.
+    pub modifier: AttributeModifier,
+}
+
+impl Serialize for ItemAttributeModifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        match self.modifier.id {
+            ModifierId::Id(_) => map.serialize_entry("type", &self.attribute)?,
+            ModifierId::Legacy { .. } => map.serialize_entry("AttributeName", &self.attribute)?,
+        }
+        self.modifier.serialize_entries(&mut map)?;
+        map.end()
+    }
+}
+
+#[derive(Deserialize)]
+struct ItemAttributeModifierRepr {
+    #[serde(rename = "type", alias = "AttributeName")]
+    attribute: Attribute,
+    #[serde(flatten)]
+    modifier: AttributeModifier,
+}
+
+impl<'de> Deserialize<'de> for ItemAttributeModifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ItemAttributeModifier, D::Error> {
+        let ItemAttributeModifierRepr { attribute, modifier } = ItemAttributeModifierRepr::deserialize(deserializer)?;
+        Ok(ItemAttributeModifier { attribute, modifier })
+    }
+}
+
+/// An attribute of an entity with its base value and active modifiers, as stored in entity NBT.
+///
+/// Serializes in the format used since 1.20.5 (`id`, `base`, `modifiers`). Deserialization also accepts the earlier format (`Name`, `Base`, `Modifiers`).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AttributeInstance {
+    /// The attribute.
+    #[serde(rename = "id", alias = "Name")]
+    pub attribute: Attribute,
+    /// The base value, before modifiers.
+    #[serde(alias = "Base")]
+    pub base: f64,
+    /// The modifiers currently applied, e.g. from equipment or effects.
+    #[serde(default, alias = "Modifiers", skip_serializing_if = "Vec::is_empty")]
+    pub modifiers: Vec<AttributeModifier>,
+}
+
+impl AttributeInstance {
+    /// The current value of the attribute, see [`apply`].
+    pub fn value(&self) -> f64 {
+        apply(self.base, &self.modifiers)
+    }
+}
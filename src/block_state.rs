@@ -0,0 +1,242 @@
+//! This module contains the [`BlockState`] type, representing a [block state](https://minecraft.fandom.com/wiki/Block_states).
+
+use {
+    std::{
+        collections::BTreeMap,
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::Error as _,
+    },
+    crate::identifier::{
+        Identifier,
+        IdentifierParseError,
+    },
+};
+
+/// A block together with values for some or all of its properties, like `minecraft:oak_stairs[facing=north,half=top]`.
+///
+/// Serializes as the string form used in commands by default; use [`nbt`] with `#[serde(with = "...")]` for the `{Name, Properties}` object form used in structure files and chunks. Deserialization accepts both forms.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::block_state::{
+///     BlockState,
+///     BlockStateParseError,
+/// };
+///
+/// let stairs = "minecraft:oak_stairs[facing=east,half=top]".parse::<BlockState>()?;
+/// assert_eq!(stairs.get("facing"), Some("east"));
+/// assert_eq!(stairs.get("half"), Some("top"));
+/// assert_eq!(stairs.to_string(), "minecraft:oak_stairs[facing=east,half=top]");
+/// assert_eq!(stairs.to_string().parse::<BlockState>()?, stairs);
+/// assert_eq!(serde_json::from_str::<BlockState>(&serde_json::to_string(&stairs)?)?, stairs);
+///
+/// assert_eq!("minecraft:oak_stairs[facing=east".parse::<BlockState>(), Err(BlockStateParseError::UnclosedBracket));
+/// assert_eq!("minecraft:oak_stairs[facing=east,facing=west]".parse::<BlockState>(), Err(BlockStateParseError::DuplicateProperty(format!("facing"))));
+/// assert_eq!("minecraft:oak_stairs[=east]".parse::<BlockState>(), Err(BlockStateParseError::MalformedProperty(format!("=east"))));
+/// assert_eq!("minecraft:oak_stairs[facing=]".parse::<BlockState>(), Err(BlockStateParseError::MalformedProperty(format!("facing="))));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BlockState {
+    /// The block ID.
+    pub block: Identifier,
+    /// The block state properties, which are always strings even if they represent booleans or numbers.
+    pub properties: BTreeMap<String, String>,
+}
+
+impl BlockState {
+    /// Creates a block state without any properties.
+    pub fn new(block: Identifier) -> BlockState {
+        BlockState {
+            properties: BTreeMap::default(),
+            block,
+        }
+    }
+
+    /// Adds or changes a property.
+    pub fn with(mut self, key: impl Into<String>, value: impl ToString) -> BlockState {
+        self.properties.insert(key.into(), value.to_string());
+        self
+    }
+
+    /// The value of the given property, if specified.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.properties.get(key).map(String::as_str)
+    }
+
+    /// The value of the given property, if specified and a boolean.
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key)?.parse().ok()
+    }
+
+    /// The value of the given property, if specified and an integer.
+    pub fn get_int(&self, key: &str) -> Option<i32> {
+        self.get(key)?.parse().ok()
+    }
+
+    /// The value of the given property parsed as another type, such as [`Direction`](crate::position::Direction), if specified and valid.
+    pub fn get_enum<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.get(key)?.parse().ok()
+    }
+
+    /// Whether this block state matches `other` as a block predicate, i.e. the blocks are the same and every property specified by `other` has the same value here.
+    ///
+    /// `other` may specify only a subset of the properties.
+    pub fn matches(&self, other: &BlockState) -> bool {
+        self.block == other.block && other.properties.iter().all(|(key, value)| self.properties.get(key) == Some(value))
+    }
+}
+
+impl From<Identifier> for BlockState {
+    fn from(block: Identifier) -> BlockState {
+        BlockState::new(block)
+    }
+}
+
+/// An error that can occur when parsing a [`BlockState`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockStateParseError {
+    /// The block ID was invalid.
+    Identifier(IdentifierParseError),
+    /// The property list was opened with `[` but not closed with `]`.
+    UnclosedBracket,
+    /// There was text after the closing `]`.
+    TrailingCharacters(String),
+    /// A property did not have the form `key=value`.
+    MalformedProperty(String),
+    /// The same property was specified more than once.
+    DuplicateProperty(String),
+}
+
+impl From<IdentifierParseError> for BlockStateParseError {
+    fn from(e: IdentifierParseError) -> BlockStateParseError {
+        BlockStateParseError::Identifier(e)
+    }
+}
+
+impl fmt::Display for BlockStateParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockStateParseError::Identifier(e) => write!(f, "invalid block ID: {}", e),
+            BlockStateParseError::UnclosedBracket => write!(f, "block state properties are missing a closing bracket"),
+            BlockStateParseError::TrailingCharacters(s) => write!(f, "unexpected text after block state properties: {:?}", s),
+            BlockStateParseError::MalformedProperty(s) => write!(f, "block state property is not of the form key=value: {:?}", s),
+            BlockStateParseError::DuplicateProperty(key) => write!(f, "block state property {:?} specified more than once", key),
+        }
+    }
+}
+
+impl std::error::Error for BlockStateParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BlockStateParseError::Identifier(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for BlockState {
+    type Err = BlockStateParseError;
+
+    fn from_str(s: &str) -> Result<BlockState, BlockStateParseError> {
+        let (block, properties) = if let Some((block, rest)) = s.split_once('[') {
+            let (properties, trailing) = rest.split_once(']').ok_or(BlockStateParseError::UnclosedBracket)?;
+            if !trailing.is_empty() { return Err(BlockStateParseError::TrailingCharacters(trailing.to_owned())) }
+            (block, Some(properties))
+        } else {
+            (s, None)
+        };
+        let mut state = BlockState::new(block.parse()?);
+        if let Some(properties) = properties.filter(|properties| !properties.trim().is_empty()) {
+            for property in properties.split(',') {
+                let (key, value) = property.split_once('=').ok_or_else(|| BlockStateParseError::MalformedProperty(property.to_owned()))?;
+                let (key, value) = (key.trim(), value.trim());
+                if key.is_empty() || value.is_empty() { return Err(BlockStateParseError::MalformedProperty(property.to_owned())) }
+                if state.properties.insert(key.to_owned(), value.to_owned()).is_some() { return Err(BlockStateParseError::DuplicateProperty(key.to_owned())) }
+            }
+        }
+        Ok(state)
+    }
+}
+
+impl fmt::Display for BlockState {
+    /// Formats in the bracket syntax, with properties sorted by key.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.block)?;
+        if !self.properties.is_empty() {
+            write!(f, "[")?;
+            for (i, (key, value)) in self.properties.iter().enumerate() {
+                if i > 0 { write!(f, ",")? }
+                write!(f, "{}={}", key, value)?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct NbtRepr {
+    #[serde(rename = "Name")]
+    block: Identifier,
+    #[serde(rename = "Properties", default, skip_serializing_if = "BTreeMap::is_empty")]
+    properties: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AnyRepr {
+    String(String),
+    Nbt(NbtRepr),
+}
+
+impl Serialize for BlockState {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<BlockState, D::Error> {
+        match AnyRepr::deserialize(deserializer)? {
+            AnyRepr::String(s) => s.parse().map_err(D::Error::custom),
+            AnyRepr::Nbt(NbtRepr { block, properties }) => Ok(BlockState { block, properties }),
+        }
+    }
+}
+
+/// Serde helpers for [`BlockState`] fields stored in the `{Name, Properties}` object form, as in structure files and chunk palettes.
+///
+/// Use with `#[serde(with = "minecraft::block_state::nbt")]`. Deserialization accepts the string form as well.
+pub mod nbt {
+    use {
+        serde::{
+            Deserialize as _,
+            Deserializer,
+            Serialize as _,
+            Serializer,
+        },
+        super::{
+            BlockState,
+            NbtRepr,
+        },
+    };
+
+    /// Serializes the block state as a `{Name, Properties}` object.
+    pub fn serialize<S: Serializer>(state: &BlockState, serializer: S) -> Result<S::Ok, S::Error> {
+        NbtRepr { block: state.block.clone(), properties: state.properties.clone() }.serialize(serializer)
+    }
+
+    /// Deserializes a block state from either form.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BlockState, D::Error> {
+        BlockState::deserialize(deserializer)
+    }
+}
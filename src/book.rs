@@ -0,0 +1,354 @@
+//! This module contains types for [written books and books and quills](https://minecraft.fandom.com/wiki/Book_and_Quill).
+
+use {
+    std::fmt,
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::{
+            self,
+            Unexpected,
+        },
+    },
+    crate::chat::{
+        self,
+        Chat,
+        ChatError,
+    },
+};
+
+/// The width of the text area of a book page in pixels of the default font.
+pub const PAGE_WIDTH: u32 = 114;
+
+/// The number of lines on a book page.
+pub const PAGE_LINES: usize = 14;
+
+/// How many times a written book has been copied.
+///
+/// Serializes as the numeric ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Generation {
+    /// The book as signed by its author.
+    #[default]
+    Original,
+    /// A copy of the original.
+    CopyOfOriginal,
+    /// A copy of a copy, which can't be copied further.
+    CopyOfCopy,
+    /// Not obtainable in survival, and can't be copied.
+    Tattered,
+}
+
+impl Generation {
+    /// All generations, in ID order.
+    pub const ALL: [Generation; 4] = [Generation::Original, Generation::CopyOfOriginal, Generation::CopyOfCopy, Generation::Tattered];
+
+    /// The numeric ID used in NBT and components.
+    pub fn id(&self) -> u8 {
+        match self {
+            Generation::Original => 0,
+            Generation::CopyOfOriginal => 1,
+            Generation::CopyOfCopy => 2,
+            Generation::Tattered => 3,
+        }
+    }
+
+    /// Looks up a generation by its numeric ID.
+    pub fn from_id(id: u8) -> Option<Generation> {
+        Generation::ALL.get(usize::from(id)).copied()
+    }
+
+    /// Whether a book of this generation can be copied in a crafting table.
+    pub fn can_be_copied(&self) -> bool {
+        matches!(self, Generation::Original | Generation::CopyOfOriginal)
+    }
+}
+
+impl Serialize for Generation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for Generation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Generation, D::Error> {
+        let id = u8::deserialize(deserializer)?;
+        Generation::from_id(id).ok_or_else(|| de::Error::invalid_value(Unexpected::Unsigned(id.into()), &"a book generation from 0 to 3"))
+    }
+}
+
+/// A value which may be given as `{"raw": …, "filtered": …}` in components, as used for text subject to the chat filter. Only the raw value is kept.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Filterable<T> {
+    Filtered { raw: T },
+    Plain(T),
+}
+
+impl<T> Filterable<T> {
+    fn into_raw(self) -> T {
+        match self {
+            Filterable::Filtered { raw } | Filterable::Plain(raw) => raw,
+        }
+    }
+}
+
+fn deserialize_filterable_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(Filterable::<String>::deserialize(deserializer)?.into_raw())
+}
+
+fn deserialize_filterable_strings<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    Ok(Vec::<Filterable<String>>::deserialize(deserializer)?.into_iter().map(Filterable::into_raw).collect())
+}
+
+fn deserialize_filterable_pages<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Chat>, D::Error> {
+    Vec::<serde_json::Value>::deserialize(deserializer)?.into_iter().map(|page| {
+        let page = match page {
+            serde_json::Value::Object(mut page) if page.contains_key("raw") => page.remove("raw").expect("just checked"),
+            page => page,
+        };
+        chat::deserialize_text_component(page).map_err(de::Error::custom)
+    }).collect()
+}
+
+/// The limits checked by [`WrittenBook::validate`].
+///
+/// The defaults are those of vanilla when signing a book in survival.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BookLimits {
+    /// The maximum number of pages.
+    pub max_pages: usize,
+    /// The maximum length of the JSON of each page, in UTF-16 code units like in Java.
+    pub max_page_json_len: usize,
+    /// The maximum length of the title, in UTF-16 code units.
+    pub max_title_len: usize,
+}
+
+impl Default for BookLimits {
+    fn default() -> BookLimits {
+        BookLimits {
+            max_pages: 100,
+            max_page_json_len: 1023,
+            max_title_len: 32,
+        }
+    }
+}
+
+/// An error found by [`WrittenBook::validate`].
+#[derive(Debug)]
+pub enum BookError {
+    /// The book has more pages than allowed.
+    TooManyPages(usize),
+    /// The JSON of the page at this 0-based index is longer than allowed.
+    PageTooLong {
+        /// The 0-based index of the page.
+        page: usize,
+        /// The length of the page's JSON.
+        len: usize,
+    },
+    /// The title is longer than allowed.
+    TitleTooLong(usize),
+    /// A page could not be serialized to JSON.
+    Chat(ChatError),
+}
+
+impl From<ChatError> for BookError {
+    fn from(e: ChatError) -> BookError {
+        BookError::Chat(e)
+    }
+}
+
+impl fmt::Display for BookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BookError::TooManyPages(pages) => write!(f, "book has too many pages ({})", pages),
+            BookError::PageTooLong { page, len } => write!(f, "page {} of book is too long ({} characters of JSON)", page + 1, len),
+            BookError::TitleTooLong(len) => write!(f, "book title is too long ({} characters)", len),
+            BookError::Chat(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for BookError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BookError::Chat(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+fn utf16_len(s: &str) -> usize {
+    s.encode_utf16().count()
+}
+
+/// A signed book, as in the `written_book_content` component.
+///
+/// Use [`legacy`] with `#[serde(with = "...")]` for the `tag` compound used before 1.20.5. Deserialization also accepts the `{"raw": …, "filtered": …}` form of the title and pages, but only the raw text is kept.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct WrittenBook {
+    /// The title shown on the tooltip and the signing screen.
+    #[serde(deserialize_with = "deserialize_filterable_string")]
+    pub title: String,
+    /// The name of the player who signed the book.
+    pub author: String,
+    /// How many times the book has been copied.
+    #[serde(default)]
+    pub generation: Generation,
+    /// The pages of the book.
+    #[serde(default, deserialize_with = "deserialize_filterable_pages", skip_serializing_if = "Vec::is_empty")]
+    pub pages: Vec<Chat>,
+    /// Whether entity selectors and scoreboard values in the pages have been resolved, which happens when the book is first opened.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub resolved: bool,
+}
+
+impl WrittenBook {
+    /// Creates an original, unresolved book with no pages.
+    pub fn new(title: impl Into<String>, author: impl Into<String>) -> WrittenBook {
+        WrittenBook {
+            title: title.into(),
+            author: author.into(),
+            ..WrittenBook::default()
+        }
+    }
+
+    /// Creates a book with no title or author whose pages contain the given text.
+    ///
+    /// The text is wrapped to the page width using the character widths of the default font, and split into pages of [`PAGE_LINES`] lines. Formatting is kept, but click and hover events are lost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::{
+    ///     book::WrittenBook,
+    ///     chat::Chat,
+    /// };
+    ///
+    /// let book = WrittenBook::paginate(&Chat::from("lorem ipsum ".repeat(100)));
+    /// assert_eq!(book.pages.len(), 5);
+    /// book.validate(&Default::default())?;
+    /// # Ok::<(), minecraft::book::BookError>(())
+    /// ```
+    pub fn paginate(content: &Chat) -> WrittenBook {
+        let lines = content.wrap_lines(PAGE_WIDTH);
+        WrittenBook {
+            pages: lines.chunks(PAGE_LINES).map(|lines| {
+                let mut page = Chat::default();
+                for (idx, line) in lines.iter().enumerate() {
+                    if idx > 0 { page.add_extra("\n"); }
+                    page.add_extra(line.clone());
+                }
+                page
+            }).collect(),
+            ..WrittenBook::default()
+        }
+    }
+
+    /// Checks the number of pages, the length of each page, and the length of the title against the given limits.
+    pub fn validate(&self, limits: &BookLimits) -> Result<(), BookError> {
+        let title_len = utf16_len(&self.title);
+        if title_len > limits.max_title_len { return Err(BookError::TitleTooLong(title_len)) }
+        if self.pages.len() > limits.max_pages { return Err(BookError::TooManyPages(self.pages.len())) }
+        for (page, chat) in self.pages.iter().enumerate() {
+            let len = utf16_len(&chat.to_json()?);
+            if len > limits.max_page_json_len { return Err(BookError::PageTooLong { page, len }) }
+        }
+        Ok(())
+    }
+}
+
+/// Serde helpers for the `tag` compound of written books used before 1.20.5, where pages are stored as JSON strings.
+///
+/// Use with `#[serde(with = "minecraft::book::legacy")]`.
+pub mod legacy {
+    use {
+        serde::{
+            Deserialize,
+            Deserializer,
+            Serialize,
+            Serializer,
+        },
+        crate::{
+            chat::{
+                self,
+                Chat,
+            },
+            util::nbt_bool,
+        },
+        super::{
+            Generation,
+            WrittenBook,
+        },
+    };
+
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    struct Page(#[serde(with = "chat::json_string")] Chat);
+
+    #[derive(Deserialize, Serialize)]
+    struct Tag {
+        title: String,
+        author: String,
+        #[serde(default)]
+        generation: Generation,
+        #[serde(default)]
+        pages: Vec<Page>,
+        #[serde(default, with = "nbt_bool")]
+        resolved: bool,
+    }
+
+    /// Serializes a written book as a legacy `tag` compound.
+    pub fn serialize<S: Serializer>(book: &WrittenBook, serializer: S) -> Result<S::Ok, S::Error> {
+        Tag {
+            title: book.title.clone(),
+            author: book.author.clone(),
+            generation: book.generation,
+            pages: book.pages.iter().cloned().map(Page).collect(),
+            resolved: book.resolved,
+        }.serialize(serializer)
+    }
+
+    /// Deserializes a written book from a legacy `tag` compound.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<WrittenBook, D::Error> {
+        let Tag { title, author, generation, pages, resolved } = Tag::deserialize(deserializer)?;
+        Ok(WrittenBook {
+            pages: pages.into_iter().map(|Page(page)| page).collect(),
+            title, author, generation, resolved,
+        })
+    }
+}
+
+/// An unsigned book and quill, as in the `writable_book_content` component or the `tag` compound used before 1.20.5.
+///
+/// Both formats store pages as plain strings. Deserialization also accepts the `{"raw": …, "filtered": …}` form of pages, but only the raw text is kept.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct WritableBook {
+    /// The text of each page.
+    #[serde(default, deserialize_with = "deserialize_filterable_strings", skip_serializing_if = "Vec::is_empty")]
+    pub pages: Vec<String>,
+}
+
+impl WritableBook {
+    /// Checks the number of pages and the length of each page, where the JSON length limit applies to the plain text.
+    pub fn validate(&self, limits: &BookLimits) -> Result<(), BookError> {
+        if self.pages.len() > limits.max_pages { return Err(BookError::TooManyPages(self.pages.len())) }
+        for (page, text) in self.pages.iter().enumerate() {
+            let len = utf16_len(text);
+            if len > limits.max_page_json_len { return Err(BookError::PageTooLong { page, len }) }
+        }
+        Ok(())
+    }
+}
+
+impl From<&WritableBook> for WrittenBook {
+    /// Converts a book and quill to a written book with no title or author, as when signing, with each page as plain text.
+    fn from(book: &WritableBook) -> WrittenBook {
+        WrittenBook {
+            pages: book.pages.iter().map(|page| Chat::from(page.as_str())).collect(),
+            ..WrittenBook::default()
+        }
+    }
+}
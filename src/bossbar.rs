@@ -0,0 +1,300 @@
+//! This module contains types for [boss bars](https://minecraft.fandom.com/wiki/Boss_bar), especially custom ones created using the `/bossbar` command.
+
+use {
+    std::collections::BTreeSet,
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    uuid::Uuid,
+    crate::{
+        chat::{
+            Chat,
+            NamedColor,
+        },
+        identifier::Identifier,
+    },
+};
+
+/// The color of a boss bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)] // variants are obvious
+pub enum BossBarColor {
+    Pink,
+    Blue,
+    Red,
+    Green,
+    Yellow,
+    Purple,
+    #[default]
+    White,
+}
+
+impl BossBarColor {
+    /// All boss bar colors, in ID order.
+    pub const ALL: [BossBarColor; 7] = [BossBarColor::Pink, BossBarColor::Blue, BossBarColor::Red, BossBarColor::Green, BossBarColor::Yellow, BossBarColor::Purple, BossBarColor::White];
+
+    /// The lowercase name used in commands and NBT, e.g. `pink`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BossBarColor::Pink => "pink",
+            BossBarColor::Blue => "blue",
+            BossBarColor::Red => "red",
+            BossBarColor::Green => "green",
+            BossBarColor::Yellow => "yellow",
+            BossBarColor::Purple => "purple",
+            BossBarColor::White => "white",
+        }
+    }
+
+    /// The numeric ID used in the Boss Bar packet.
+    pub fn id(&self) -> i32 {
+        match self {
+            BossBarColor::Pink => 0,
+            BossBarColor::Blue => 1,
+            BossBarColor::Red => 2,
+            BossBarColor::Green => 3,
+            BossBarColor::Yellow => 4,
+            BossBarColor::Purple => 5,
+            BossBarColor::White => 6,
+        }
+    }
+
+    /// The closest named text color, as used by vanilla when a boss bar's name is shown in chat, e.g. by `/bossbar list`.
+    pub fn chat_color(&self) -> NamedColor {
+        match self {
+            BossBarColor::Pink => NamedColor::Red,
+            BossBarColor::Blue => NamedColor::Blue,
+            BossBarColor::Red => NamedColor::DarkRed,
+            BossBarColor::Green => NamedColor::Green,
+            BossBarColor::Yellow => NamedColor::Yellow,
+            BossBarColor::Purple => NamedColor::DarkBlue,
+            BossBarColor::White => NamedColor::White,
+        }
+    }
+}
+
+/// Whether a boss bar is continuous or divided into segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+pub enum BossBarStyle {
+    /// No divisions.
+    #[default]
+    #[serde(rename = "progress")]
+    Progress,
+    /// 6 segments.
+    #[serde(rename = "notched_6")]
+    Notched6,
+    /// 10 segments.
+    #[serde(rename = "notched_10")]
+    Notched10,
+    /// 12 segments.
+    #[serde(rename = "notched_12")]
+    Notched12,
+    /// 20 segments.
+    #[serde(rename = "notched_20")]
+    Notched20,
+}
+
+impl BossBarStyle {
+    /// The name used in commands and NBT, e.g. `notched_6`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BossBarStyle::Progress => "progress",
+            BossBarStyle::Notched6 => "notched_6",
+            BossBarStyle::Notched10 => "notched_10",
+            BossBarStyle::Notched12 => "notched_12",
+            BossBarStyle::Notched20 => "notched_20",
+        }
+    }
+
+    /// The numeric ID used in the Boss Bar packet.
+    pub fn id(&self) -> i32 {
+        match self {
+            BossBarStyle::Progress => 0,
+            BossBarStyle::Notched6 => 1,
+            BossBarStyle::Notched10 => 2,
+            BossBarStyle::Notched12 => 3,
+            BossBarStyle::Notched20 => 4,
+        }
+    }
+
+    /// The number of segments, or `None` for [`BossBarStyle::Progress`].
+    pub fn segments(&self) -> Option<u8> {
+        match self {
+            BossBarStyle::Progress => None,
+            BossBarStyle::Notched6 => Some(6),
+            BossBarStyle::Notched10 => Some(10),
+            BossBarStyle::Notched12 => Some(12),
+            BossBarStyle::Notched20 => Some(20),
+        }
+    }
+}
+
+/// A custom boss bar created using the `/bossbar` command.
+///
+/// Custom boss bars are stored in the `CustomBossEvents` compound of `level.dat`, keyed by ID. Use [`map`] to (de)serialize that compound.
+#[derive(Debug, Clone)]
+pub struct BossBar {
+    /// The ID used to refer to this boss bar in commands.
+    pub id: Identifier,
+    /// The text shown above the bar.
+    pub name: Chat,
+    /// The color of the bar.
+    pub color: BossBarColor,
+    /// Whether the bar is divided into segments.
+    pub style: BossBarStyle,
+    /// The current value, which determines how full the bar is together with `max`.
+    pub value: i32,
+    /// The maximum value.
+    pub max: i32,
+    /// Whether the bar is shown to its players.
+    pub visible: bool,
+    /// The players who see the bar.
+    pub players: BTreeSet<Uuid>,
+    /// Whether the sky is darkened, as with the wither.
+    pub darken_screen: bool,
+    /// Whether boss music is played, as with the ender dragon.
+    pub play_boss_music: bool,
+    /// Whether fog is created around the player, as with the ender dragon.
+    pub create_world_fog: bool,
+}
+
+impl BossBar {
+    /// Creates a boss bar with the same defaults as `/bossbar add`: white, without divisions, visible, with a value of 0 out of 100 and no players.
+    pub fn new(id: Identifier, name: impl Into<Chat>) -> BossBar {
+        BossBar {
+            name: name.into(),
+            color: BossBarColor::default(),
+            style: BossBarStyle::default(),
+            value: 0,
+            max: 100,
+            visible: true,
+            players: BTreeSet::default(),
+            darken_screen: false,
+            play_boss_music: false,
+            create_world_fog: false,
+            id,
+        }
+    }
+
+    /// How full the bar is, from 0 to 1.
+    pub fn progress(&self) -> f32 {
+        if self.max <= 0 { return 0.0 }
+        (self.value as f32 / self.max as f32).clamp(0.0, 1.0)
+    }
+
+    /// The `/bossbar` commands (without leading slashes) which create this boss bar with its settings on a server.
+    ///
+    /// Players are not included, since offline players can't be targeted by commands. The darken screen, boss music, and world fog flags can't be set by commands either.
+    pub fn to_commands(&self) -> Vec<String> {
+        vec![
+            format!("bossbar add {} {}", self.id, self.name.to_json().unwrap_or_else(|_| String::from("\"\""))),
+            format!("bossbar set {} color {}", self.id, self.color.name()),
+            format!("bossbar set {} style {}", self.id, self.style.name()),
+            format!("bossbar set {} max {}", self.id, self.max),
+            format!("bossbar set {} value {}", self.id, self.value),
+            format!("bossbar set {} visible {}", self.id, self.visible),
+        ]
+    }
+}
+
+/// Serde helpers for the `CustomBossEvents` compound in `level.dat`, which maps boss bar IDs to their settings.
+///
+/// Use with `#[serde(with = "minecraft::bossbar::map")]` on a `Vec<BossBar>`.
+pub mod map {
+    use {
+        std::collections::{
+            BTreeMap,
+            BTreeSet,
+        },
+        serde::{
+            Deserialize,
+            Deserializer,
+            Serialize,
+            Serializer,
+        },
+        uuid::Uuid,
+        crate::{
+            chat::{
+                self,
+                Chat,
+            },
+            identifier::Identifier,
+            util::{
+                nbt_bool,
+                uuid_ints,
+            },
+        },
+        super::{
+            BossBar,
+            BossBarColor,
+            BossBarStyle,
+        },
+    };
+
+    #[derive(Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+    #[serde(transparent)]
+    struct UuidInts(#[serde(with = "uuid_ints")] Uuid);
+
+    fn default_max() -> i32 { 100 }
+    fn default_true() -> bool { true }
+
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Entry {
+        #[serde(with = "chat::json_string")]
+        name: Chat,
+        #[serde(default)]
+        color: BossBarColor,
+        #[serde(rename = "Overlay", default)]
+        style: BossBarStyle,
+        #[serde(default)]
+        value: i32,
+        #[serde(default = "default_max")]
+        max: i32,
+        #[serde(default = "default_true", with = "nbt_bool")]
+        visible: bool,
+        #[serde(default)]
+        players: BTreeSet<UuidInts>,
+        #[serde(default, with = "nbt_bool")]
+        darken_screen: bool,
+        #[serde(default, with = "nbt_bool")]
+        play_boss_music: bool,
+        #[serde(default, with = "nbt_bool")]
+        create_world_fog: bool,
+    }
+
+    /// Serializes the boss bars as a map from ID to settings.
+    pub fn serialize<S: Serializer>(boss_bars: &[BossBar], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(boss_bars.iter().map(|boss_bar| (&boss_bar.id, Entry {
+            name: boss_bar.name.clone(),
+            color: boss_bar.color,
+            style: boss_bar.style,
+            value: boss_bar.value,
+            max: boss_bar.max,
+            visible: boss_bar.visible,
+            players: boss_bar.players.iter().copied().map(UuidInts).collect(),
+            darken_screen: boss_bar.darken_screen,
+            play_boss_music: boss_bar.play_boss_music,
+            create_world_fog: boss_bar.create_world_fog,
+        })))
+    }
+
+    /// Deserializes the boss bars from a map from ID to settings, sorted by ID.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<BossBar>, D::Error> {
+        Ok(BTreeMap::<Identifier, Entry>::deserialize(deserializer)?.into_iter().map(|(id, entry)| BossBar {
+            name: entry.name,
+            color: entry.color,
+            style: entry.style,
+            value: entry.value,
+            max: entry.max,
+            visible: entry.visible,
+            players: entry.players.into_iter().map(|UuidInts(uuid)| uuid).collect(),
+            darken_screen: entry.darken_screen,
+            play_boss_music: entry.play_boss_music,
+            create_world_fog: entry.create_world_fog,
+            id,
+        }).collect())
+    }
+}
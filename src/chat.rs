@@ -3,20 +3,263 @@
 use {
     std::{
         borrow::Cow,
+        cell::{
+            Cell,
+            RefCell,
+        },
+        collections::{
+            BTreeMap,
+            BTreeSet,
+            HashMap,
+        },
         fmt,
+        io::{
+            self,
+            Write as _,
+        },
+        mem,
+        slice,
+        str::{
+            Chars,
+            FromStr,
+        },
     },
     serde::{
         Deserialize,
+        Deserializer,
         Serialize,
+        Serializer,
+        de::{
+            self,
+            DeserializeSeed,
+            MapAccess,
+            SeqAccess,
+            Visitor,
+        },
+        ser::SerializeMap as _,
     },
     uuid::Uuid,
+    crate::{
+        dimension::Dimension,
+        entity::EntityKind,
+        legacy,
+        locale::Locale,
+        position::BlockPos,
+        util,
+    },
 };
 
-/// The text colors used in [`Chat`] messages.
-#[derive(Deserialize, Serialize)]
+#[cfg(feature = "arbitrary")] mod arbitrary;
+pub mod borrowed;
+#[cfg(feature = "proptest")] pub mod proptest;
+#[cfg(feature = "schemars")] mod schema;
+pub mod signing;
+
+/// An error that can occur when converting a [`Chat`] to another format.
+#[derive(Debug)]
+pub enum ChatError {
+    /// The component could not be serialized to JSON.
+    Json(serde_json::Error),
+    /// A translatable component used a key which is not in the translation map.
+    UnresolvedTranslation(String),
+    /// A color string started with `#` but was not a valid hex color.
+    InvalidHex(String),
+    /// A color string was not a known color name.
+    UnknownColor(String),
+    /// The component was nested more deeply than the given limit.
+    RecursionLimitExceeded(usize),
+    /// The component tree contained more components than the given limit.
+    TooManyComponents(usize),
+    /// A translatable component had an argument using the same translation key, directly or further down.
+    TranslationCycle(String),
+    /// A command line passed to [`Chat::parse_tellraw`] had no target or no text component.
+    MalformedCommand(String),
+}
+
+impl From<serde_json::Error> for ChatError {
+    fn from(e: serde_json::Error) -> ChatError {
+        ChatError::Json(e)
+    }
+}
+
+impl fmt::Display for ChatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChatError::Json(e) => write!(f, "JSON error: {}", e),
+            ChatError::UnresolvedTranslation(key) => write!(f, "no translation for key {:?}", key),
+            ChatError::InvalidHex(s) => write!(f, "invalid hex color: {:?}", s),
+            ChatError::UnknownColor(s) => write!(f, "unknown color: {:?}", s),
+            ChatError::RecursionLimitExceeded(limit) => write!(f, "text component nested more than {} levels deep", limit),
+            ChatError::TooManyComponents(limit) => write!(f, "text component tree has more than {} components", limit),
+            ChatError::TranslationCycle(key) => write!(f, "translation key {:?} is used in its own arguments", key),
+            ChatError::MalformedCommand(line) => write!(f, "malformed tellraw command: {:?}", line),
+        }
+    }
+}
+
+impl std::error::Error for ChatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ChatError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// The maximum number of characters in a chat message sent by a client, enforced for the content of signed messages since 1.19.
+pub const MAX_CHAT_LENGTH: usize = 256;
+
+/// The limits checked by [`Chat::resolve_translations`] and [`Translator::resolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TranslationLimits {
+    /// The maximum nesting depth of components, counting both children and translation arguments.
+    pub max_depth: usize,
+    /// The maximum number of components in the result. Since arguments can be referenced multiple times by a translation, the result can be much larger than the input.
+    pub max_components: usize,
+}
+
+impl Default for TranslationLimits {
+    fn default() -> TranslationLimits {
+        TranslationLimits {
+            max_depth: 64,
+            max_components: 10_000,
+        }
+    }
+}
+
+/// Which markers [`Chat::to_log_line_with`] adds after the text of interactive components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogLineOptions {
+    /// Whether to append ` (<url>)` after components which open a URL when clicked.
+    pub links: bool,
+    /// Whether to append ` [hover]` after components with a hover event.
+    pub hover: bool,
+}
+
+impl Default for LogLineOptions {
+    /// Links are shown, hover markers are not.
+    fn default() -> LogLineOptions {
+        LogLineOptions {
+            links: true,
+            hover: false,
+        }
+    }
+}
+
+/// Options for [`Chat::to_json_with`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct JsonOptions {
+    /// Whether to omit formatting flags like `bold` which are equal to the value inherited from the parent component, or `false` at the root, like vanilla does.
+    ///
+    /// This is off by default since it's lossy: a component with an explicit `"bold":false` displays differently once it's added as a child of bold text. Text shown on hover is written unchanged.
+    pub omit_redundant_flags: bool,
+}
+
+/// The translations for multiple locales, for resolving translatable components in a player's language.
+///
+/// Like the client, a translation missing from a locale is looked up in its [fallbacks](Locale::fallbacks), ending with `en_us`.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::{
+///     chat::{
+///         Chat,
+///         Translator,
+///     },
+///     locale::Locale,
+/// };
+///
+/// let mut translator = Translator::default();
+/// translator.insert(Locale::EN_US, vec![(format!("chat.type.text"), format!("<%s> %s")), (format!("greeting"), format!("Hello"))].into_iter().collect());
+/// translator.insert(Locale::DE_DE, vec![(format!("greeting"), format!("Hallo"))].into_iter().collect());
+/// translator.insert(Locale::DE_AT, vec![(format!("greeting"), format!("Servus"))].into_iter().collect());
+/// assert_eq!(translator.translate(&Locale::DE_CH, "greeting"), Some("Hallo"));
+/// assert_eq!(translator.translate(&Locale::DE_AT, "greeting"), Some("Servus"));
+///
+/// let chat = Chat::from_json(r#"{"translate":"chat.type.text","with":["Notch",{"translate":"greeting"}]}"#)?;
+/// let resolved = translator.resolve(&chat, &Locale::DE_CH, &Default::default())?;
+/// assert_eq!(resolved.runs().map(|(text, _)| text).collect::<String>(), "<Notch> Hallo");
+/// # Ok::<(), minecraft::chat::ChatError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Translator {
+    /// The translations of each locale by translation key, e.g. from the game's language files.
+    pub languages: HashMap<Locale, HashMap<String, String>>,
+}
+
+impl Translator {
+    /// Adds the translations for a locale, replacing any previously added for the same locale.
+    pub fn insert(&mut self, locale: Locale, translations: HashMap<String, String>) -> &mut Translator {
+        self.languages.insert(locale, translations);
+        self
+    }
+
+    /// The translation of `key` in the first of the locale's [fallbacks](Locale::fallbacks) which has one.
+    pub fn translate(&self, locale: &Locale, key: &str) -> Option<&str> {
+        locale.fallbacks().iter()
+            .filter_map(|locale| self.languages.get(locale))
+            .find_map(|translations| translations.get(key))
+            .map(String::as_str)
+    }
+
+    /// Returns a copy of `chat` with translatable components replaced by their translations in the given locale, as in [`Chat::resolve_translations`].
+    pub fn resolve(&self, chat: &Chat, locale: &Locale, limits: &TranslationLimits) -> Result<Chat, ChatError> {
+        let fallbacks = locale.fallbacks().iter().filter_map(|locale| self.languages.get(locale)).collect::<Vec<_>>();
+        chat.resolve_translations_with(&|key| fallbacks.iter().find_map(|translations| translations.get(key)).map(String::as_str), limits)
+    }
+}
+
+/// A piece of a translation string, see [`parse_translation`].
+enum TranslationPiece<'a> {
+    Text(Cow<'a, str>),
+    Arg(usize),
+}
+
+/// Splits a translation string into literal text and argument references, using the placeholders `%s`, `%1$s`, and `%%` like vanilla.
+///
+/// Returns `None` if the string contains another placeholder or references a missing argument, in which case vanilla displays it literally.
+fn parse_translation(format: &str, num_args: usize) -> Option<Vec<TranslationPiece<'_>>> {
+    fn push_text<'a>(pieces: &mut Vec<TranslationPiece<'a>>, text: &'a str) {
+        match pieces.last_mut() {
+            Some(TranslationPiece::Text(prev)) => prev.to_mut().push_str(text),
+            _ => pieces.push(TranslationPiece::Text(Cow::Borrowed(text))),
+        }
+    }
+
+    let mut pieces = Vec::default();
+    let mut next_arg = 0;
+    let mut rest = format;
+    while let Some(idx) = rest.find('%') {
+        if idx > 0 { push_text(&mut pieces, &rest[..idx]) }
+        rest = &rest[idx + 1..];
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let (arg, after) = if digits > 0 && rest[digits..].starts_with('$') {
+            (Some(rest[..digits].parse::<usize>().ok()?.checked_sub(1)?), &rest[digits + 1..])
+        } else {
+            (None, rest)
+        };
+        match after.chars().next() {
+            Some('s') => {
+                let arg = arg.unwrap_or_else(|| { next_arg += 1; next_arg - 1 });
+                if arg >= num_args { return None }
+                pieces.push(TranslationPiece::Arg(arg));
+            }
+            Some('%') if arg.is_none() => push_text(&mut pieces, "%"),
+            _ => return None,
+        }
+        rest = &after[1..];
+    }
+    if !rest.is_empty() { push_text(&mut pieces, rest) }
+    Some(pieces)
+}
+
+/// The 16 named text colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[allow(missing_docs)] // variants are obvious
-pub enum Color {
+pub enum NamedColor {
     Black,
     DarkBlue,
     DarkGreen,
@@ -35,8 +278,403 @@ pub enum Color {
     White,
 }
 
+impl NamedColor {
+    /// All named colors, in vanilla order.
+    pub const ALL: [NamedColor; 16] = [
+        NamedColor::Black,
+        NamedColor::DarkBlue,
+        NamedColor::DarkGreen,
+        NamedColor::DarkAqua,
+        NamedColor::DarkRed,
+        NamedColor::DarkPurple,
+        NamedColor::Gold,
+        NamedColor::Gray,
+        NamedColor::DarkGray,
+        NamedColor::Blue,
+        NamedColor::Green,
+        NamedColor::Aqua,
+        NamedColor::Red,
+        NamedColor::LightPurple,
+        NamedColor::Yellow,
+        NamedColor::White,
+    ];
+
+    /// The index of this color in vanilla order, from 0 for black to 15 for white.
+    ///
+    /// This is the order of the legacy formatting codes `§0` to `§f` and of the team-colored sidebar display slots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minecraft::chat::NamedColor;
+    ///
+    /// for (idx, color) in NamedColor::ALL.iter().enumerate() {
+    ///     assert_eq!(usize::from(color.index()), idx);
+    ///     assert_eq!(NamedColor::from_index(idx as u8), Some(*color));
+    /// }
+    /// assert_eq!(NamedColor::DarkRed.index(), 4);
+    /// assert_eq!(NamedColor::from_index(16), None);
+    /// ```
+    pub fn index(&self) -> u8 {
+        match self {
+            NamedColor::Black => 0,
+            NamedColor::DarkBlue => 1,
+            NamedColor::DarkGreen => 2,
+            NamedColor::DarkAqua => 3,
+            NamedColor::DarkRed => 4,
+            NamedColor::DarkPurple => 5,
+            NamedColor::Gold => 6,
+            NamedColor::Gray => 7,
+            NamedColor::DarkGray => 8,
+            NamedColor::Blue => 9,
+            NamedColor::Green => 10,
+            NamedColor::Aqua => 11,
+            NamedColor::Red => 12,
+            NamedColor::LightPurple => 13,
+            NamedColor::Yellow => 14,
+            NamedColor::White => 15,
+        }
+    }
+
+    /// Looks up a color by its [index](NamedColor::index) in vanilla order.
+    pub fn from_index(index: u8) -> Option<NamedColor> {
+        NamedColor::ALL.get(usize::from(index)).copied()
+    }
+
+    /// The name used in JSON, e.g. `dark_red`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            NamedColor::Black => "black",
+            NamedColor::DarkBlue => "dark_blue",
+            NamedColor::DarkGreen => "dark_green",
+            NamedColor::DarkAqua => "dark_aqua",
+            NamedColor::DarkRed => "dark_red",
+            NamedColor::DarkPurple => "dark_purple",
+            NamedColor::Gold => "gold",
+            NamedColor::Gray => "gray",
+            NamedColor::DarkGray => "dark_gray",
+            NamedColor::Blue => "blue",
+            NamedColor::Green => "green",
+            NamedColor::Aqua => "aqua",
+            NamedColor::Red => "red",
+            NamedColor::LightPurple => "light_purple",
+            NamedColor::Yellow => "yellow",
+            NamedColor::White => "white",
+        }
+    }
+
+    /// The named color closest to the given RGB value, e.g. for displaying hex colors where only named colors are supported.
+    pub fn nearest(rgb: [u8; 3]) -> NamedColor {
+        *NamedColor::ALL.iter().min_by_key(|color| util::color_distance(color.rgb(), rgb)).expect("there are named colors")
+    }
+
+    /// The RGB value of this color as rendered in chat.
+    pub fn rgb(&self) -> [u8; 3] {
+        match self {
+            NamedColor::Black => [0x00, 0x00, 0x00],
+            NamedColor::DarkBlue => [0x00, 0x00, 0xaa],
+            NamedColor::DarkGreen => [0x00, 0xaa, 0x00],
+            NamedColor::DarkAqua => [0x00, 0xaa, 0xaa],
+            NamedColor::DarkRed => [0xaa, 0x00, 0x00],
+            NamedColor::DarkPurple => [0xaa, 0x00, 0xaa],
+            NamedColor::Gold => [0xff, 0xaa, 0x00],
+            NamedColor::Gray => [0xaa, 0xaa, 0xaa],
+            NamedColor::DarkGray => [0x55, 0x55, 0x55],
+            NamedColor::Blue => [0x55, 0x55, 0xff],
+            NamedColor::Green => [0x55, 0xff, 0x55],
+            NamedColor::Aqua => [0x55, 0xff, 0xff],
+            NamedColor::Red => [0xff, 0x55, 0x55],
+            NamedColor::LightPurple => [0xff, 0x55, 0xff],
+            NamedColor::Yellow => [0xff, 0xff, 0x55],
+            NamedColor::White => [0xff, 0xff, 0xff],
+        }
+    }
+}
+
+/// The text colors used in [`Chat`] messages.
+///
+/// Serializes as the color name, `#rrggbb` for hex colors, or `reset`/`none`.
+///
+/// Parsing and deserialization also accept color names which are capitalized differently or use hyphens or camelCase instead of underscores, as written by some third-party tools:
+///
+/// ```
+/// use minecraft::chat::{
+///     Color,
+///     NamedColor,
+/// };
+///
+/// for spelling in ["dark_red", "DARK_RED", "dark-red", "darkRed", "DarkRed"] {
+///     assert_eq!(spelling.parse::<Color>()?, Color::Named(NamedColor::DarkRed));
+/// }
+/// assert_eq!(serde_json::from_str::<Color>("\"Dark-Red\"")?.to_string(), "dark_red");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Color {
+    /// One of the 16 named colors.
+    Named(NamedColor),
+    /// An arbitrary RGB color, written as `#rrggbb`.
+    Hex([u8; 3]),
+    /// Resets the color to the default, written as `reset`.
+    Reset,
+    /// Also resets the color to the default, written as `none` as accepted by recent versions.
+    None,
+}
+
+impl From<NamedColor> for Color {
+    fn from(color: NamedColor) -> Color {
+        Color::Named(color)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Named(color) => write!(f, "{}", color.name()),
+            Color::Hex([r, g, b]) => write!(f, "#{:02x}{:02x}{:02x}", r, g, b),
+            Color::Reset => write!(f, "reset"),
+            Color::None => write!(f, "none"),
+        }
+    }
+}
+
+/// Converts a color name like `DARK_RED`, `dark-red`, or `darkRed` to the canonical snake_case form.
+///
+/// Strings which are already canonical, including hex colors, are returned unchanged without allocating.
+fn normalize_color_name(s: &str) -> Cow<'_, str> {
+    if !s.chars().any(|c| c.is_ascii_uppercase() || c == '-') { return Cow::Borrowed(s) }
+    let camel_case = s.chars().any(|c| c.is_ascii_lowercase());
+    let mut name = String::with_capacity(s.len() + 1);
+    for c in s.chars() {
+        match c {
+            '-' => name.push('_'),
+            'A'..='Z' => {
+                if camel_case && !name.is_empty() && !name.ends_with('_') { name.push('_') }
+                name.push(c.to_ascii_lowercase());
+            }
+            _ => name.push(c),
+        }
+    }
+    Cow::Owned(name)
+}
+
+/// Parses a hex color in `#rrggbb` or `#rgb` form, case-insensitively, into its red, green, and blue channels.
+///
+/// In the `#rgb` shorthand, each digit is repeated, so `#f5a` is the same as `#ff55aa`.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::chat::{
+///     self,
+///     ChatError,
+/// };
+///
+/// assert_eq!(chat::parse_hex("#f5a")?, [0xff, 0x55, 0xaa]);
+/// assert_eq!(chat::parse_hex("#FF55AA")?, [0xff, 0x55, 0xaa]);
+/// assert_eq!(chat::parse_hex("#00Ff80")?, [0x00, 0xff, 0x80]);
+/// for invalid in ["ff55aa", "#ff55a", "#ff55aa0", "#", "", "#gg0000", "#+f5a", "#f5a ", "#ff55\u{e9}"] {
+///     assert!(matches!(chat::parse_hex(invalid), Err(ChatError::InvalidHex(s)) if s == invalid));
+/// }
+/// # Ok::<(), ChatError>(())
+/// ```
+pub fn parse_hex(s: &str) -> Result<[u8; 3], ChatError> {
+    let invalid = || ChatError::InvalidHex(s.to_owned());
+    let hex = s.strip_prefix('#').filter(|hex| matches!(hex.len(), 3 | 6)).ok_or_else(invalid)?;
+    let digits = hex.chars().map(|c| c.to_digit(16).map(|digit| digit as u8)).collect::<Option<Vec<_>>>().ok_or_else(invalid)?;
+    match digits[..] {
+        [r, g, b] => Ok([r * 0x11, g * 0x11, b * 0x11]),
+        [r1, r2, g1, g2, b1, b2] => Ok([r1 << 4 | r2, g1 << 4 | g2, b1 << 4 | b2]),
+        _ => Err(invalid()),
+    }
+}
+
+/// # Example
+///
+/// ```
+/// use minecraft::chat::{
+///     Chat,
+///     Color,
+///     NamedColor,
+/// };
+///
+/// for (name, color) in [
+///     ("none", Color::None),
+///     ("reset", Color::Reset),
+///     ("dark_aqua", Color::Named(NamedColor::DarkAqua)),
+///     ("#ff55aa", Color::Hex([0xff, 0x55, 0xaa])),
+/// ] {
+///     assert_eq!(name.parse::<Color>()?, color);
+///     assert_eq!(color.to_string(), name);
+///     let json = format!(r#"{{"text":"hi","color":"{}"}}"#, name);
+///     let chat = Chat::from_json(&json)?;
+///     assert_eq!(chat.color, Some(color));
+///     assert_eq!(chat.to_json()?, json);
+/// }
+/// # Ok::<(), minecraft::chat::ChatError>(())
+/// ```
+impl FromStr for Color {
+    type Err = ChatError;
+
+    /// Parses a color name, a hex color as in [`parse_hex`], `reset`, or `none`.
+    ///
+    /// Names are matched case-insensitively, and hyphens or camelCase word boundaries are treated like underscores.
+    fn from_str(s: &str) -> Result<Color, ChatError> {
+        let name = normalize_color_name(s);
+        match &*name {
+            "reset" => Ok(Color::Reset),
+            "none" => Ok(Color::None),
+            _ => if s.starts_with('#') {
+                parse_hex(s).map(Color::Hex)
+            } else {
+                NamedColor::ALL.iter()
+                    .find(|color| color.name() == name)
+                    .map(|&color| Color::Named(color))
+                    .ok_or_else(|| ChatError::UnknownColor(s.to_owned()))
+            },
+        }
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        struct ColorVisitor;
+
+        impl<'de> Visitor<'de> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a color name or hex color")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Color, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(ColorVisitor)
+    }
+}
+
+/// The formatting fields of a text component, each of which may be unset.
+///
+/// Unset fields are inherited from the parent component. See [`Style::inherit_from`] and [`ResolvedStyle::overlay`] for ways to combine styles.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+pub struct Style {
+    /// The text color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    /// The color of the text shadow as ARGB, added in 1.21.4. Fully transparent hides the shadow.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "shadow_color")]
+    pub shadow_color: Option<u32>,
+    /// Whether to render the content in boldface.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+    /// Whether to render the content in italics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+    /// Whether to underline the content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub underlined: Option<bool>,
+    /// Whether to strike through the content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strikethrough: Option<bool>,
+    /// Whether to render the content obfuscated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub obfuscated: Option<bool>,
+    /// Text inserted into the chat input when the content is shift-clicked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insertion: Option<String>,
+    /// The resource location of the font, e.g. `minecraft:uniform`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font: Option<String>,
+}
+
+impl Style {
+    /// Returns this style with its unset fields taken from `parent`, as when a component is displayed as a child of a component with the `parent` style.
+    ///
+    /// Fields set in `self` are kept. Compare [`ResolvedStyle::overlay`], where the other style takes precedence.
+    pub fn inherit_from(&self, parent: &Style) -> Style {
+        Style {
+            color: self.color.or(parent.color),
+            shadow_color: self.shadow_color.or(parent.shadow_color),
+            bold: self.bold.or(parent.bold),
+            italic: self.italic.or(parent.italic),
+            underlined: self.underlined.or(parent.underlined),
+            strikethrough: self.strikethrough.or(parent.strikethrough),
+            obfuscated: self.obfuscated.or(parent.obfuscated),
+            insertion: self.insertion.clone().or_else(|| parent.insertion.clone()),
+            font: self.font.clone().or_else(|| parent.font.clone()),
+        }
+    }
+}
+
+/// The formatting of a piece of text after applying inheritance from parent components.
+///
+/// The default is the style of text without any formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ResolvedStyle {
+    /// The text color, or `None` for the default color of the place where the text is displayed, e.g. white in chat.
+    ///
+    /// This is never [`Color::Reset`] or [`Color::None`], which resolve to `None`.
+    pub color: Option<Color>,
+    /// Whether the text is bold.
+    pub bold: bool,
+    /// Whether the text is italic.
+    pub italic: bool,
+    /// Whether the text is underlined.
+    pub underlined: bool,
+    /// Whether the text is struck through.
+    pub strikethrough: bool,
+    /// Whether the text is obfuscated.
+    pub obfuscated: bool,
+}
+
+impl ResolvedStyle {
+    /// Returns this style with the fields set in `top` replaced, e.g. to apply a theme on top of existing formatting.
+    ///
+    /// Fields set in `top` win even if they are also set here, and unset fields keep their value from `self`. A [`Color::Reset`] or [`Color::None`] in `top` resets the color to the default. This is also how a component's own style applies on top of the style it inherits. Compare [`Style::inherit_from`], which combines two partial styles and lets the receiver win instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Color,
+    ///     NamedColor,
+    ///     ResolvedStyle,
+    ///     Style,
+    /// };
+    ///
+    /// let base = ResolvedStyle { color: Some(Color::Named(NamedColor::Red)), bold: true, ..ResolvedStyle::default() };
+    /// let theme = Style { color: Some(Color::Named(NamedColor::Gold)), bold: Some(false), italic: Some(true), ..Style::default() };
+    /// assert_eq!(base.overlay(&theme), ResolvedStyle { color: Some(Color::Named(NamedColor::Gold)), italic: true, ..ResolvedStyle::default() });
+    /// assert_eq!(base.overlay(&Style::default()), base);
+    /// ```
+    pub fn overlay(&self, top: &Style) -> ResolvedStyle {
+        ResolvedStyle {
+            color: match top.color {
+                Some(Color::Reset) | Some(Color::None) => None,
+                Some(color) => Some(color),
+                None => self.color,
+            },
+            bold: top.bold.unwrap_or(self.bold),
+            italic: top.italic.unwrap_or(self.italic),
+            underlined: top.underlined.unwrap_or(self.underlined),
+            strikethrough: top.strikethrough.unwrap_or(self.strikethrough),
+            obfuscated: top.obfuscated.unwrap_or(self.obfuscated),
+        }
+    }
+}
+
 /// The events that can be performed when a [`Chat`] is clicked.
-#[derive(Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "action", content = "value", rename_all = "snake_case")]
 #[allow(missing_docs)] //TODO
 pub enum ClickEvent {
@@ -49,7 +687,34 @@ pub enum ClickEvent {
 }
 
 /// The events that can be performed when a player hovers over a [`Chat`] with the mouse.
-#[derive(Deserialize, Serialize)]
+///
+/// Serializes in the format used since 1.16, with the data in `contents`. Deserialization also accepts the older format with the data in `value`, where items and entities are given as SNBT strings. An item count may also be given as a numeric string. Entity types not known to this crate, e.g. from mods, are kept as [`EntityKind::Other`].
+///
+/// # Example
+///
+/// ```
+/// use minecraft::{
+///     chat::HoverEvent,
+///     entity::EntityKind,
+/// };
+///
+/// let json = r#"{"action":"show_entity","contents":{"type":"minecraft:zombie","id":"0b1d3e5f-0000-4000-8000-000000000000"}}"#;
+/// let event = serde_json::from_str::<HoverEvent>(json)?;
+/// assert!(matches!(event, HoverEvent::ShowEntity { entity_type: EntityKind::Zombie, .. }));
+/// assert_eq!(serde_json::to_string(&event)?, json);
+/// let modded = serde_json::from_str::<HoverEvent>(r#"{"action":"show_entity","contents":{"type":"example:gremlin","id":"0b1d3e5f-0000-4000-8000-000000000000"}}"#)?;
+/// assert!(matches!(modded, HoverEvent::ShowEntity { entity_type: EntityKind::Other(id), .. } if id.to_string() == "example:gremlin"));
+///
+/// let event = serde_json::from_str::<HoverEvent>(r#"{"action":"show_item","contents":{"id":"minecraft:diamond","count":"3"}}"#)?;
+/// assert!(matches!(event, HoverEvent::ShowItem { count: Some(3), .. }));
+/// assert!(serde_json::from_str::<HoverEvent>(r#"{"action":"show_item","contents":{"id":"minecraft:diamond","count":"three"}}"#).is_err());
+///
+/// // malformed legacy SNBT is an error rather than a panic or stack overflow
+/// assert!(serde_json::from_str::<HoverEvent>(r#"{"action":"show_item","value":"[a€]"}"#).is_err());
+/// assert!(serde_json::from_str::<HoverEvent>(&format!(r#"{{"action":"show_item","value":"{}"}}"#, "[".repeat(200_000))).is_err());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, Serialize)]
 #[serde(tag = "action", content = "contents", rename_all = "snake_case")]
 #[allow(missing_docs)] //TODO
 pub enum HoverEvent {
@@ -60,60 +725,1386 @@ pub enum HoverEvent {
         count: Option<i32>,
         #[serde(skip_serializing_if = "Option::is_none")]
         tag: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        components: Option<serde_json::Value>,
     },
     ShowEntity {
         #[serde(skip_serializing_if = "Option::is_none")]
         name: Option<Box<Chat>>,
         #[serde(rename = "type")]
-        entity_type: String,
+        entity_type: EntityKind,
         id: Uuid,
     },
 }
 
+impl HoverEvent {
+    /// Returns a copy of this hover event with `f` applied to the text components it contains, i.e. the text of `show_text` and the entity name of `show_entity`.
+    fn map_components(&self, f: &dyn Fn(&Chat) -> Chat) -> HoverEvent {
+        match self {
+            HoverEvent::ShowText(text) => HoverEvent::ShowText(Box::new(f(text))),
+            HoverEvent::ShowEntity { name, entity_type, id } => HoverEvent::ShowEntity {
+                name: name.as_ref().map(|name| Box::new(f(name))),
+                entity_type: entity_type.clone(),
+                id: *id,
+            },
+            HoverEvent::ShowItem { .. } => self.clone(),
+        }
+    }
+
+    /// Creates a `show_entity` hover event without a custom name.
+    pub fn entity(kind: EntityKind, id: Uuid) -> HoverEvent {
+        HoverEvent::ShowEntity {
+            name: None,
+            entity_type: kind,
+            id,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HoverEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<HoverEvent, D::Error> {
+        deserializer.deserialize_map(HoverEventVisitor)
+    }
+}
+
+/// Deserializes a hover event in either the current or the pre-1.16 format.
+///
+/// If `action` comes first, as written by vanilla, the data is deserialized directly, with text components counted against the component budget as they're read. Otherwise, the data is buffered first, counting every JSON value against the budget instead.
+struct HoverEventVisitor;
+
+impl<'de> Visitor<'de> for HoverEventVisitor {
+    type Value = HoverEvent;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a hover event")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<HoverEvent, A::Error> {
+        let mut action = None::<String>;
+        // whether the data is from `contents` rather than `value`, and the data, which is preferred from `contents`
+        let mut parsed = None::<(bool, HoverEvent)>;
+        let mut buffered = None::<(bool, serde_json::Value)>;
+        while let Some(key) = map.next_key::<Cow<'de, str>>()? {
+            match &*key {
+                "action" => {
+                    if action.is_some() { return Err(de::Error::duplicate_field("action")) }
+                    action = Some(map.next_value()?);
+                }
+                "contents" | "value" => {
+                    let modern = key == "contents";
+                    if parsed.as_ref().map(|&(modern, _)| modern).or(buffered.as_ref().map(|&(modern, _)| modern)) == Some(true) {
+                        if modern { return Err(de::Error::duplicate_field("contents")) }
+                        map.next_value::<de::IgnoredAny>()?;
+                        continue
+                    }
+                    match action {
+                        Some(ref action) => parsed = Some((modern, map.next_value_seed(HoverDataSeed { action, modern })?)),
+                        None => buffered = Some((modern, map.next_value_seed(BufferedValueSeed)?)),
+                    }
+                }
+                _ => { map.next_value::<de::IgnoredAny>()?; }
+            }
+        }
+        let action = action.ok_or_else(|| de::Error::missing_field("action"))?;
+        if let Some((_, event)) = parsed { return Ok(event) }
+        let (modern, data) = buffered.ok_or_else(|| de::Error::custom("hover event has neither contents nor value"))?;
+        // the buffered data has already been counted
+        let _guard = ComponentBudgetGuard(COMPONENT_BUDGET.with(|budget| budget.replace(None)), COMPONENT_BUDGET_EXCEEDED.with(Cell::get));
+        HoverDataSeed { action: &action, modern }.deserialize(data).map_err(de::Error::custom)
+    }
+}
+
+/// Deserializes the `contents` or `value` of a hover event with the given action.
+struct HoverDataSeed<'a> {
+    action: &'a str,
+    /// Whether this is `contents` rather than the pre-1.16 `value`.
+    modern: bool,
+}
+
+impl<'de> DeserializeSeed<'de> for HoverDataSeed<'_> {
+    type Value = HoverEvent;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<HoverEvent, D::Error> {
+        Ok(match (self.action, self.modern) {
+            ("show_text", _) => HoverEvent::ShowText(Box::new(TextComponentSeed { scalars: false }.deserialize(deserializer)?)),
+            ("show_item", true) => {
+                let ShowItemContents { id, count, tag, components } = deserializer.deserialize_any(ShowItemContentsVisitor)?;
+                HoverEvent::ShowItem { id, count, tag, components }
+            }
+            ("show_item", false) => {
+                let snbt = String::deserialize(deserializer)?;
+                let LegacyShowItem { id, count, tag } = serde_json::from_value(crate::snbt::from_str(&snbt).map_err(de::Error::custom)?).map_err(de::Error::custom)?;
+                HoverEvent::ShowItem { id, count, tag: tag.map(|tag| tag.to_string()), components: None }
+            }
+            ("show_entity", true) => {
+                let ShowEntityContents { name, entity_type, id } = ShowEntityContents::deserialize(deserializer)?;
+                HoverEvent::ShowEntity { name: name.map(Box::new), entity_type, id }
+            }
+            ("show_entity", false) => {
+                let snbt = String::deserialize(deserializer)?;
+                let LegacyShowEntity { name, entity_type, id } = serde_json::from_value(crate::snbt::from_str(&snbt).map_err(de::Error::custom)?).map_err(de::Error::custom)?;
+                let name = match name {
+                    // in the legacy format, the name is a JSON string inside the SNBT
+                    Some(serde_json::Value::String(name)) => Some(Box::new(Chat::from_json(&name).unwrap_or_else(|_| Chat::from(name)))),
+                    Some(name) => Some(chat_from_value(name).map_err(de::Error::custom)?),
+                    None => None,
+                };
+                HoverEvent::ShowEntity { name, entity_type, id }
+            }
+            (action, _) => return Err(de::Error::custom(format_args!("unknown hover event action: {:?}", action))),
+        })
+    }
+}
+
+/// Deserializes a JSON value, counting each value in it against the component budget.
+#[derive(Clone, Copy)]
+struct BufferedValueSeed;
+
+impl<'de> DeserializeSeed<'de> for BufferedValueSeed {
+    type Value = serde_json::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<serde_json::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for BufferedValueSeed {
+    type Value = serde_json::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "any JSON value")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<serde_json::Value, E> {
+        count_component()?;
+        Ok(serde_json::Value::Null)
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<serde_json::Value, E> {
+        count_component()?;
+        Ok(serde_json::Value::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<serde_json::Value, E> {
+        count_component()?;
+        Ok(serde_json::Value::from(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<serde_json::Value, E> {
+        count_component()?;
+        Ok(serde_json::Value::from(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<serde_json::Value, E> {
+        count_component()?;
+        Ok(serde_json::Value::from(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<serde_json::Value, E> {
+        count_component()?;
+        Ok(serde_json::Value::String(v.to_owned()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<serde_json::Value, E> {
+        count_component()?;
+        Ok(serde_json::Value::String(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<serde_json::Value, A::Error> {
+        count_component()?;
+        let mut items = Vec::default();
+        while let Some(item) = seq.next_element_seed(self)? {
+            items.push(item);
+        }
+        Ok(serde_json::Value::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<serde_json::Value, A::Error> {
+        count_component()?;
+        let mut entries = serde_json::Map::default();
+        while let Some(key) = map.next_key::<String>()? {
+            entries.insert(key, map.next_value_seed(self)?);
+        }
+        Ok(serde_json::Value::Object(entries))
+    }
+}
+
+#[derive(Deserialize)]
+struct ShowItemContents {
+    id: String,
+    #[serde(default, deserialize_with = "util::lenient_i32::deserialize")]
+    count: Option<i32>,
+    tag: Option<String>,
+    components: Option<serde_json::Value>,
+}
+
+/// Deserializes the contents of a `show_item` hover event, which may also be just the item ID.
+struct ShowItemContentsVisitor;
+
+impl<'de> Visitor<'de> for ShowItemContentsVisitor {
+    type Value = ShowItemContents;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an item ID or item stack")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<ShowItemContents, E> {
+        Ok(ShowItemContents { id: v.to_owned(), count: None, tag: None, components: None })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<ShowItemContents, A::Error> {
+        ShowItemContents::deserialize(de::value::MapAccessDeserializer::new(map))
+    }
+}
+
+/// The SNBT-encoded contents of a pre-1.16 `show_item` hover event.
+#[derive(Deserialize)]
+struct LegacyShowItem {
+    id: String,
+    #[serde(rename = "Count", default, deserialize_with = "util::lenient_i32::deserialize")]
+    count: Option<i32>,
+    tag: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct ShowEntityContents {
+    #[serde(default, deserialize_with = "deserialize_optional_text_component")]
+    name: Option<Chat>,
+    #[serde(rename = "type")]
+    entity_type: EntityKind,
+    id: Uuid,
+}
+
+/// The SNBT-encoded contents of a pre-1.16 `show_entity` hover event.
+#[derive(Deserialize)]
+struct LegacyShowEntity {
+    name: Option<serde_json::Value>,
+    #[serde(rename = "type")]
+    entity_type: EntityKind,
+    id: Uuid,
+}
+
+fn deserialize_optional_text_component<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Chat>, D::Error> {
+    TextComponentSeed { scalars: false }.deserialize(deserializer).map(Some)
+}
+
+/// Deserializes a text component, which may also be given as a plain string, and counts it against the component budget.
+fn chat_from_value(value: serde_json::Value) -> Result<Box<Chat>, serde_json::Error> {
+    TextComponentSeed { scalars: false }.deserialize(value).map(Box::new)
+}
+
+/// Deserializes a text component which may also be given as a plain string or a list of components, as in advancements and other data pack files.
+pub(crate) fn deserialize_text_component<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Chat, D::Error> {
+    TextComponentSeed { scalars: false }.deserialize(deserializer)
+}
+
+/// Deserializes a text component in any of its forms directly, without going through a [`serde_json::Value`]. Each component is counted against the component budget.
+#[derive(Clone, Copy)]
+struct TextComponentSeed {
+    /// Whether numbers and booleans are also accepted as plain text, as in translation arguments.
+    scalars: bool,
+}
+
+impl<'de> DeserializeSeed<'de> for TextComponentSeed {
+    type Value = Chat;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Chat, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for TextComponentSeed {
+    type Value = Chat;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a text component")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Chat, E> {
+        self.visit_string(v.to_owned())
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Chat, E> {
+        count_component()?;
+        Ok(Chat::from(v))
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Chat, E> {
+        if !self.scalars { return Err(E::invalid_type(de::Unexpected::Bool(v), &self)) }
+        self.visit_string(v.to_string())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Chat, E> {
+        if !self.scalars { return Err(E::invalid_type(de::Unexpected::Signed(v), &self)) }
+        self.visit_string(v.to_string())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Chat, E> {
+        if !self.scalars { return Err(E::invalid_type(de::Unexpected::Unsigned(v), &self)) }
+        self.visit_string(v.to_string())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Chat, E> {
+        if !self.scalars { return Err(E::invalid_type(de::Unexpected::Float(v), &self)) }
+        // format like JSON, e.g. `1.0` rather than `1`
+        self.visit_string(serde_json::Number::from_f64(v).map_or_else(|| v.to_string(), |n| n.to_string()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Chat, A::Error> {
+        // like in vanilla, the first element of a list is the root and the rest are appended to its extra
+        let mut chat = seq.next_element_seed(TextComponentSeed { scalars: false })?.unwrap_or_default();
+        let rest = collect_components(seq, TextComponentSeed { scalars: false })?;
+        if chat.extra.is_empty() { chat.extra = rest } else { chat.extra.extend(rest) }
+        count_component()?;
+        Ok(chat)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Chat, A::Error> {
+        let chat = Chat::deserialize(de::value::MapAccessDeserializer::new(map))?;
+        count_component()?;
+        Ok(chat)
+    }
+}
+
+thread_local! {
+    /// The components of the `extra` and `with` lists currently being deserialized on this thread, see [`collect_components`].
+    static PENDING_COMPONENTS: RefCell<Vec<Chat>> = RefCell::default();
+}
+
+/// Deserializes the remaining elements of a list of text components into a `Vec` allocated at its final size.
+///
+/// Since JSON lists don't have a size hint, the elements are first collected on a reused thread-local stack. Nested lists are collected above the elements of the outer list and removed before the next element of the outer list is pushed.
+fn collect_components<'de, A: SeqAccess<'de>>(mut seq: A, seed: impl DeserializeSeed<'de, Value = Chat> + Copy) -> Result<Vec<Chat>, A::Error> {
+    /// Removes the elements of a list that failed to deserialize.
+    struct Truncate(usize);
+
+    impl Drop for Truncate {
+        fn drop(&mut self) {
+            PENDING_COMPONENTS.with(|pending| if let Ok(mut pending) = pending.try_borrow_mut() { pending.truncate(self.0) });
+        }
+    }
+
+    let start = PENDING_COMPONENTS.with(|pending| pending.borrow().len());
+    let guard = Truncate(start);
+    while let Some(component) = seq.next_element_seed(seed)? {
+        PENDING_COMPONENTS.with(|pending| pending.borrow_mut().push(component));
+    }
+    mem::forget(guard);
+    Ok(PENDING_COMPONENTS.with(|pending| {
+        let mut pending = pending.borrow_mut();
+        let components = pending.drain(start..).collect();
+        // don't keep the memory used by an unusually large message
+        if start == 0 { pending.shrink_to(MAX_PENDING_CAPACITY) }
+        components
+    }))
+}
+
+/// The capacity of the stack used by [`collect_components`] which is kept between messages.
+const MAX_PENDING_CAPACITY: usize = 256;
+
 /// The [raw JSON text format](https://minecraft.fandom.com/wiki/Raw_JSON_text_format#Java_Edition), also [called Chat](https://wiki.vg/Chat).
 ///
 /// Not yet fully implemented.
-#[derive(Default, Deserialize, Serialize)]
-#[serde(deny_unknown_fields, rename_all = "camelCase")]
+///
+/// The event fields are also accepted in snake_case (`click_event`, `hover_event`) when deserializing, as written by some tools, but always serialized in camelCase:
+///
+/// ```
+/// use minecraft::chat::Chat;
+///
+/// let camel = Chat::from_json(r#"{"text":"hi","clickEvent":{"action":"run_command","value":"/help"}}"#)?;
+/// let snake = Chat::from_json(r#"{"text":"hi","click_event":{"action":"run_command","value":"/help"}}"#)?;
+/// assert_eq!(snake.to_json()?, camel.to_json()?);
+/// assert_eq!(snake.to_json()?, r#"{"text":"hi","clickEvent":{"action":"run_command","value":"/help"}}"#);
+/// # Ok::<(), minecraft::chat::ChatError>(())
+/// ```
+///
+/// Fields not known to this crate, e.g. from newer versions or mods, are an error when deserializing. With the `preserve-unknown-fields` feature, they are instead kept in `ChatDetails::extra_fields` and written back when serializing, so tools like editors can modify a component without losing data they don't understand:
+///
+/// ```
+/// use minecraft::chat::Chat;
+///
+/// let json = r#"{"text":"hi","bold":true,"example:glow":{"radius":2}}"#;
+/// if cfg!(feature = "preserve-unknown-fields") {
+///     let mut chat = Chat::from_json(json)?;
+///     chat.bold = None;
+///     assert_eq!(chat.to_json()?, r#"{"text":"hi","example:glow":{"radius":2}}"#);
+/// } else {
+///     assert!(Chat::from_json(json).is_err());
+/// }
+/// # Ok::<(), minecraft::chat::ChatError>(())
+/// ```
+///
+/// With the `schemars` feature, this type and the types used in its fields implement `JsonSchema`, describing all forms accepted when deserializing.
+///
+/// With the `arbitrary` feature, this type and the types used in its fields implement `Arbitrary` for fuzzing. Generated components round-trip through JSON; see `fuzz/` for a fuzz target checking this.
+///
+/// With the `proptest` feature, the `chat::proptest` module provides strategies for property testing with this type.
+///
+/// Fields are serialized in the order used by vanilla and BungeeCord: `text` first, then formatting, then events, then `extra`. Some consumers compare JSON byte-for-byte, so this order must be kept when adding fields.
+///
+/// ```
+/// use minecraft::chat::{
+///     Chat,
+///     ClickEvent,
+///     HoverEvent,
+///     NamedColor,
+/// };
+///
+/// let mut chat = Chat::from("a");
+/// chat.color(NamedColor::Gold).bold().italic().underlined().strikethrough().obfuscated()
+///     .on_click(ClickEvent::RunCommand(format!("/help")))
+///     .on_hover(HoverEvent::ShowText(Box::new(Chat::from("b"))))
+///     .add_extra("c");
+/// chat.shadow_color = Some(0xff000000);
+/// let details = chat.details_mut();
+/// details.translate = Some(format!("chat.type.text"));
+/// details.with = vec![Chat::from("d")];
+/// details.insertion = Some(format!("e"));
+/// details.font = Some(format!("minecraft:uniform"));
+/// let expected = concat!(
+///     r#"{"text":"a","translate":"chat.type.text","with":[{"text":"d"}],"color":"gold","shadow_color":-16777216,"#,
+///     r#""bold":true,"italic":true,"underlined":true,"strikethrough":true,"obfuscated":true,"#,
+///     r#""clickEvent":{"action":"run_command","value":"/help"},"hoverEvent":{"action":"show_text","contents":{"text":"b"}},"#,
+///     r#""insertion":"e","font":"minecraft:uniform","extra":[{"text":"c"}]}"#,
+/// );
+/// assert_eq!(chat.to_json()?, expected);
+/// assert_eq!(serde_json::to_string(&chat)?, expected);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Deeply nested components
+///
+/// Dropping a `Chat` uses an explicit stack, so components nested arbitrarily deep, e.g. from untrusted input, can be dropped without overflowing the stack. Cloning and JSON output from [`Chat::to_json`] and [`Chat::write_json`] also use an explicit stack for long chains of `extra` components, but the [`Serialize`] implementation recurses once per level of nesting.
+///
+/// ```
+/// use minecraft::chat::{
+///     Chat,
+///     HoverEvent,
+/// };
+///
+/// std::thread::spawn(|| {
+///     let mut chat = Chat::from("end");
+///     for _ in 0..100_000 {
+///         chat = Chat::from("").with_extras(vec![chat]);
+///     }
+///     let json = chat.to_json()?;
+///     assert_eq!(json.len(), chat.json_len());
+///     assert_eq!(chat.clone().json_len(), json.len());
+///
+///     // dropping translation arguments and hover text is iterative too
+///     for i in 0..100_000 {
+///         let mut parent = Chat::from("");
+///         if i % 2 == 0 {
+///             parent.details_mut().translate = Some(format!("chat.type.text"));
+///             parent.details_mut().with = vec![chat];
+///         } else {
+///             parent.on_hover(HoverEvent::ShowText(Box::new(chat)));
+///         }
+///         chat = parent;
+///     }
+///     drop(chat);
+///     Ok::<(), minecraft::chat::ChatError>(())
+/// }).join().unwrap()?;
+/// # Ok::<(), minecraft::chat::ChatError>(())
+/// ```
+///
+/// Since every run of differently formatted text is a separate component, the rarely used fields are stored in a separate allocation, see [`ChatDetails`].
+#[derive(Default, Deserialize)]
+#[serde(from = "ChatRepr")]
 pub struct Chat {
     /// The plain text of this text component.
     pub text: String,
-    /// Text components displayed after the main `text`. The main formatting is inherited unless specified otherwise.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub extra: Vec<Chat>,
     /// The text color.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub color: Option<Color>,
+    /// The color of the text shadow as ARGB, added in 1.21.4. Fully transparent hides the shadow.
+    pub shadow_color: Option<u32>,
     /// Whether to render the content in boldface.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub bold: Option<bool>,
     /// Whether to render the content in italics.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub italic: Option<bool>,
     /// Whether to underline the content.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub underlined: Option<bool>,
     /// Whether to strike through the content.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub strikethrough: Option<bool>,
     /// Whether to render the content obfuscated, i.e. with characters randomly replaced with others of the same width.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub obfuscated: Option<bool>,
+    /// The rarely used fields. `None` is equivalent to all of them being unset, see [`Chat::details`] and [`Chat::details_mut`].
+    pub details: Option<Box<ChatDetails>>,
+    /// Text components displayed after the main `text`. The main formatting is inherited unless specified otherwise.
+    pub extra: Vec<Chat>,
+}
+
+const _: () = assert!(size_of::<Chat>() <= 80, "Chat has grown, consider moving the new field to ChatDetails");
+
+impl Drop for Chat {
+    /// Drops `with`, `extra`, and hover text using an explicit stack, so deeply nested components don't overflow the stack.
+    fn drop(&mut self) {
+        let mut stack = Vec::default();
+        self.take_children(&mut stack);
+        while let Some(mut chat) = stack.pop() {
+            chat.take_children(&mut stack);
+        }
+    }
+}
+
+impl Clone for Chat {
+    /// Clones `extra` using an explicit stack, so long chains of components don't overflow the stack.
+    fn clone(&self) -> Chat {
+        fn shallow(chat: &Chat) -> Chat {
+            Chat {
+                text: chat.text.clone(),
+                color: chat.color,
+                shadow_color: chat.shadow_color,
+                bold: chat.bold,
+                italic: chat.italic,
+                underlined: chat.underlined,
+                strikethrough: chat.strikethrough,
+                obfuscated: chat.obfuscated,
+                details: chat.details.clone(),
+                extra: Vec::with_capacity(chat.extra.len()),
+            }
+        }
+
+        let mut stack = vec![(self.extra.iter(), shallow(self))];
+        loop {
+            let (children, _) = stack.last_mut().expect("stack is only empty after returning");
+            if let Some(child) = children.next() {
+                stack.push((child.extra.iter(), shallow(child)));
+            } else {
+                let (_, done) = stack.pop().expect("stack is only empty after returning");
+                match stack.last_mut() {
+                    Some((_, parent)) => parent.extra.push(done),
+                    None => return done,
+                }
+            }
+        }
+    }
+}
+
+/// The fields of a [`Chat`] which are rarely set.
+///
+/// These are serialized at the top level of the component like the other fields.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::chat::{
+///     Chat,
+///     ClickEvent,
+/// };
+///
+/// let mut chat = Chat::from_json(r#"{"text":"hi","insertion":"hello"}"#)?;
+/// assert_eq!(chat.details().insertion.as_deref(), Some("hello"));
+/// assert!(chat.details().click_event.is_none());
+/// chat.details_mut().click_event = Some(ClickEvent::RunCommand(format!("/help")));
+/// assert_eq!(chat.to_json()?, r#"{"text":"hi","clickEvent":{"action":"run_command","value":"/help"},"insertion":"hello"}"#);
+/// assert!(Chat::from("plain").details.is_none());
+/// # Ok::<(), minecraft::chat::ChatError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChatDetails {
+    /// The translation key of a translatable component. If set, the translated string is displayed instead of `text`.
+    pub translate: Option<String>,
+    /// The arguments substituted into the translated string in place of `%s` and `%1$s` placeholders.
+    pub with: Vec<Chat>,
     /// The action to perform when this text component is clicked.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub click_event: Option<ClickEvent>,
     /// The action to perform when a player hovers over this text component.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub hover_event: Option<HoverEvent>,
+    /// Text inserted into the chat input when this text component is shift-clicked.
+    pub insertion: Option<String>,
+    /// The resource location of the font, e.g. `minecraft:uniform`. The default font is used if unset.
+    pub font: Option<String>,
+    /// Fields not known to this crate, which are kept when deserializing and written back, after the known fields, when serializing.
+    #[cfg(feature = "preserve-unknown-fields")]
+    pub extra_fields: BTreeMap<String, serde_json::Value>,
+}
+
+/// Returned by [`Chat::details`] for components without details.
+static EMPTY_DETAILS: ChatDetails = ChatDetails {
+    translate: None,
+    with: Vec::new(),
+    click_event: None,
+    hover_event: None,
+    insertion: None,
+    font: None,
+    #[cfg(feature = "preserve-unknown-fields")]
+    extra_fields: BTreeMap::new(),
+};
+
+impl ChatDetails {
+    fn is_empty(&self) -> bool {
+        self.translate.is_none()
+        && self.with.is_empty()
+        && self.click_event.is_none()
+        && self.hover_event.is_none()
+        && self.insertion.is_none()
+        && self.font.is_none()
+        && self.extra_fields_empty()
+    }
+
+    #[cfg(feature = "preserve-unknown-fields")]
+    fn extra_fields_empty(&self) -> bool { self.extra_fields.is_empty() }
+
+    #[cfg(not(feature = "preserve-unknown-fields"))]
+    fn extra_fields_empty(&self) -> bool { true }
+
+    /// Boxes these fields for storing in [`Chat::details`], or returns `None` if they're all unset.
+    fn into_box(self) -> Option<Box<ChatDetails>> {
+        if self.is_empty() { None } else { Some(Box::new(self)) }
+    }
 }
 
-impl Chat {
+/// The JSON representation of a [`Chat`], with all fields at the top level.
+///
+/// Unknown fields are an error unless the `preserve-unknown-fields` feature is enabled.
+#[derive(Deserialize)]
+#[cfg_attr(not(feature = "preserve-unknown-fields"), serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+struct ChatRepr {
+    #[serde(default)]
+    text: String,
+    translate: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_with_args")]
+    with: Vec<Chat>,
+    color: Option<Color>,
+    #[serde(rename = "shadow_color", default, with = "shadow_color")]
+    shadow_color: Option<u32>,
+    bold: Option<bool>,
+    italic: Option<bool>,
+    underlined: Option<bool>,
+    strikethrough: Option<bool>,
+    obfuscated: Option<bool>,
+    #[serde(alias = "click_event")]
+    click_event: Option<ClickEvent>,
+    #[serde(alias = "hover_event")]
+    hover_event: Option<HoverEvent>,
+    insertion: Option<String>,
+    font: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_extra")]
+    extra: Vec<Chat>,
+    #[cfg(feature = "preserve-unknown-fields")]
+    #[serde(flatten)]
+    extra_fields: BTreeMap<String, serde_json::Value>,
+}
+
+impl From<ChatRepr> for Chat {
+    fn from(repr: ChatRepr) -> Chat {
+        Chat {
+            text: repr.text,
+            color: repr.color,
+            shadow_color: repr.shadow_color,
+            bold: repr.bold,
+            italic: repr.italic,
+            underlined: repr.underlined,
+            strikethrough: repr.strikethrough,
+            obfuscated: repr.obfuscated,
+            details: ChatDetails {
+                translate: repr.translate,
+                with: repr.with,
+                click_event: repr.click_event,
+                hover_event: repr.hover_event,
+                insertion: repr.insertion,
+                font: repr.font,
+                #[cfg(feature = "preserve-unknown-fields")]
+                extra_fields: repr.extra_fields,
+            }.into_box(),
+            extra: repr.extra,
+        }
+    }
+}
+
+thread_local! {
+    /// The number of components [`Chat::from_json_limited`] still allows on this thread, or `None` if not limited.
+    static COMPONENT_BUDGET: Cell<Option<usize>> = const { Cell::new(None) };
+    /// Set when [`COMPONENT_BUDGET`] runs out, to distinguish this from other deserialization errors.
+    static COMPONENT_BUDGET_EXCEEDED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Counts one deserialized component against the [`COMPONENT_BUDGET`], if any.
+fn count_component<E: de::Error>() -> Result<(), E> {
+    COMPONENT_BUDGET.with(|budget| match budget.get() {
+        None => Ok(()),
+        Some(0) => {
+            COMPONENT_BUDGET_EXCEEDED.with(|exceeded| exceeded.set(true));
+            Err(E::custom("too many text components"))
+        }
+        Some(remaining) => {
+            budget.set(Some(remaining - 1));
+            Ok(())
+        }
+    })
+}
+
+/// (De)serializes an ARGB shadow color, which is written as a signed integer but may also be given as a list of four floats from 0 to 1 in RGBA order.
+mod shadow_color {
+    use {
+        std::{
+            convert::TryFrom as _,
+            fmt,
+        },
+        serde::{
+            Deserializer,
+            Serializer,
+            de::{
+                self,
+                SeqAccess,
+                Visitor,
+            },
+        },
+    };
+
+    struct ShadowColorVisitor;
+
+    impl<'de> Visitor<'de> for ShadowColorVisitor {
+        type Value = Option<u32>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "an ARGB integer or a list of four floats")
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Option<u32>, E> {
+            i32::try_from(v).map(|v| Some(v as u32)).or_else(|_| u32::try_from(v).map(Some)).map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Option<u32>, E> {
+            u32::try_from(v).map(Some).map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Option<u32>, A::Error> {
+            let mut rgba = [0; 4];
+            for (idx, channel) in rgba.iter_mut().enumerate() {
+                let value = seq.next_element::<f32>()?.ok_or_else(|| de::Error::invalid_length(idx, &self))?;
+                *channel = (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+            }
+            if seq.next_element::<de::IgnoredAny>()?.is_some() { return Err(de::Error::invalid_length(5, &self)) }
+            let [r, g, b, a] = rgba;
+            Ok(Some(u32::from_be_bytes([a, r, g, b])))
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Option<u32>, E> { Ok(None) }
+        fn visit_unit<E: de::Error>(self) -> Result<Option<u32>, E> { Ok(None) }
+    }
+
+    pub(super) fn serialize<S: Serializer>(color: &Option<u32>, serializer: S) -> Result<S::Ok, S::Error> {
+        match color {
+            Some(color) => serializer.serialize_i32(*color as i32),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u32>, D::Error> {
+        deserializer.deserialize_any(ShadowColorVisitor)
+    }
+}
+
+/// Deserializes translation arguments, which may also be given as strings, numbers, or booleans.
+fn deserialize_with_args<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Chat>, D::Error> {
+    struct ArgsVisitor;
+
+    impl<'de> Visitor<'de> for ArgsVisitor {
+        type Value = Vec<Chat>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a list of translation arguments")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Vec<Chat>, A::Error> {
+            collect_components(seq, TextComponentSeed { scalars: true })
+        }
+    }
+
+    deserializer.deserialize_seq(ArgsVisitor)
+}
+
+fn deserialize_extra<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Chat>, D::Error> {
+    struct ExtraVisitor;
+
+    impl<'de> Visitor<'de> for ExtraVisitor {
+        type Value = Vec<Chat>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a list of text components")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Vec<Chat>, A::Error> {
+            collect_components(seq, ExtraSeed)
+        }
+    }
+
+    /// Deserializes an element of `extra`, which must be an object.
+    #[derive(Clone, Copy)]
+    struct ExtraSeed;
+
+    impl<'de> DeserializeSeed<'de> for ExtraSeed {
+        type Value = Chat;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Chat, D::Error> {
+            let component = Chat::deserialize(deserializer)?;
+            count_component()?;
+            Ok(component)
+        }
+    }
+
+    deserializer.deserialize_seq(ExtraVisitor)
+}
+
+/// Restores the previous component budget when dropped.
+struct ComponentBudgetGuard(Option<usize>, bool);
+
+impl Drop for ComponentBudgetGuard {
+    fn drop(&mut self) {
+        COMPONENT_BUDGET.with(|budget| budget.set(self.0));
+        COMPONENT_BUDGET_EXCEEDED.with(|exceeded| exceeded.set(self.1));
+    }
+}
+
+impl Chat {
+    /// Parses a text component from JSON.
+    pub fn from_json(s: &str) -> Result<Chat, ChatError> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// Parses a text component from JSON, failing as soon as more than `max_components` components have been read.
+    ///
+    /// Components are counted the same way as in [`Chat::component_count`]. This protects servers processing untrusted input from payloads consisting of huge numbers of tiny components. Legitimate messages rarely exceed a few hundred components even with per-character gradients, so a limit around 1000–10000 is a safe choice for chat messages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     ChatError,
+    /// };
+    ///
+    /// let json = r#"{"text":"a","extra":[{"text":"b"},{"text":"c"}]}"#;
+    /// assert_eq!(Chat::from_json_limited(json, 3)?.component_count(), 3);
+    /// assert!(matches!(Chat::from_json_limited(json, 2), Err(ChatError::TooManyComponents(2))));
+    ///
+    /// // translation arguments and hover text count too
+    /// let json = r#"{"translate":"chat.type.text","with":["Notch",{"text":"hi"}],"hoverEvent":{"action":"show_text","contents":{"text":"","extra":[{"text":"x"}]}}}"#;
+    /// assert_eq!(Chat::from_json(json)?.component_count(), 5);
+    /// assert!(Chat::from_json_limited(json, 5).is_ok());
+    /// assert!(matches!(Chat::from_json_limited(json, 4), Err(ChatError::TooManyComponents(4))));
+    ///
+    /// // hover contents are counted while they're read, even if given before the action
+    /// let hover = format!(r#"{{"text":"","extra":[{}]}}"#, vec![r#"{"text":"x"}"#; 100].join(","));
+    /// for json in [
+    ///     format!(r#"{{"text":"","hoverEvent":{{"action":"show_text","contents":{}}}}}"#, hover),
+    ///     format!(r#"{{"text":"","hoverEvent":{{"contents":{},"action":"show_text"}}}}"#, hover),
+    ///     format!(r#"{{"text":"","hoverEvent":{{"action":"show_entity","contents":{{"type":"minecraft:pig","id":"0b1d3e5f-0000-4000-8000-000000000000","name":{}}}}}}}"#, hover),
+    /// ] {
+    ///     assert_eq!(Chat::from_json(&json)?.component_count(), 102);
+    ///     assert!(matches!(Chat::from_json_limited(&json, 10), Err(ChatError::TooManyComponents(10))));
+    /// }
+    ///
+    /// // the limit doesn't leak into later calls after an error
+    /// let long = format!(r#"{{"text":"","extra":[{}]}}"#, vec![r#"{"text":"x"}"#; 100].join(","));
+    /// assert!(matches!(Chat::from_json_limited(&long, 10), Err(ChatError::TooManyComponents(10))));
+    /// assert_eq!(Chat::from_json(&long)?.component_count(), 101);
+    /// assert!(Chat::from_json_limited(&long, 101).is_ok());
+    /// # Ok::<(), ChatError>(())
+    /// ```
+    pub fn from_json_limited(s: &str, max_components: usize) -> Result<Chat, ChatError> {
+        let _guard = ComponentBudgetGuard(
+            COMPONENT_BUDGET.with(|budget| budget.replace(Some(max_components))),
+            COMPONENT_BUDGET_EXCEEDED.with(|exceeded| exceeded.replace(false)),
+        );
+        let result = serde_json::from_str(s).and_then(|chat| count_component().map(|()| chat));
+        if COMPONENT_BUDGET_EXCEEDED.with(Cell::get) {
+            Err(ChatError::TooManyComponents(max_components))
+        } else {
+            Ok(result?)
+        }
+    }
+
+    /// Parses the arguments of a `/tellraw` command, e.g. from a `.mcfunction` file, into the target and the text component.
+    ///
+    /// The line may start with the command name, with or without a slash, or directly with the target. The target is kept as written and may be a player name or a selector, including arguments containing spaces like `@a[tag=a, limit=1]`. The rest of the line is parsed as JSON, which may also be a plain string or a list of components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::Chat;
+    ///
+    /// let (target, chat) = Chat::parse_tellraw(r#"tellraw @a[tag=admin, limit=1]  {"text":"Hello world","color":"red"}"#)?;
+    /// assert_eq!(target, "@a[tag=admin, limit=1]");
+    /// assert_eq!(chat.text, "Hello world");
+    ///
+    /// let (target, chat) = Chat::parse_tellraw(r#"/tellraw Notch ["a", {"text":"b"}]"#)?;
+    /// assert_eq!(target, "Notch");
+    /// assert_eq!(chat.to_json()?, r#"{"text":"a","extra":[{"text":"b"}]}"#);
+    ///
+    /// assert_eq!(Chat::parse_tellraw(r#"@s "hi""#)?.1.text, "hi");
+    /// assert!(Chat::parse_tellraw("tellraw @a").is_err());
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn parse_tellraw(line: &str) -> Result<(String, Chat), ChatError> {
+        let malformed = || ChatError::MalformedCommand(line.to_owned());
+        let mut rest = line.trim();
+        rest = rest.strip_prefix('/').unwrap_or(rest);
+        for command in ["tellraw", "minecraft:tellraw"] {
+            if let Some(args) = rest.strip_prefix(command).filter(|args| args.starts_with(char::is_whitespace)) {
+                rest = args.trim_start();
+                break
+            }
+        }
+        // the target ends at the first whitespace outside of selector arguments
+        let mut depth = 0usize;
+        let mut quote = None;
+        let mut escaped = false;
+        let end = rest.char_indices().find(|&(_, c)| {
+            match (quote, c) {
+                (Some(_), _) if escaped => escaped = false,
+                (Some(_), '\\') => escaped = true,
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') => quote = Some(c),
+                (None, '[' | '{') => depth += 1,
+                (None, ']' | '}') => depth = depth.saturating_sub(1),
+                (None, c) => return depth == 0 && c.is_whitespace(),
+            }
+            false
+        }).map(|(idx, _)| idx).ok_or_else(malformed)?;
+        let (target, json) = rest.split_at(end);
+        let json = json.trim();
+        if target.is_empty() || json.is_empty() { return Err(malformed()) }
+        Ok((target.to_owned(), *chat_from_value(serde_json::from_str(json)?)?))
+    }
+
+    /// The total number of components in this tree, including this one, everything in `extra`, and text shown on hover.
+    pub fn component_count(&self) -> usize {
+        let details = self.details();
+        let hover = match &details.hover_event {
+            Some(HoverEvent::ShowText(text)) => text.component_count(),
+            Some(HoverEvent::ShowEntity { name: Some(name), .. }) => name.component_count(),
+            _ => 0,
+        };
+        1 + hover + details.with.iter().chain(&self.extra).map(Chat::component_count).sum::<usize>()
+    }
+
+    /// The distinct colors set explicitly anywhere in this tree, including translation arguments and text shown on hover.
+    ///
+    /// Inherited colors are not resolved, and [`Color::Reset`] and [`Color::None`] are included if set. See [`Chat::color_counts`] for the number of components using each color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     Color,
+    ///     NamedColor,
+    /// };
+    ///
+    /// let chat = Chat::from_json(r##"{"text":"","color":"gold","extra":[{"text":"a","color":"#ff55aa"},{"text":"b"},{"text":"c","color":"gold","hoverEvent":{"action":"show_text","contents":{"text":"d","color":"red"}}}]}"##)?;
+    /// assert_eq!(chat.colors_used().into_iter().collect::<Vec<_>>(), [Color::Named(NamedColor::Gold), Color::Named(NamedColor::Red), Color::Hex([0xff, 0x55, 0xaa])]);
+    /// assert_eq!(chat.color_counts()[&Color::Named(NamedColor::Gold)], 2);
+    /// assert!(Chat::from("plain").colors_used().is_empty());
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn colors_used(&self) -> BTreeSet<Color> {
+        self.color_counts().into_keys().collect()
+    }
+
+    /// The number of components in this tree which explicitly set each color, counted the same way as [`Chat::colors_used`].
+    pub fn color_counts(&self) -> BTreeMap<Color, usize> {
+        fn visit(chat: &Chat, counts: &mut BTreeMap<Color, usize>) {
+            if let Some(color) = chat.color {
+                *counts.entry(color).or_default() += 1;
+            }
+            let details = chat.details();
+            match &details.hover_event {
+                Some(HoverEvent::ShowText(text)) => visit(text, counts),
+                Some(HoverEvent::ShowEntity { name: Some(name), .. }) => visit(name, counts),
+                _ => {}
+            }
+            for child in details.with.iter().chain(&chat.extra) {
+                visit(child, counts);
+            }
+        }
+
+        let mut counts = BTreeMap::default();
+        visit(self, &mut counts);
+        counts
+    }
+
+    /// Serializes this text component to compact JSON.
+    ///
+    /// This is the same as the [`Display`](fmt::Display) output, but preserves the error.
+    pub fn to_json(&self) -> Result<String, ChatError> {
+        let mut json = String::default();
+        self.serialize_into(&mut json, serde_json::ser::CompactFormatter, &JsonOptions::default())?;
+        Ok(json)
+    }
+
+    /// Serializes this text component to compact JSON with the given options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     JsonOptions,
+    /// };
+    ///
+    /// let chat = Chat::from_json(r#"{"text":"a","bold":false,"italic":true,"extra":[{"text":"b","italic":true},{"text":"c","italic":false}]}"#)?;
+    /// let options = JsonOptions { omit_redundant_flags: true };
+    /// assert_eq!(chat.to_json_with(&options)?, r#"{"text":"a","italic":true,"extra":[{"text":"b"},{"text":"c","italic":false}]}"#);
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn to_json_with(&self, options: &JsonOptions) -> Result<String, ChatError> {
+        let mut json = String::default();
+        self.serialize_into(&mut json, serde_json::ser::CompactFormatter, options)?;
+        Ok(json)
+    }
+
+    /// Serializes this text component to indented JSON, e.g. for debugging or files meant to be edited by hand.
+    pub fn to_pretty_json(&self) -> Result<String, ChatError> {
+        let mut json = String::default();
+        self.serialize_into(&mut json, serde_json::ser::PrettyFormatter::new(), &JsonOptions::default())?;
+        Ok(json)
+    }
+
+    /// Serializes this text component as compact JSON with all non-ASCII characters escaped as `\uXXXX`.
+    ///
+    /// Characters outside the Basic Multilingual Plane are written as UTF-16 surrogate pairs, like in Java. The result is still valid JSON and deserializes to the same component, but can be passed through systems which only handle ASCII.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::Chat;
+    ///
+    /// let chat = Chat::from("Grüße 🐝");
+    /// let json = chat.to_json_ascii_safe()?;
+    /// assert_eq!(json, r#"{"text":"Gr\u00fc\u00dfe \ud83d\udc1d"}"#);
+    /// assert_eq!(Chat::from_json(&json)?.to_json()?, chat.to_json()?);
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn to_json_ascii_safe(&self) -> Result<String, ChatError> {
+        let mut json = String::default();
+        self.serialize_into(&mut json, AsciiSafeFormatter, &JsonOptions::default())?;
+        Ok(json)
+    }
+
+    /// Writes this text component as compact JSON to `writer`, e.g. directly into a packet buffer.
+    ///
+    /// The output is the same as that of [`Chat::to_json`], and its length is [`Chat::json_len`].
+    pub fn write_json(&self, writer: impl io::Write) -> Result<(), ChatError> {
+        self.write_with(writer, serde_json::ser::CompactFormatter, &JsonOptions::default())
+    }
+
+    /// The length in bytes of this text component as compact JSON, e.g. for the length prefix of a string in a packet, computed without producing the JSON.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::Chat;
+    ///
+    /// for json in [
+    ///     r#"{"text":"plain"}"#,
+    ///     r##"{"text":"Grüße \"🐝\"\n\u0000","color":"#ff55aa","shadow_color":[1.0,0.5,0.0,1.0]}"##,
+    ///     r#"{"translate":"chat.type.text","with":[{"text":"Notch","hoverEvent":{"action":"show_entity","contents":{"type":"minecraft:player","id":"069a79f4-44e9-4726-a5be-fca90e38aaf5","name":{"text":"Notch","hoverEvent":{"action":"show_text","contents":"ü"}}}}},42]}"#,
+    ///     r#"{"text":"","extra":[{"text":"item","hoverEvent":{"action":"show_item","value":"{id:\"minecraft:stone\",Count:2b}"}}]}"#,
+    /// ] {
+    ///     let chat = Chat::from_json(json)?;
+    ///     let mut buf = Vec::default();
+    ///     chat.write_json(&mut buf)?;
+    ///     assert_eq!(chat.json_len(), buf.len());
+    ///     assert_eq!(buf, chat.to_json()?.into_bytes());
+    /// }
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn json_len(&self) -> usize {
+        let mut counter = util::CountingWriter::default();
+        self.write_json(&mut counter).expect("writing to a CountingWriter doesn't fail");
+        counter.0
+    }
+
+    /// Moves the components in `with`, `extra`, and hover text to `stack`, so they can be dropped without recursion.
+    fn take_children(&mut self, stack: &mut Vec<Chat>) {
+        stack.append(&mut self.extra);
+        if let Some(ref mut details) = self.details {
+            stack.append(&mut details.with);
+            match details.hover_event.take() {
+                Some(HoverEvent::ShowText(mut text)) => stack.push(mem::take(&mut *text)),
+                Some(HoverEvent::ShowEntity { name: Some(mut name), .. }) => stack.push(mem::take(&mut *name)),
+                Some(HoverEvent::ShowEntity { name: None, .. }) | Some(HoverEvent::ShowItem { .. }) | None => {}
+            }
+        }
+    }
+
+    /// Serializes this text component as JSON directly into `out`, without an intermediate buffer.
+    fn serialize_into(&self, out: impl fmt::Write, formatter: impl serde_json::ser::Formatter + Clone, options: &JsonOptions) -> Result<(), ChatError> {
+        let mut writer = util::FmtWriter::new(out);
+        self.write_with(&mut writer, formatter, options)?;
+        writer.flush().map_err(serde_json::Error::io)?;
+        Ok(())
+    }
+
+    /// Serializes this text component as JSON into `writer`. All JSON output goes through here.
+    ///
+    /// Unlike the [`Serialize`] implementation, this uses an explicit stack for `with` and `extra`, so long chains of components don't overflow the stack.
+    fn write_with<F: serde_json::ser::Formatter + Clone>(&self, mut writer: impl io::Write, mut formatter: F, options: &JsonOptions) -> Result<(), ChatError> {
+        /// The flags inherited by a component if [`JsonOptions::omit_redundant_flags`] is set, in the order of `Chat::flags`.
+        type Inherited = Option<[bool; 5]>;
+
+        enum Step<'a> {
+            /// Opens the component and writes the fields before `with`.
+            Start(&'a Chat, Inherited),
+            /// Writes the fields between `with` and `extra`.
+            Middle(&'a Chat, Inherited),
+            /// Closes the component.
+            End(&'a Chat),
+            /// Writes the remaining components of a `with` or `extra` list.
+            Items(slice::Iter<'a, Chat>, bool, Inherited),
+            /// Closes an element of a `with` or `extra` list.
+            EndItem,
+        }
+
+        fn write_key<W: io::Write, F: serde_json::ser::Formatter + Clone>(writer: &mut W, formatter: &mut F, first: &mut bool, key: &str) -> Result<(), serde_json::Error> {
+            formatter.begin_object_key(writer, *first).map_err(serde_json::Error::io)?;
+            *first = false;
+            key.serialize(&mut serde_json::Serializer::with_formatter(&mut *writer, formatter.clone()))?;
+            formatter.end_object_key(writer).map_err(serde_json::Error::io)?;
+            formatter.begin_object_value(writer).map_err(serde_json::Error::io)
+        }
+
+        fn write_entry<W: io::Write, F: serde_json::ser::Formatter + Clone>(writer: &mut W, formatter: &mut F, first: &mut bool, key: &str, value: Field<'_>) -> Result<(), serde_json::Error> {
+            write_key(writer, formatter, first, key)?;
+            value.serialize(&mut serde_json::Serializer::with_formatter(&mut *writer, formatter.clone()))?;
+            formatter.end_object_value(writer).map_err(serde_json::Error::io)
+        }
+
+        fn resolve(chat: &Chat, inherited: Inherited) -> Inherited {
+            let flags = chat.flags();
+            inherited.map(|inherited| [0, 1, 2, 3, 4].map(|idx| flags[idx].unwrap_or(inherited[idx])))
+        }
+
+        let mut stack = vec![Step::Start(self, if options.omit_redundant_flags { Some([false; 5]) } else { None })];
+        while let Some(step) = stack.pop() {
+            match step {
+                Step::Start(chat, inherited) => {
+                    let details = chat.details();
+                    formatter.begin_object(&mut writer).map_err(serde_json::Error::io)?;
+                    let mut first = true;
+                    chat.for_each_head_field(|key, value| write_entry(&mut writer, &mut formatter, &mut first, key, value))?;
+                    stack.push(Step::Middle(chat, inherited));
+                    if !details.with.is_empty() {
+                        write_key(&mut writer, &mut formatter, &mut first, "with")?;
+                        formatter.begin_array(&mut writer).map_err(serde_json::Error::io)?;
+                        stack.push(Step::Items(details.with.iter(), true, resolve(chat, inherited)));
+                    }
+                }
+                Step::Middle(chat, inherited) => {
+                    if !chat.details().with.is_empty() {
+                        formatter.end_array(&mut writer).map_err(serde_json::Error::io)?;
+                        formatter.end_object_value(&mut writer).map_err(serde_json::Error::io)?;
+                    }
+                    // text or translate was written
+                    let mut first = false;
+                    chat.for_each_middle_field(inherited, |key, value| write_entry(&mut writer, &mut formatter, &mut first, key, value))?;
+                    stack.push(Step::End(chat));
+                    if !chat.extra.is_empty() {
+                        write_key(&mut writer, &mut formatter, &mut first, "extra")?;
+                        formatter.begin_array(&mut writer).map_err(serde_json::Error::io)?;
+                        stack.push(Step::Items(chat.extra.iter(), true, resolve(chat, inherited)));
+                    }
+                }
+                Step::End(chat) => {
+                    if !chat.extra.is_empty() {
+                        formatter.end_array(&mut writer).map_err(serde_json::Error::io)?;
+                        formatter.end_object_value(&mut writer).map_err(serde_json::Error::io)?;
+                    }
+                    formatter.end_object(&mut writer).map_err(serde_json::Error::io)?;
+                }
+                Step::Items(mut items, first, inherited) => if let Some(item) = items.next() {
+                    formatter.begin_array_value(&mut writer, first).map_err(serde_json::Error::io)?;
+                    stack.push(Step::Items(items, false, inherited));
+                    stack.push(Step::EndItem);
+                    stack.push(Step::Start(item, inherited));
+                },
+                Step::EndItem => formatter.end_array_value(&mut writer).map_err(serde_json::Error::io)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// The formatting flags in the order they're serialized: `bold`, `italic`, `underlined`, `strikethrough`, and `obfuscated`.
+    fn flags(&self) -> [Option<bool>; 5] {
+        [self.bold, self.italic, self.underlined, self.strikethrough, self.obfuscated]
+    }
+
+    /// Calls `f` with the fields serialized before `with`. `text` is omitted from translatable components if it's empty, since its presence would make vanilla treat the component as plain text.
+    fn for_each_head_field<E>(&self, mut f: impl FnMut(&str, Field<'_>) -> Result<(), E>) -> Result<(), E> {
+        let details = self.details();
+        if details.translate.is_none() || !self.text.is_empty() { f("text", Field::Str(&self.text))? }
+        if let Some(ref translate) = details.translate { f("translate", Field::Str(translate))? }
+        Ok(())
+    }
+
+    /// Calls `f` with the fields serialized between `with` and `extra`, omitting unset ones as well as flags equal to the `inherited` ones if given.
+    fn for_each_middle_field<E>(&self, inherited: Option<[bool; 5]>, mut f: impl FnMut(&str, Field<'_>) -> Result<(), E>) -> Result<(), E> {
+        let details = self.details();
+        if let Some(ref color) = self.color { f("color", Field::Color(color))? }
+        if let Some(shadow_color) = self.shadow_color { f("shadow_color", Field::ShadowColor(shadow_color))? }
+        for (idx, (name, flag)) in ["bold", "italic", "underlined", "strikethrough", "obfuscated"].iter().zip(self.flags()).enumerate() {
+            if let Some(flag) = flag {
+                if inherited.is_none_or(|inherited| inherited[idx] != flag) { f(name, Field::Bool(flag))? }
+            }
+        }
+        if let Some(ref click_event) = details.click_event { f("clickEvent", Field::ClickEvent(click_event))? }
+        if let Some(ref hover_event) = details.hover_event { f("hoverEvent", Field::HoverEvent(hover_event))? }
+        if let Some(ref insertion) = details.insertion { f("insertion", Field::Str(insertion))? }
+        if let Some(ref font) = details.font { f("font", Field::Str(font))? }
+        #[cfg(feature = "preserve-unknown-fields")] for (key, value) in &details.extra_fields { f(key, Field::Unknown(value))? }
+        Ok(())
+    }
+
+    /// Creates an empty component which explicitly turns off all formatting and resets the color, like the `§r` [legacy formatting code](legacy).
+    ///
+    /// Children of this component are displayed without formatting even if the component itself is a child of formatted text.
+    pub fn reset_component() -> Chat {
+        Chat {
+            text: String::default(),
+            color: Some(Color::Reset),
+            shadow_color: None,
+            bold: Some(false),
+            italic: Some(false),
+            underlined: Some(false),
+            strikethrough: Some(false),
+            obfuscated: Some(false),
+            details: None,
+            extra: Vec::default(),
+        }
+    }
+
+    /// Converts text with [legacy formatting codes](legacy) like `§c` to a text component.
+    ///
+    /// Text without formatting codes becomes a single plain component. Otherwise, each run of text with the same formatting becomes a child of an empty root component, with only the formatting turned on by codes set explicitly. Malformed codes are removed like in the vanilla client, see [`legacy::Scanner::remove_unknown_codes`].
+    pub fn from_legacy(text: &str) -> Chat {
+        let mut chat = Chat::default();
+        let mut prev_style = (None, legacy::StyleFlags::default());
+        for span in legacy::Scanner::new(text).remove_unknown_codes() {
+            if span.text.is_empty() { continue }
+            let style = (span.color, span.flags);
+            if style == prev_style {
+                // consecutive spans can have the same style if separated by unknown or redundant codes
+                match chat.extra.last_mut() {
+                    Some(last) => last.text.push_str(span.text),
+                    None => chat.text.push_str(span.text),
+                }
+            } else {
+                prev_style = style;
+                chat.extra.push(Chat {
+                    text: span.text.to_owned(),
+                    color: span.color,
+                    shadow_color: None,
+                    bold: if span.flags.bold { Some(true) } else { None },
+                    italic: if span.flags.italic { Some(true) } else { None },
+                    underlined: if span.flags.underlined { Some(true) } else { None },
+                    strikethrough: if span.flags.strikethrough { Some(true) } else { None },
+                    obfuscated: if span.flags.obfuscated { Some(true) } else { None },
+                    details: None,
+                    extra: Vec::default(),
+                });
+            }
+        }
+        chat
+    }
+
+    /// Converts this text component to text with [legacy formatting codes](legacy), e.g. for plugins or clients which don't support text components.
+    ///
+    /// Click and hover events are lost. If `hex` is true, hex colors are written in the BungeeCord format supported by Spigot and its forks; otherwise they are replaced with the nearest named color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     Color,
+    /// };
+    ///
+    /// let chat = Chat::from_legacy("§x§f§f§5§5§a§aHello §lworld");
+    /// assert_eq!(chat.extra[0].color, Some(Color::Hex([0xff, 0x55, 0xaa])));
+    /// assert_eq!(chat.to_legacy(true), "§x§f§f§5§5§a§aHello §lworld");
+    ///
+    /// let mut red = Chat::from("warning");
+    /// red.color(Color::Hex([0xff, 0x50, 0x50]));
+    /// assert_eq!(red.to_legacy(false), "§cwarning");
+    /// ```
+    pub fn to_legacy(&self, hex: bool) -> String {
+        legacy::write_runs(self.runs(), hex)
+    }
+
+    /// Creates a message preceded by a prefix like `[Server]`, separated by a space.
+    ///
+    /// The prefix, space, and message are siblings in the `extra` list of an empty root component, so the prefix's formatting does not leak into the message.
+    pub fn prefixed(prefix: impl Into<Chat>, message: impl Into<Chat>) -> Chat {
+        Chat::default().with_extras(vec![prefix.into(), Chat::from(" "), message.into()])
+    }
+
+    /// Creates a list of differently colored items, e.g. player names on a leaderboard, joined by a separator.
+    ///
+    /// The items and separators are siblings in the `extra` list of an empty root component, so neither the items' colors nor the separator's formatting leak into each other.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     Color,
+    ///     NamedColor,
+    /// };
+    ///
+    /// let list = Chat::colored_list(&[
+    ///     (format!("red"), Color::Named(NamedColor::Red)),
+    ///     (format!("green"), Color::Named(NamedColor::Green)),
+    ///     (format!("blue"), Color::Named(NamedColor::Blue)),
+    /// ], ", ");
+    /// assert_eq!(list.to_legacy(false), "§cred§r, §agreen§r, §9blue");
+    /// ```
+    pub fn colored_list(items: &[(String, Color)], separator: impl Into<Chat>) -> Chat {
+        let separator = separator.into();
+        let mut extra = Vec::with_capacity(items.len() * 2);
+        for (idx, (text, color)) in items.iter().enumerate() {
+            if idx > 0 { extra.push(separator.clone()) }
+            let mut item = Chat::from(text.clone());
+            item.color(*color);
+            extra.push(item);
+        }
+        Chat::default().with_extras(extra)
+    }
+
+    /// Creates a green `x y z` component for the given coordinates which suggests a command to teleport there when clicked and shows the dimension when hovered over.
+    pub fn coordinate(pos: BlockPos, dimension: Dimension) -> Chat {
+        let coords = format!("{} {} {}", pos.x, pos.y, pos.z);
+        let mut chat = Chat::from(coords.clone());
+        chat.color(NamedColor::Green)
+            .on_click(ClickEvent::SuggestCommand(format!("/execute in {} run tp @s {}", dimension, coords)))
+            .on_hover(HoverEvent::ShowText(Box::new(Chat::from(dimension.to_string()))));
+        chat
+    }
+
     /// Adds a text component to the `extra` list.
     pub fn add_extra(&mut self, extra: impl Into<Chat>) -> &mut Chat {
         self.extra.push(extra.into());
         self
     }
 
+    /// Appends the given text components to the `extra` list.
+    pub fn extend_extras(&mut self, extras: impl IntoIterator<Item = Chat>) -> &mut Chat {
+        self.extra.extend(extras);
+        self
+    }
+
+    /// Appends the given text components to the `extra` list, taking and returning ownership.
+    ///
+    /// This is useful for building a component in a single expression, e.g. `Chat::from("Players: ").with_extras(names)`.
+    pub fn with_extras(mut self, extras: impl IntoIterator<Item = Chat>) -> Chat {
+        self.extra.extend(extras);
+        self
+    }
+
     /// Replaces the `extra` list with the given text components.
     pub fn set_extras(&mut self, extras: Vec<Chat>) -> &mut Chat {
         self.extra = extras;
@@ -121,8 +2112,8 @@ impl Chat {
     }
 
     /// Sets the color of the text.
-    pub fn color(&mut self, color: Color) -> &mut Chat {
-        self.color = Some(color);
+    pub fn color(&mut self, color: impl Into<Color>) -> &mut Chat {
+        self.color = Some(color.into());
         self
     }
 
@@ -138,6 +2129,27 @@ impl Chat {
         self
     }
 
+    /// Explicitly enables or disables boldface.
+    ///
+    /// Unlike [`Chat::bold`] and [`Chat::no_bold`], this is convenient when the formatting comes from configuration or user input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::Chat;
+    ///
+    /// let highlight = true;
+    /// let mut chat = Chat::from("note");
+    /// chat.set_bold(highlight).set_italic(!highlight);
+    /// assert_eq!(chat.to_json()?, r#"{"text":"note","bold":true,"italic":false}"#);
+    /// assert_eq!(Chat::from("note").with_bold_set(false).to_json()?, r#"{"text":"note","bold":false}"#);
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn set_bold(&mut self, on: bool) -> &mut Chat {
+        self.bold = Some(on);
+        self
+    }
+
     /// Enables italics.
     pub fn italic(&mut self) -> &mut Chat {
         self.italic = Some(true);
@@ -150,6 +2162,12 @@ impl Chat {
         self
     }
 
+    /// Explicitly enables or disables italics.
+    pub fn set_italic(&mut self, on: bool) -> &mut Chat {
+        self.italic = Some(on);
+        self
+    }
+
     /// Enables underline.
     pub fn underlined(&mut self) -> &mut Chat {
         self.underlined = Some(true);
@@ -162,6 +2180,12 @@ impl Chat {
         self
     }
 
+    /// Explicitly enables or disables underline.
+    pub fn set_underlined(&mut self, on: bool) -> &mut Chat {
+        self.underlined = Some(on);
+        self
+    }
+
     /// Enables strike-through.
     pub fn strikethrough(&mut self) -> &mut Chat {
         self.strikethrough = Some(true);
@@ -174,6 +2198,12 @@ impl Chat {
         self
     }
 
+    /// Explicitly enables or disables strike-through.
+    pub fn set_strikethrough(&mut self, on: bool) -> &mut Chat {
+        self.strikethrough = Some(on);
+        self
+    }
+
     /// Enables obfuscation.
     pub fn obfuscated(&mut self) -> &mut Chat {
         self.obfuscated = Some(true);
@@ -186,45 +2216,1344 @@ impl Chat {
         self
     }
 
+    /// Explicitly enables or disables obfuscation.
+    pub fn set_obfuscated(&mut self, on: bool) -> &mut Chat {
+        self.obfuscated = Some(on);
+        self
+    }
+
+    /// The rarely used fields of this component.
+    pub fn details(&self) -> &ChatDetails {
+        self.details.as_deref().unwrap_or(&EMPTY_DETAILS)
+    }
+
+    /// Mutable access to the rarely used fields of this component, allocating them if necessary.
+    pub fn details_mut(&mut self) -> &mut ChatDetails {
+        self.details.get_or_insert_with(Box::default)
+    }
+
+    /// The translation key, see [`ChatDetails::translate`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     ClickEvent,
+    /// };
+    ///
+    /// let mut chat = Chat::from_json(r#"{"translate":"chat.type.text","with":["Notch","hi"],"insertion":"Notch"}"#)?;
+    /// assert_eq!(chat.translate(), Some("chat.type.text"));
+    /// assert_eq!(chat.with().len(), 2);
+    /// assert_eq!(chat.insertion(), Some("Notch"));
+    /// assert!(chat.click_event().is_none() && chat.hover_event().is_none() && chat.font().is_none());
+    /// *chat.click_event_mut() = Some(ClickEvent::SuggestCommand(format!("/msg Notch ")));
+    /// chat.with_mut().pop();
+    /// *chat.insertion_mut() = None;
+    /// assert_eq!(chat.to_json()?, r#"{"translate":"chat.type.text","with":[{"text":"Notch"}],"clickEvent":{"action":"suggest_command","value":"/msg Notch "}}"#);
+    /// // reading doesn't allocate details
+    /// let plain = Chat::from("plain");
+    /// assert!(plain.translate().is_none() && plain.with().is_empty());
+    /// assert!(plain.details.is_none());
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn translate(&self) -> Option<&str> {
+        self.details().translate.as_deref()
+    }
+
+    /// Mutable access to the translation key, allocating the details if necessary.
+    pub fn translate_mut(&mut self) -> &mut Option<String> {
+        &mut self.details_mut().translate
+    }
+
+    /// The translation arguments, see [`ChatDetails::with`].
+    pub fn with(&self) -> &[Chat] {
+        &self.details().with
+    }
+
+    /// Mutable access to the translation arguments, allocating the details if necessary.
+    pub fn with_mut(&mut self) -> &mut Vec<Chat> {
+        &mut self.details_mut().with
+    }
+
+    /// The action to perform when clicked, see [`ChatDetails::click_event`].
+    pub fn click_event(&self) -> Option<&ClickEvent> {
+        self.details().click_event.as_ref()
+    }
+
+    /// Mutable access to the action to perform when clicked, allocating the details if necessary.
+    pub fn click_event_mut(&mut self) -> &mut Option<ClickEvent> {
+        &mut self.details_mut().click_event
+    }
+
+    /// The action to perform when hovered over, see [`ChatDetails::hover_event`].
+    pub fn hover_event(&self) -> Option<&HoverEvent> {
+        self.details().hover_event.as_ref()
+    }
+
+    /// Mutable access to the action to perform when hovered over, allocating the details if necessary.
+    pub fn hover_event_mut(&mut self) -> &mut Option<HoverEvent> {
+        &mut self.details_mut().hover_event
+    }
+
+    /// The text inserted when shift-clicked, see [`ChatDetails::insertion`].
+    pub fn insertion(&self) -> Option<&str> {
+        self.details().insertion.as_deref()
+    }
+
+    /// Mutable access to the text inserted when shift-clicked, allocating the details if necessary.
+    pub fn insertion_mut(&mut self) -> &mut Option<String> {
+        &mut self.details_mut().insertion
+    }
+
+    /// The resource location of the font, see [`ChatDetails::font`].
+    pub fn font(&self) -> Option<&str> {
+        self.details().font.as_deref()
+    }
+
+    /// Mutable access to the resource location of the font, allocating the details if necessary.
+    pub fn font_mut(&mut self) -> &mut Option<String> {
+        &mut self.details_mut().font
+    }
+
     /// Sets the action to perform when clicked.
     pub fn on_click(&mut self, event: ClickEvent) -> &mut Chat {
-        self.click_event = Some(event);
+        self.details_mut().click_event = Some(event);
         self
     }
 
     /// Sets the action to perform when hovered over.
-    pub fn on_hover(&mut self, event: HoverEvent) -> &mut Chat {
-        self.hover_event = Some(event);
+    pub fn on_hover(&mut self, event: impl Into<HoverEvent>) -> &mut Chat {
+        self.details_mut().hover_event = Some(event.into());
+        self
+    }
+
+    /// Sets the color of the text, taking and returning ownership.
+    pub fn with_color(mut self, color: impl Into<Color>) -> Chat {
+        self.color(color);
+        self
+    }
+
+    /// Explicitly enables or disables boldface, taking and returning ownership.
+    pub fn with_bold_set(mut self, on: bool) -> Chat {
+        self.set_bold(on);
+        self
+    }
+
+    /// Explicitly enables or disables italics, taking and returning ownership.
+    pub fn with_italic_set(mut self, on: bool) -> Chat {
+        self.set_italic(on);
+        self
+    }
+
+    /// Explicitly enables or disables underline, taking and returning ownership.
+    pub fn with_underlined_set(mut self, on: bool) -> Chat {
+        self.set_underlined(on);
+        self
+    }
+
+    /// Explicitly enables or disables strike-through, taking and returning ownership.
+    pub fn with_strikethrough_set(mut self, on: bool) -> Chat {
+        self.set_strikethrough(on);
+        self
+    }
+
+    /// Explicitly enables or disables obfuscation, taking and returning ownership.
+    pub fn with_obfuscated_set(mut self, on: bool) -> Chat {
+        self.set_obfuscated(on);
+        self
+    }
+
+    /// Sets the action to perform when clicked, taking and returning ownership.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     ClickEvent,
+    ///     HoverEvent,
+    ///     NamedColor,
+    /// };
+    ///
+    /// let link = Chat::from("wiki")
+    ///     .with_color(NamedColor::Aqua)
+    ///     .with_click(ClickEvent::OpenUrl(format!("https://wiki.wurstmineberg.de/")))
+    ///     .with_hover(HoverEvent::ShowText(Box::new(Chat::from("Open the wiki"))));
+    /// assert_eq!(link.to_json()?, r#"{"text":"wiki","color":"aqua","clickEvent":{"action":"open_url","value":"https://wiki.wurstmineberg.de/"},"hoverEvent":{"action":"show_text","contents":{"text":"Open the wiki"}}}"#);
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn with_click(mut self, event: ClickEvent) -> Chat {
+        self.on_click(event);
+        self
+    }
+
+    /// Sets the action to perform when hovered over, taking and returning ownership.
+    pub fn with_hover(mut self, event: impl Into<HoverEvent>) -> Chat {
+        self.on_hover(event);
+        self
+    }
+
+    /// The formatting fields of this component, not including inherited formatting.
+    pub fn style(&self) -> Style {
+        Style {
+            color: self.color,
+            shadow_color: self.shadow_color,
+            bold: self.bold,
+            italic: self.italic,
+            underlined: self.underlined,
+            strikethrough: self.strikethrough,
+            obfuscated: self.obfuscated,
+            insertion: self.details().insertion.clone(),
+            font: self.details().font.clone(),
+        }
+    }
+
+    /// Replaces all formatting fields of this component with those of `style`, e.g. to apply a theme.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     NamedColor,
+    ///     Style,
+    /// };
+    ///
+    /// let theme = Style { color: Some(NamedColor::Gold.into()), italic: Some(true), font: Some(format!("minecraft:uniform")), ..Style::default() };
+    /// let mut chat = Chat::from("hi");
+    /// chat.bold().set_style(theme.clone());
+    /// assert_eq!(chat.bold, None);
+    /// assert_eq!(chat.style(), theme);
+    /// assert_eq!(chat.to_json()?, r#"{"text":"hi","color":"gold","italic":true,"font":"minecraft:uniform"}"#);
+    ///
+    /// let shadow = Chat::from_json(r#"{"text":"hi","shadow_color":[1.0,0.0,0.0,1.0],"insertion":"hello"}"#)?;
+    /// assert_eq!(shadow.style().shadow_color, Some(0xffff0000));
+    /// assert_eq!(shadow.to_json()?, r#"{"text":"hi","shadow_color":-65536,"insertion":"hello"}"#);
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn set_style(&mut self, style: Style) -> &mut Chat {
+        let Style { color, shadow_color, bold, italic, underlined, strikethrough, obfuscated, insertion, font } = style;
+        self.color = color;
+        self.shadow_color = shadow_color;
+        self.bold = bold;
+        self.italic = italic;
+        self.underlined = underlined;
+        self.strikethrough = strikethrough;
+        self.obfuscated = obfuscated;
+        if self.details.is_some() || insertion.is_some() || font.is_some() {
+            let details = self.details_mut();
+            details.insertion = insertion;
+            details.font = font;
+        }
         self
     }
+
+    /// Whether this component and all its descendants consist only of text, with no formatting or events.
+    ///
+    /// This is the condition under which the component can be represented as a plain string, e.g. `"text"` instead of `{"text":"text"}`.
+    pub fn is_plain(&self) -> bool {
+        self.color.is_none()
+        && self.shadow_color.is_none()
+        && self.bold.is_none()
+        && self.italic.is_none()
+        && self.underlined.is_none()
+        && self.strikethrough.is_none()
+        && self.obfuscated.is_none()
+        && self.details().is_empty()
+        && self.extra.iter().all(Chat::is_plain)
+    }
+
+    /// Returns the beginning of the visible text of this component, for previews in places like notifications or chat history lists.
+    ///
+    /// Text is read in render order. Leading whitespace is skipped and runs of whitespace, including newlines, are collapsed to a single space. If the text is longer than `max_chars` characters, it is cut off at that length and `…` is appended.
+    pub fn preview(&self, max_chars: usize) -> String {
+        struct Preview {
+            text: String,
+            len: usize,
+            pending_space: bool,
+            truncated: bool,
+        }
+
+        fn visit(chat: &Chat, preview: &mut Preview, max_chars: usize) {
+            for c in chat.text.chars() {
+                if c.is_whitespace() {
+                    preview.pending_space = preview.len > 0;
+                    continue
+                }
+                if preview.len + usize::from(preview.pending_space) >= max_chars {
+                    preview.truncated = true;
+                    return
+                }
+                if preview.pending_space {
+                    preview.text.push(' ');
+                    preview.len += 1;
+                    preview.pending_space = false;
+                }
+                preview.text.push(c);
+                preview.len += 1;
+            }
+            for extra in &chat.extra {
+                if preview.truncated { return }
+                visit(extra, preview, max_chars);
+            }
+        }
+
+        let mut preview = Preview { text: String::default(), len: 0, pending_space: false, truncated: false };
+        visit(self, &mut preview, max_chars);
+        if preview.truncated { preview.text.push('…') }
+        preview.text
+    }
+
+    /// Whether the visible text of this component is longer than [`MAX_CHAT_LENGTH`] characters, so it can't be sent as a single chat message.
+    ///
+    /// The length is that of the text of all [`runs`](Chat::runs) in Unicode scalar values. Translatable components don't count, since the length of a translation depends on the client's language.
+    pub fn exceeds_chat_limit(&self) -> bool {
+        self.runs().map(|(text, _)| text.chars().count()).sum::<usize>() > MAX_CHAT_LENGTH
+    }
+
+    /// Splits this component into messages with at most `max_chars` characters of visible text each, to be sent in order, e.g. when forwarding a long message from another chat platform.
+    ///
+    /// Messages are split at whitespace where possible, which is dropped at the split, and words longer than `max_chars` are split anywhere. Each message is an empty component whose `extra` contains a component for each piece of text, with the formatting, events, insertion, and font it inherited in the original tree. Translatable components are kept whole and not counted, like in [`Chat::exceeds_chat_limit`]. A component which doesn't need to be split is returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If `max_chars` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     NamedColor,
+    /// };
+    ///
+    /// let chat = Chat::from("Hello there, ").with_extras(vec![Chat::from("general Kenobi").with_color(NamedColor::Red)]);
+    /// let messages = chat.split_into_messages(12);
+    /// assert_eq!(messages.len(), 3);
+    /// assert_eq!(messages[0].to_json()?, r#"{"text":"","extra":[{"text":"Hello there,"}]}"#);
+    /// assert_eq!(messages[1].to_json()?, r#"{"text":"","extra":[{"text":"general","color":"red"}]}"#);
+    /// assert_eq!(messages[2].to_json()?, r#"{"text":"","extra":[{"text":"Kenobi","color":"red"}]}"#);
+    /// assert!(messages.iter().all(|message| !message.exceeds_chat_limit()));
+    ///
+    /// let long = Chat::from("x".repeat(300));
+    /// assert!(long.exceeds_chat_limit());
+    /// assert_eq!(long.split_into_messages(minecraft::chat::MAX_CHAT_LENGTH).iter().map(|message| message.runs().map(|(text, _)| text.len()).sum::<usize>()).collect::<Vec<_>>(), [256, 44]);
+    ///
+    /// let blank = Chat::from_json(&format!(r#"{{"text":"{}","extra":[{{"translate":"chat.type.text"}}]}}"#, " ".repeat(300)))?;
+    /// let messages = blank.split_into_messages(minecraft::chat::MAX_CHAT_LENGTH);
+    /// assert_eq!(messages.len(), 1);
+    /// assert_eq!(messages[0].to_json()?, r#"{"text":"","extra":[{"translate":"chat.type.text"}]}"#);
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn split_into_messages(&self, max_chars: usize) -> Vec<Chat> {
+        /// Collects the text and translatable components in the tree as components with the style and events they inherit, each with the offset of its text in `chars`.
+        fn flatten(chat: &Chat, parent: &Chat, pieces: &mut Vec<(usize, Chat)>, chars: &mut Vec<char>) {
+            let details = chat.details();
+            let mut inherited = Chat::default();
+            inherited.set_style(chat.style().inherit_from(&parent.style()));
+            if let Some(click_event) = details.click_event.as_ref().or(parent.details().click_event.as_ref()) { inherited.on_click(click_event.clone()); }
+            if let Some(hover_event) = details.hover_event.as_ref().or(parent.details().hover_event.as_ref()) { inherited.on_hover(hover_event.clone()); }
+            if let Some(ref translate) = details.translate {
+                let mut piece = inherited.clone();
+                piece.details_mut().translate = Some(translate.clone());
+                piece.details_mut().with = details.with.clone();
+                pieces.push((chars.len(), piece));
+            } else if !chat.text.is_empty() {
+                let mut piece = inherited.clone();
+                piece.text = chat.text.clone();
+                pieces.push((chars.len(), piece));
+                chars.extend(chat.text.chars());
+            }
+            for extra in &chat.extra {
+                flatten(extra, &inherited, pieces, chars);
+            }
+        }
+
+        assert!(max_chars > 0, "messages must allow at least one character");
+        let mut pieces = Vec::default();
+        let mut chars = Vec::default();
+        flatten(self, &Chat::default(), &mut pieces, &mut chars);
+        if chars.len() <= max_chars { return vec![self.clone()] }
+        let mut ranges = Vec::default();
+        let mut start = chars.iter().take_while(|c| c.is_whitespace()).count();
+        while start < chars.len() {
+            let mut end = start + max_chars;
+            if end >= chars.len() {
+                end = chars.len();
+            } else if let Some(space) = (start + 1..=end).rev().find(|&idx| chars[idx].is_whitespace()) {
+                end = space;
+                while chars[end - 1].is_whitespace() { end -= 1 }
+            }
+            ranges.push(start..end);
+            start = end;
+            while chars.get(start).is_some_and(|c| c.is_whitespace()) { start += 1 }
+        }
+        // if the text is all whitespace, only the translatable components are kept, as a single message
+        if ranges.is_empty() { ranges.push(chars.len()..chars.len()) }
+        let mut messages = vec![Chat::default(); ranges.len()];
+        for (offset, piece) in pieces {
+            if piece.details().translate.is_some() {
+                // place translatable components with the text following them, or at the end
+                let idx = ranges.iter().position(|range| offset < range.end).unwrap_or(ranges.len() - 1);
+                messages[idx].extra.push(piece);
+                continue
+            }
+            let end = offset + piece.text.chars().count();
+            for (range, message) in ranges.iter().zip(&mut messages) {
+                let (from, to) = (offset.max(range.start), end.min(range.end));
+                if from < to {
+                    let mut part = piece.clone();
+                    part.text = chars[from..to].iter().collect();
+                    message.extra.push(part);
+                }
+            }
+        }
+        messages
+    }
+
+    /// Renders this component as a single line of plain text for log files, using the default [`LogLineOptions`].
+    ///
+    /// See [`Chat::to_log_line_with`] for details.
+    pub fn to_log_line(&self) -> String {
+        self.to_log_line_with(&LogLineOptions::default())
+    }
+
+    /// Renders this component as a single line of plain text for log files, i.e. what a human would read in chat.
+    ///
+    /// Formatting is dropped, including [legacy formatting codes](legacy) in the text. Depending on `options`, ` (<url>)` is appended after components which open a URL when clicked and ` [hover]` after components with a hover event. Translatable components are written as their translation key followed by their arguments in square brackets, so they should be [resolved](Chat::resolve_translations) first if translations are available. The result never contains control characters: line breaks and tabs are replaced with spaces, ANSI escape sequences like `ESC[1m` are removed entirely, and other control characters are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     LogLineOptions,
+    /// };
+    ///
+    /// let chat = Chat::from_json(r#"{"text":"§cSee\n","extra":[{"text":"the wiki","clickEvent":{"action":"open_url","value":"https://minecraft.wiki/"},"hoverEvent":{"action":"show_text","contents":"click me"}},{"text":" or \u001b[1mask\u001b[0m"},{"translate":"chat.type.text","with":["Notch"]}]}"#)?;
+    /// assert_eq!(chat.to_log_line(), "See the wiki (https://minecraft.wiki/) or askchat.type.text[Notch]");
+    /// assert_eq!(chat.to_log_line_with(&LogLineOptions { links: false, hover: true }), "See the wiki [hover] or askchat.type.text[Notch]");
+    /// assert_eq!(Chat::from("\u{1b}]8;;https://example.com\u{7}link\u{1b}]8;;\u{1b}\\ \u{1b}[38;5;196mred\u{1b}[m \u{1b}[12").to_log_line(), "link red [12");
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn to_log_line_with(&self, options: &LogLineOptions) -> String {
+        /// Skips the rest of a complete ANSI escape sequence following an escape character, i.e. a CSI sequence like `ESC[1m` up to its final byte or an OSC sequence like a hyperlink up to its terminator. Otherwise, only the escape character is dropped.
+        fn skip_escape(chars: &mut Chars<'_>) {
+            let mut rest = chars.clone();
+            let complete = match rest.next() {
+                Some('[') => rest.find(|c| !matches!(c, '\x20'..='\x3f')).is_some_and(|c| matches!(c, '\x40'..='\x7e')),
+                Some(']') => loop {
+                    match rest.next() {
+                        Some('\x07') => break true,
+                        Some('\x1b') => break rest.next() == Some('\\'),
+                        Some(_) => {}
+                        None => break false,
+                    }
+                },
+                _ => false,
+            };
+            if complete { *chars = rest }
+        }
+
+        fn push_text(line: &mut String, text: &str) {
+            for span in legacy::Scanner::new(text).remove_unknown_codes() {
+                let mut chars = span.text.chars();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\x1b' => skip_escape(&mut chars),
+                        '\n' | '\r' | '\t' => line.push(' '),
+                        c if c.is_control() => {}
+                        c => line.push(c),
+                    }
+                }
+            }
+        }
+
+        fn visit(chat: &Chat, options: &LogLineOptions, line: &mut String) {
+            let details = chat.details();
+            if let Some(ref key) = details.translate {
+                push_text(line, key);
+                if !details.with.is_empty() {
+                    line.push('[');
+                    for (idx, arg) in details.with.iter().enumerate() {
+                        if idx > 0 { line.push_str(", ") }
+                        visit(arg, options, line);
+                    }
+                    line.push(']');
+                }
+            } else {
+                push_text(line, &chat.text);
+            }
+            for extra in &chat.extra {
+                visit(extra, options, line);
+            }
+            if options.links {
+                if let Some(ClickEvent::OpenUrl(ref url)) = details.click_event {
+                    line.push_str(" (");
+                    push_text(line, url);
+                    line.push(')');
+                }
+            }
+            if options.hover && details.hover_event.is_some() { line.push_str(" [hover]") }
+        }
+
+        let mut line = String::default();
+        visit(self, options, &mut line);
+        line
+    }
+
+    /// Returns a copy of this text component where `http://` and `https://` URLs in the text are split into separate components which open the URL when clicked.
+    ///
+    /// Links are styled blue and underlined, like in vanilla chat. Trailing punctuation is not considered part of a URL, except for closing parentheses matching opening ones in the URL. Components which already have a click event, color, or underline setting are left alone, along with their children.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     ClickEvent,
+    /// };
+    ///
+    /// fn links(text: &str) -> Vec<String> {
+    ///     Chat::from(text).linkify().extra.iter().filter_map(|extra| match extra.click_event() {
+    ///         Some(ClickEvent::OpenUrl(url)) => Some(url.clone()),
+    ///         _ => None,
+    ///     }).collect()
+    /// }
+    ///
+    /// assert_eq!(links("see https://wurstmineberg.de/."), ["https://wurstmineberg.de/"]);
+    /// assert_eq!(links("(see https://wurstmineberg.de/)"), ["https://wurstmineberg.de/"]);
+    /// assert_eq!(links("https://en.wikipedia.org/wiki/Foo_(disambiguation)"), ["https://en.wikipedia.org/wiki/Foo_(disambiguation)"]);
+    /// assert_eq!(links("(https://en.wikipedia.org/wiki/Foo_(disambiguation)), http://example.com"), ["https://en.wikipedia.org/wiki/Foo_(disambiguation)", "http://example.com"]);
+    /// assert_eq!(links("https:// is not a link"), Vec::<String>::default());
+    /// ```
+    pub fn linkify(&self) -> Chat {
+        let mut linked = self.clone();
+        if self.details().click_event.is_some() || self.color.is_some() || self.underlined.is_some() { return linked }
+        let mut segments = split_urls(&self.text).into_iter();
+        let mut extra = Vec::with_capacity(self.extra.len());
+        linked.text = match segments.next() {
+            Some((first, false)) => first.to_owned(),
+            Some((first, true)) => {
+                extra.push(Chat::link(first));
+                String::default()
+            }
+            None => String::default(),
+        };
+        for (segment, is_url) in segments {
+            extra.push(if is_url { Chat::link(segment) } else { Chat::from(segment) });
+        }
+        extra.extend(self.extra.iter().map(Chat::linkify));
+        linked.extra = extra;
+        linked
+    }
+
+    /// Returns a copy of this text component with `f` applied to the text of every component in the tree, including text shown on hover.
+    ///
+    /// The structure and formatting are unchanged, and `f` only sees the raw text of one component at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use minecraft::chat::Chat;
+    /// let mut chat = Chat::from("darn it, ");
+    /// chat.add_extra("darn it all");
+    /// let censored = chat.map_text(|text| text.replace("darn", "****"));
+    /// assert_eq!(censored.text, "**** it, ");
+    /// assert_eq!(censored.extra[0].text, "**** it all");
+    /// ```
+    pub fn map_text(&self, f: impl Fn(&str) -> String) -> Chat {
+        self.map_text_dyn(&f)
+    }
+
+    fn map_text_dyn(&self, f: &dyn Fn(&str) -> String) -> Chat {
+        let details = self.details();
+        let mut mapped = self.clone_without_children();
+        mapped.text = f(&self.text);
+        mapped.extra = self.extra.iter().map(|extra| extra.map_text_dyn(f)).collect();
+        mapped.set_with_and_hover(
+            details.with.iter().map(|arg| arg.map_text_dyn(f)).collect(),
+            details.hover_event.as_ref().map(|hover_event| hover_event.map_components(&|chat| chat.map_text_dyn(f))),
+        );
+        mapped
+    }
+
+    /// Returns a copy of this text component with every line break in the text of the tree replaced with `replacement`, for places which only display a single line like the action bar.
+    ///
+    /// Translation arguments are included, but text shown on hover is not, since tooltips can have multiple lines. Translation keys are resolved by the client, so line breaks in translations are only replaced after [resolving them](Chat::resolve_translations). A single space is a good default replacement.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::Chat;
+    ///
+    /// let chat = Chat::from_json(r#"{"text":"Welcome!\n","extra":[{"translate":"motd","with":["line 1\r\nline 2"]}]}"#)?;
+    /// assert_eq!(chat.single_line(" | ").to_json()?, r#"{"text":"Welcome! | ","extra":[{"translate":"motd","with":[{"text":"line 1 | line 2"}]}]}"#);
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn single_line(&self, replacement: &str) -> Chat {
+        let details = self.details();
+        let mut single_line = self.clone_without_children();
+        single_line.text = self.text.replace("\r\n", "\n").replace('\n', replacement);
+        single_line.extra = self.extra.iter().map(|extra| extra.single_line(replacement)).collect();
+        single_line.set_with_and_hover(details.with.iter().map(|arg| arg.single_line(replacement)).collect(), details.hover_event.clone());
+        single_line
+    }
+
+    /// Returns a copy of this text component with translatable components replaced by their translations, e.g. to display messages without the game's language files.
+    ///
+    /// A translatable component is replaced by one with the same formatting whose children are the pieces of the translation followed by its own children, so arguments inherit its formatting like in vanilla. Arguments and text shown on hover are resolved as well. Translations with invalid placeholders are used literally.
+    ///
+    /// Since translation maps and messages can come from untrusted sources, this fails with [`ChatError::TranslationCycle`] if an argument uses the translation key of a component it's an argument of, and with [`ChatError::RecursionLimitExceeded`] or [`ChatError::TooManyComponents`] if the given limits are exceeded. Missing translations fail with [`ChatError::UnresolvedTranslation`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use {
+    ///     std::collections::HashMap,
+    ///     minecraft::chat::{
+    ///         Chat,
+    ///         ChatError,
+    ///     },
+    /// };
+    ///
+    /// let translations = vec![
+    ///     (format!("chat.type.text"), format!("<%s> %s")),
+    ///     (format!("quote"), format!("%1$s said: %2$s")),
+    /// ].into_iter().collect::<HashMap<_, _>>();
+    /// let chat = Chat::from_json(r#"{"translate":"chat.type.text","with":["Notch","hi"]}"#)?;
+    /// assert_eq!(chat.resolve_translations(&translations, &Default::default())?.runs().map(|(text, _)| text).collect::<String>(), "<Notch> hi");
+    ///
+    /// let cyclic = Chat::from_json(r#"{"translate":"quote","with":["Notch",{"translate":"quote","with":["jeb_","hi"]}]}"#)?;
+    /// assert!(matches!(cyclic.resolve_translations(&translations, &Default::default()), Err(ChatError::TranslationCycle(key)) if key == "quote"));
+    /// # Ok::<(), ChatError>(())
+    /// ```
+    pub fn resolve_translations(&self, translations: &HashMap<String, String>, limits: &TranslationLimits) -> Result<Chat, ChatError> {
+        self.resolve_translations_with(&|key| translations.get(key).map(String::as_str), limits)
+    }
+
+    /// Implementation of [`Chat::resolve_translations`] and [`Translator::resolve`], looking up translations with `lookup`.
+    fn resolve_translations_with<'t>(&self, lookup: &dyn Fn(&str) -> Option<&'t str>, limits: &TranslationLimits) -> Result<Chat, ChatError> {
+        fn spend(budget: &mut usize, count: usize, limits: &TranslationLimits) -> Result<(), ChatError> {
+            *budget = budget.checked_sub(count).ok_or(ChatError::TooManyComponents(limits.max_components))?;
+            Ok(())
+        }
+
+        fn resolve<'a, 't>(chat: &'a Chat, lookup: &dyn Fn(&str) -> Option<&'t str>, limits: &TranslationLimits, depth: usize, keys: &mut Vec<&'a str>, budget: &mut usize) -> Result<Chat, ChatError> {
+            if depth > limits.max_depth { return Err(ChatError::RecursionLimitExceeded(limits.max_depth)) }
+            spend(budget, 1, limits)?;
+            let details = chat.details();
+            let mut resolved = chat.clone_without_children();
+            let hover_event = match &details.hover_event {
+                Some(HoverEvent::ShowText(text)) => Some(HoverEvent::ShowText(Box::new(resolve(text, lookup, limits, depth + 1, keys, budget)?))),
+                Some(HoverEvent::ShowEntity { name: Some(name), entity_type, id }) => Some(HoverEvent::ShowEntity {
+                    name: Some(Box::new(resolve(name, lookup, limits, depth + 1, keys, budget)?)),
+                    entity_type: entity_type.clone(),
+                    id: *id,
+                }),
+                hover_event => hover_event.clone(),
+            };
+            resolved.set_with_and_hover(Vec::default(), hover_event);
+            if let Some(key) = &details.translate {
+                if keys.contains(&&**key) { return Err(ChatError::TranslationCycle(key.clone())) }
+                let format = lookup(key).ok_or_else(|| ChatError::UnresolvedTranslation(key.clone()))?;
+                keys.push(key);
+                let args = details.with.iter().map(|arg| resolve(arg, lookup, limits, depth + 1, keys, budget)).collect::<Result<Vec<_>, _>>()?;
+                keys.pop();
+                let pieces = parse_translation(format, args.len()).unwrap_or_else(|| vec![TranslationPiece::Text(Cow::Borrowed(format))]);
+                resolved.details = resolved.details.take().and_then(|details| ChatDetails { translate: None, ..*details }.into_box());
+                resolved.text = String::default();
+                let mut placed = vec![false; args.len()];
+                for piece in pieces {
+                    match piece {
+                        TranslationPiece::Text(text) => {
+                            spend(budget, 1, limits)?;
+                            resolved.extra.push(Chat::from(text.into_owned()));
+                        }
+                        TranslationPiece::Arg(idx) => {
+                            // the first use of each argument was already counted when resolving it
+                            if placed[idx] { spend(budget, args[idx].component_count(), limits)?; }
+                            placed[idx] = true;
+                            resolved.extra.push(args[idx].clone());
+                        }
+                    }
+                }
+            }
+            for extra in &chat.extra {
+                resolved.extra.push(resolve(extra, lookup, limits, depth + 1, keys, budget)?);
+            }
+            Ok(resolved)
+        }
+
+        let mut budget = limits.max_components;
+        resolve(self, lookup, limits, 0, &mut Vec::default(), &mut budget)
+    }
+
+    /// Returns a copy of this text component suitable for storing in logs or databases, without any interactive payloads.
+    ///
+    /// Colors and formatting are kept for readability, but click and hover events and insertions are removed from the entire tree, including translation arguments. Child components which display nothing, i.e. have no text, no translation key, no fields not known to this crate (like `keybind` or `score` with the `preserve-unknown-fields` feature), and no children of their own, are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::Chat;
+    ///
+    /// let chat = Chat::from_json(r#"{"text":"a","extra":[{"text":"","italic":true},{"text":"b","clickEvent":{"action":"run_command","value":"/help"},"insertion":"b"}]}"#)?;
+    /// assert_eq!(chat.for_logging().to_json()?, r#"{"text":"a","extra":[{"text":"b"}]}"#);
+    ///
+    /// let json = r#"{"text":"a","extra":[{"text":"","keybind":"key.jump"}]}"#;
+    /// if cfg!(feature = "preserve-unknown-fields") {
+    ///     assert_eq!(Chat::from_json(json)?.for_logging().to_json()?, json);
+    /// }
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn for_logging(&self) -> Chat {
+        let mut logged = self.clone_without_children();
+        logged.extra = self.extra.iter()
+            .filter(|extra| extra.has_content() || !extra.extra.is_empty())
+            .map(Chat::for_logging)
+            .collect();
+        logged.set_with_and_hover(self.details().with.iter().map(Chat::for_logging).collect(), None);
+        logged.details = logged.details.take().and_then(|details| ChatDetails {
+            click_event: None,
+            insertion: None,
+            ..*details
+        }.into_box());
+        logged
+    }
+
+    /// Removes components from `extra` lists throughout the tree which have no effect, to reduce the size of generated messages.
+    ///
+    /// A component is prunable if its text is empty, it has no translation key, no click or hover event, no fields not known to this crate (which are kept with the `preserve-unknown-fields` feature and may be content like `keybind` or `score`), and no children left after pruning them. Its formatting doesn't matter since there is no text to apply it to. This component itself and translation arguments are never removed, since removing an argument would change which placeholders the others fill, but their children and text shown on hover are pruned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::Chat;
+    ///
+    /// let mut chat = Chat::from_json(r#"{"text":"a","extra":[{"text":"","bold":true,"extra":[{"text":""}]},{"text":"","clickEvent":{"action":"run_command","value":"/help"}},{"text":"b"}]}"#)?;
+    /// chat.prune_empty();
+    /// assert_eq!(chat.to_json()?, r#"{"text":"a","extra":[{"text":"","clickEvent":{"action":"run_command","value":"/help"}},{"text":"b"}]}"#);
+    ///
+    /// let json = r#"{"text":"a","extra":[{"text":"","keybind":"key.jump"},{"text":"","score":{"name":"@s","objective":"points"}},{"text":""}]}"#;
+    /// if cfg!(feature = "preserve-unknown-fields") {
+    ///     let mut chat = Chat::from_json(json)?;
+    ///     chat.prune_empty();
+    ///     assert_eq!(chat.to_json()?, r#"{"text":"a","extra":[{"text":"","keybind":"key.jump"},{"text":"","score":{"name":"@s","objective":"points"}}]}"#);
+    /// }
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn prune_empty(&mut self) {
+        if let Some(details) = &mut self.details {
+            for arg in &mut details.with {
+                arg.prune_empty();
+            }
+            match &mut details.hover_event {
+                Some(HoverEvent::ShowText(text)) => text.prune_empty(),
+                Some(HoverEvent::ShowEntity { name: Some(name), .. }) => name.prune_empty(),
+                _ => {}
+            }
+        }
+        for extra in &mut self.extra {
+            extra.prune_empty();
+        }
+        self.extra.retain(|extra| {
+            extra.has_content()
+            || extra.details().click_event.is_some()
+            || extra.details().hover_event.is_some()
+            || !extra.extra.is_empty()
+        });
+    }
+
+    /// Whether this component itself displays something, i.e. has text, a translation key, or fields not known to this crate which may be content like `keybind` or `score`. Children are not considered.
+    fn has_content(&self) -> bool {
+        !self.text.is_empty() || self.details().translate.is_some() || !self.details().extra_fields_empty()
+    }
+
+    /// Returns a copy of this text component where [legacy formatting codes](legacy) embedded in the text of any component, including text shown on hover, are converted to child components as in [`Chat::from_legacy`].
+    ///
+    /// The formatting codes only apply to the rest of the text in which they appear, not to the component's existing children or its siblings, like in vanilla. Components whose text doesn't contain a section sign are left unchanged.
+    pub fn expand_legacy_in_text(&self) -> Chat {
+        let mut expanded = self.clone_without_children();
+        expanded.set_with_and_hover(
+            self.details().with.iter().map(Chat::expand_legacy_in_text).collect(),
+            self.details().hover_event.as_ref().map(|hover_event| hover_event.map_components(&Chat::expand_legacy_in_text)),
+        );
+        if self.text.contains(legacy::SECTION_SIGN) {
+            let mut legacy = Chat::from_legacy(&self.text);
+            expanded.text = mem::take(&mut legacy.text);
+            expanded.extra = mem::take(&mut legacy.extra);
+        }
+        expanded.extra.extend(self.extra.iter().map(Chat::expand_legacy_in_text));
+        expanded
+    }
+
+    /// Returns the text of this component and its descendants in render order, each paired with its formatting after inheritance.
+    ///
+    /// Components with empty text are skipped, and text shown on hover is not included. Consecutive runs may have the same style, e.g. if the message was split into components for other reasons.
+    pub fn runs(&self) -> impl Iterator<Item = (String, ResolvedStyle)> {
+        fn visit(chat: &Chat, parent: &ResolvedStyle, runs: &mut Vec<(String, ResolvedStyle)>) {
+            let style = chat.resolve_style(parent);
+            if !chat.text.is_empty() { runs.push((chat.text.clone(), style)) }
+            for extra in &chat.extra {
+                visit(extra, &style, runs);
+            }
+        }
+
+        let mut runs = Vec::default();
+        visit(self, &ResolvedStyle::default(), &mut runs);
+        runs.into_iter()
+    }
+
+    /// The formatting of the last character of this component as rendered, i.e. of the last of its [`runs`](Chat::runs).
+    ///
+    /// If this component contains no text, this is the style of the component itself. Compare with [`ResolvedStyle::default`] to check whether formatting would carry over into text appended after conversion to a flat format like legacy formatting codes.
+    pub fn trailing_style(&self) -> ResolvedStyle {
+        self.runs().last().map_or_else(|| self.resolve_style(&ResolvedStyle::default()), |(_, style)| style)
+    }
+
+    /// A hash of the text and formatting of this component as rendered, e.g. for detecting repeated messages.
+    ///
+    /// Components that render identically have the same fingerprint regardless of how they're split into children, where formatting is set, or whether a color is given by name or as the equivalent hex code. Click and hover events and translatable text are ignored. The hash is 64-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) over a fixed encoding of the [`runs`](Chat::runs), so fingerprints are stable across runs and versions of this crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::{
+    ///     Chat,
+    ///     Color,
+    ///     NamedColor,
+    /// };
+    ///
+    /// let mut split = Chat::default();
+    /// split.color = Some(Color::Named(NamedColor::Red));
+    /// split.add_extra("Hello, ");
+    /// split.add_extra("world!");
+    /// let mut hex = Chat::from("Hello, world!");
+    /// hex.color = Some(Color::Hex([0xff, 0x55, 0x55]));
+    /// assert_eq!(split.render_fingerprint(), hex.render_fingerprint());
+    /// assert_ne!(split.render_fingerprint(), Chat::from("Hello, world!").render_fingerprint());
+    /// ```
+    pub fn render_fingerprint(&self) -> u64 {
+        let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+        let mut write = |bytes: &[u8]| for &byte in bytes {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        };
+        let mut prev_style = None;
+        for (text, style) in self.runs() {
+            let rgb = match style.color {
+                Some(Color::Named(color)) => Some(color.rgb()),
+                Some(Color::Hex(rgb)) => Some(rgb),
+                Some(Color::Reset) | Some(Color::None) | None => None,
+            };
+            let style = (rgb, [style.bold, style.italic, style.underlined, style.strikethrough, style.obfuscated].map(u8::from));
+            if prev_style != Some(style) {
+                // a run separator can't occur in text, which is written as UTF-8
+                write(&[0xff]);
+                match rgb {
+                    Some(rgb) => { write(&[1]); write(&rgb) }
+                    None => write(&[0]),
+                }
+                write(&style.1);
+                prev_style = Some(style);
+            }
+            write(text.as_bytes());
+        }
+        hash
+    }
+
+    /// Splits this component into lines at most `max_width` pixels wide, using the character widths of the default font.
+    ///
+    /// Lines are split at newlines and wrapped at spaces, and words too long for a line are broken up. Each line keeps the formatting of its text, but click and hover events are lost.
+    pub(crate) fn wrap_lines(&self, max_width: u32) -> Vec<Chat> {
+        let mut lines = vec![Vec::<(char, ResolvedStyle)>::default()];
+        let mut width = 0;
+        for (text, style) in self.runs() {
+            for c in text.chars() {
+                if c == '\n' {
+                    lines.push(Vec::default());
+                    width = 0;
+                    continue
+                }
+                let char_width = util::char_width(c, style.bold);
+                let line = lines.last_mut().expect("there is always a line");
+                if width + char_width > max_width && !line.is_empty() {
+                    if c == ' ' {
+                        lines.push(Vec::default());
+                        width = 0;
+                        continue
+                    }
+                    // wrap at the last space if there is one, moving the rest of the word to the next line
+                    let next = match line.iter().rposition(|&(c, _)| c == ' ') {
+                        Some(space) => {
+                            let next = line.split_off(space + 1);
+                            line.pop();
+                            next
+                        }
+                        None => Vec::default(),
+                    };
+                    width = next.iter().map(|&(c, style)| util::char_width(c, style.bold)).sum();
+                    lines.push(next);
+                }
+                lines.last_mut().expect("there is always a line").push((c, style));
+                width += char_width;
+            }
+        }
+        lines.into_iter().map(|line| {
+            let mut runs = Vec::<(String, ResolvedStyle)>::default();
+            for (c, style) in line {
+                match runs.last_mut() {
+                    Some((text, last_style)) if *last_style == style => text.push(c),
+                    _ => runs.push((c.to_string(), style)),
+                }
+            }
+            if runs.len() == 1 && runs[0].1 == ResolvedStyle::default() {
+                Chat::from(runs.remove(0).0)
+            } else {
+                Chat::default().with_extras(runs.into_iter().map(|(text, style)| Chat::styled(text, style)))
+            }
+        }).collect()
+    }
+
+    /// A component with the given text and the given formatting set explicitly.
+    fn styled(text: String, style: ResolvedStyle) -> Chat {
+        let flag = |on: bool| if on { Some(true) } else { None };
+        Chat {
+            text,
+            color: style.color,
+            shadow_color: None,
+            bold: flag(style.bold),
+            italic: flag(style.italic),
+            underlined: flag(style.underlined),
+            strikethrough: flag(style.strikethrough),
+            obfuscated: flag(style.obfuscated),
+            details: None,
+            extra: Vec::default(),
+        }
+    }
+
+    /// The component at `path` within this one, or `None` if there is no such component.
+    pub fn get(&self, path: &ComponentPath) -> Option<&Chat> {
+        path.0.iter().try_fold(self, |chat, &step| match step {
+            PathStep::Extra(idx) => chat.extra.get(idx),
+            PathStep::WithArg(idx) => chat.details().with.get(idx),
+            PathStep::HoverText => match chat.details().hover_event {
+                Some(HoverEvent::ShowText(ref text)) => Some(&**text),
+                _ => None,
+            },
+            PathStep::HoverEntityName => match chat.details().hover_event {
+                Some(HoverEvent::ShowEntity { name: Some(ref name), .. }) => Some(&**name),
+                _ => None,
+            },
+        })
+    }
+
+    /// Mutable access to the component at `path` within this one, or `None` if there is no such component.
+    pub fn get_mut(&mut self, path: &ComponentPath) -> Option<&mut Chat> {
+        path.0.iter().try_fold(self, |chat, &step| match step {
+            PathStep::Extra(idx) => chat.extra.get_mut(idx),
+            PathStep::WithArg(idx) => chat.details.as_mut()?.with.get_mut(idx),
+            PathStep::HoverText => match chat.details.as_mut()?.hover_event {
+                Some(HoverEvent::ShowText(ref mut text)) => Some(&mut **text),
+                _ => None,
+            },
+            PathStep::HoverEntityName => match chat.details.as_mut()?.hover_event {
+                Some(HoverEvent::ShowEntity { name: Some(ref mut name), .. }) => Some(&mut **name),
+                _ => None,
+            },
+        })
+    }
+
+    /// The path of the first component for which `predicate` returns `true`, e.g. to point diagnostics at the offending component.
+    ///
+    /// Components are visited depth-first: each component before its translation arguments, then the text shown on hover, then `extra`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::chat::Chat;
+    ///
+    /// let chat = Chat::from_json(r#"{"text":"a","extra":[{"text":"b"},{"text":"c","hoverEvent":{"action":"show_text","contents":{"text":"","extra":[{"text":"§cd"}]}}}]}"#)?;
+    /// let path = chat.find(|component| component.text.contains('§')).unwrap();
+    /// assert_eq!(path.to_string(), "extra[1].hover.contents.extra[0]");
+    /// assert_eq!(chat.get(&path).unwrap().text, "§cd");
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn find(&self, mut predicate: impl FnMut(&Chat) -> bool) -> Option<ComponentPath> {
+        fn visit(chat: &Chat, path: &mut Vec<PathStep>, predicate: &mut dyn FnMut(&Chat) -> bool) -> bool {
+            if predicate(chat) { return true }
+            let details = chat.details();
+            let hover = match details.hover_event {
+                Some(HoverEvent::ShowText(ref text)) => Some((PathStep::HoverText, &**text)),
+                Some(HoverEvent::ShowEntity { name: Some(ref name), .. }) => Some((PathStep::HoverEntityName, &**name)),
+                _ => None,
+            };
+            let children = details.with.iter().enumerate().map(|(idx, arg)| (PathStep::WithArg(idx), arg))
+                .chain(hover)
+                .chain(chat.extra.iter().enumerate().map(|(idx, extra)| (PathStep::Extra(idx), extra)));
+            for (step, child) in children {
+                path.push(step);
+                if visit(child, path, predicate) { return true }
+                path.pop();
+            }
+            false
+        }
+
+        let mut path = Vec::default();
+        if visit(self, &mut path, &mut predicate) { Some(ComponentPath(path)) } else { None }
+    }
+
+    /// Returns a wrapper whose [`Debug`](fmt::Debug) impl shows the full structure of this component, omitting unset fields.
+    ///
+    /// This is what `{:#?}` uses. The compact `{:?}` representation of [`Chat`] only shows the rendered text and a summary of the formatting.
+    pub fn debug_tree(&self) -> DebugTree<'_> {
+        DebugTree(self)
+    }
+
+    /// The style of this component when displayed as a child of text with the `parent` style.
+    fn resolve_style(&self, parent: &ResolvedStyle) -> ResolvedStyle {
+        parent.overlay(&self.style())
+    }
+
+    /// Clones this component's text, formatting, and click event, but not its `extra` or hover event, which may contain large subtrees.
+    fn clone_without_children(&self) -> Chat {
+        Chat {
+            text: self.text.clone(),
+            color: self.color,
+            shadow_color: self.shadow_color,
+            bold: self.bold,
+            italic: self.italic,
+            underlined: self.underlined,
+            strikethrough: self.strikethrough,
+            obfuscated: self.obfuscated,
+            details: self.details.as_ref().and_then(|details| ChatDetails {
+                translate: details.translate.clone(),
+                with: Vec::default(),
+                click_event: details.click_event.clone(),
+                hover_event: None,
+                insertion: details.insertion.clone(),
+                font: details.font.clone(),
+                #[cfg(feature = "preserve-unknown-fields")]
+                extra_fields: details.extra_fields.clone(),
+            }.into_box()),
+            extra: Vec::default(),
+        }
+    }
+
+    /// Sets the translation arguments and hover event, only allocating [`ChatDetails`] if either is set.
+    fn set_with_and_hover(&mut self, with: Vec<Chat>, hover_event: Option<HoverEvent>) {
+        if !with.is_empty() || hover_event.is_some() || self.details.is_some() {
+            let details = self.details_mut();
+            details.with = with;
+            details.hover_event = hover_event;
+        }
+    }
+
+    fn link(url: &str) -> Chat {
+        let mut chat = Chat::from(url);
+        chat.color(NamedColor::Blue).underlined().on_click(ClickEvent::OpenUrl(url.to_owned()));
+        chat
+    }
+}
+
+/// Splits text into segments, each flagged with whether it's a URL.
+fn split_urls(text: &str) -> Vec<(&str, bool)> {
+    let mut segments = Vec::default();
+    let mut plain_start = 0;
+    let mut search_start = 0;
+    while let Some(start) = ["http://", "https://"].iter().filter_map(|scheme| text[search_start..].find(scheme)).min().map(|start| search_start + start) {
+        let end = text[start..].find(char::is_whitespace).map_or(text.len(), |end| start + end);
+        let mut url = &text[start..end];
+        let url = loop {
+            url = url.trim_end_matches(&['.', ',', ';', ':', '!', '?', ']', '}', '>', '\'', '"'][..]);
+            // a closing parenthesis is only part of the URL if it closes one opened in the URL, as in Wikipedia links
+            match url.strip_suffix(')') {
+                Some(rest) if url.matches(')').count() > url.matches('(').count() => url = rest,
+                _ => break url,
+            }
+        };
+        if !url.ends_with("://") { // a scheme without a host is not a link
+            if start > plain_start { segments.push((&text[plain_start..start], false)) }
+            segments.push((url, true));
+            plain_start = start + url.len();
+        }
+        search_start = end;
+    }
+    if plain_start < text.len() { segments.push((&text[plain_start..], false)) }
+    segments
+}
+
+/// A JSON formatter which escapes all non-ASCII characters in strings.
+#[derive(Clone)]
+struct AsciiSafeFormatter;
+
+impl serde_json::ser::Formatter for AsciiSafeFormatter {
+    fn write_string_fragment<W: ?Sized + io::Write>(&mut self, writer: &mut W, fragment: &str) -> io::Result<()> {
+        let mut rest = fragment;
+        while let Some(idx) = rest.find(|c: char| !c.is_ascii()) {
+            writer.write_all(&rest.as_bytes()[..idx])?;
+            let c = rest[idx..].chars().next().expect("found a character at this index");
+            for unit in c.encode_utf16(&mut [0; 2]) {
+                write!(writer, "\\u{:04x}", unit)?;
+            }
+            rest = &rest[idx + c.len_utf8()..];
+        }
+        writer.write_all(rest.as_bytes())
+    }
 }
 
 impl From<String> for Chat {
     fn from(text: String) -> Chat {
-        Chat { text, ..Chat::default() }
+        Chat {
+            text,
+            color: None,
+            shadow_color: None,
+            bold: None,
+            italic: None,
+            underlined: None,
+            strikethrough: None,
+            obfuscated: None,
+            details: None,
+            extra: Vec::default(),
+        }
     }
 }
 
-impl<'a> From<&'a str> for Chat {
+impl From<&str> for Chat {
     fn from(text: &str) -> Chat {
-        Chat {
-            text: text.to_owned(),
-            ..Chat::default()
-        }
+        Chat::from(text.to_owned())
     }
 }
 
 impl<'a> From<Cow<'a, str>> for Chat {
     fn from(text: Cow<'a, str>) -> Chat {
-        Chat {
-            text: text.into_owned(),
-            ..Chat::default()
+        Chat::from(text.into_owned())
+    }
+}
+
+impl Serialize for Chat {
+    /// Serializes the fields in the order vanilla uses, omitting unset ones.
+    ///
+    /// This recurses into `with` and `extra`. JSON output from methods like [`Chat::to_json`] and [`Chat::write_json`] uses an explicit stack instead, so it also works for very deep trees.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let details = self.details();
+        let mut map = serializer.serialize_map(None)?;
+        self.for_each_head_field(|key, value| map.serialize_entry(key, &value))?;
+        if !details.with.is_empty() { map.serialize_entry("with", &details.with)?; }
+        self.for_each_middle_field(None, |key, value| map.serialize_entry(key, &value))?;
+        if !self.extra.is_empty() { map.serialize_entry("extra", &self.extra)?; }
+        map.end()
+    }
+}
+
+/// The value of a field of a [`Chat`] other than `with` and `extra`, so the [`Serialize`] implementation and [`Chat::write_json`] write the same fields in the same order.
+enum Field<'a> {
+    Str(&'a str),
+    Color(&'a Color),
+    ShadowColor(u32),
+    Bool(bool),
+    ClickEvent(&'a ClickEvent),
+    HoverEvent(&'a HoverEvent),
+    #[cfg(feature = "preserve-unknown-fields")]
+    Unknown(&'a serde_json::Value),
+}
+
+impl Serialize for Field<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Field::Str(s) => s.serialize(serializer),
+            Field::Color(color) => color.serialize(serializer),
+            Field::ShadowColor(color) => shadow_color::serialize(&Some(*color), serializer),
+            Field::Bool(b) => b.serialize(serializer),
+            Field::ClickEvent(event) => event.serialize(serializer),
+            Field::HoverEvent(event) => event.serialize(serializer),
+            #[cfg(feature = "preserve-unknown-fields")]
+            Field::Unknown(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl fmt::Debug for Chat {
+    /// With `{:?}`, shows the rendered text followed by a summary of the formatting and events used anywhere in the tree, e.g. `Chat("Hello world", red, bold)`. This keeps assertion failures readable.
+    ///
+    /// With `{:#?}`, shows the full structure like [`Chat::debug_tree`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() { return fmt::Debug::fmt(&self.debug_tree(), f) }
+        let mut text = String::default();
+        let mut colors = Vec::default();
+        let mut flags = [false; 7];
+        for (run, style) in self.runs() {
+            text.push_str(&run);
+            if let Some(color) = style.color {
+                if !colors.contains(&color) { colors.push(color) }
+            }
+            for (flag, set) in flags.iter_mut().zip([style.bold, style.italic, style.underlined, style.strikethrough, style.obfuscated]) {
+                *flag |= set;
+            }
+        }
+        let mut stack = vec![self];
+        while let Some(chat) = stack.pop() {
+            flags[5] |= chat.details().click_event.is_some();
+            flags[6] |= chat.details().hover_event.is_some();
+            stack.extend(&chat.details().with);
+            stack.extend(&chat.extra);
+        }
+        write!(f, "Chat({:?}", text)?;
+        for color in colors {
+            write!(f, ", {}", color)?;
+        }
+        for (set, name) in flags.iter().zip(["bold", "italic", "underlined", "strikethrough", "obfuscated", "click_event", "hover_event"]) {
+            if *set { write!(f, ", {}", name)? }
+        }
+        write!(f, ")")
+    }
+}
+
+/// A step from a text component to a component it contains, see [`ComponentPath`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathStep {
+    /// The element of `extra` with the given index.
+    Extra(usize),
+    /// The translation argument with the given index.
+    WithArg(usize),
+    /// The text shown by a `show_text` hover event.
+    HoverText,
+    /// The entity name shown by a `show_entity` hover event.
+    HoverEntityName,
+}
+
+impl fmt::Display for PathStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathStep::Extra(idx) => write!(f, "extra[{}]", idx),
+            PathStep::WithArg(idx) => write!(f, "with[{}]", idx),
+            PathStep::HoverText => write!(f, "hover.contents"),
+            PathStep::HoverEntityName => write!(f, "hover.contents.name"),
+        }
+    }
+}
+
+/// The location of a component within a text component tree, as returned by [`Chat::find`] and used by [`Chat::get`].
+///
+/// Displays the steps separated by dots, e.g. `extra[2].hover.contents`, or `root` for the empty path.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::chat::{
+///     Chat,
+///     ComponentPath,
+///     PathStep,
+/// };
+///
+/// let chat = Chat::from_json(r#"{"translate":"chat.type.text","with":["Notch",{"text":"hi","extra":[{"text":"!"}]}]}"#)?;
+/// let path = ComponentPath::root().with_step(PathStep::WithArg(1)).with_step(PathStep::Extra(0));
+/// assert_eq!(path.to_string(), "with[1].extra[0]");
+/// assert_eq!(chat.get(&path).unwrap().text, "!");
+/// assert!(chat.get(&ComponentPath::root().with_step(PathStep::HoverText)).is_none());
+/// # Ok::<(), minecraft::chat::ChatError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ComponentPath(pub Vec<PathStep>);
+
+impl ComponentPath {
+    /// The path of the root component itself.
+    pub fn root() -> ComponentPath {
+        ComponentPath::default()
+    }
+
+    /// Appends a step to this path.
+    pub fn with_step(mut self, step: PathStep) -> ComponentPath {
+        self.0.push(step);
+        self
+    }
+
+    /// Whether this is the path of the root component.
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for ComponentPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() { return write!(f, "root") }
+        for (idx, step) in self.0.iter().enumerate() {
+            if idx > 0 { write!(f, ".")? }
+            write!(f, "{}", step)?;
         }
+        Ok(())
+    }
+}
+
+/// The structural [`Debug`](fmt::Debug) representation of a [`Chat`], returned by [`Chat::debug_tree`].
+pub struct DebugTree<'a>(&'a Chat);
+
+impl fmt::Debug for DebugTree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chat = self.0;
+        let details = chat.details();
+        let mut debug = f.debug_struct("Chat");
+        debug.field("text", &chat.text);
+        if let Some(ref translate) = details.translate { debug.field("translate", translate); }
+        if !details.with.is_empty() { debug.field("with", &details.with.iter().map(DebugTree).collect::<Vec<_>>()); }
+        if let Some(ref color) = chat.color { debug.field("color", color); }
+        if let Some(ref shadow_color) = chat.shadow_color { debug.field("shadow_color", &format_args!("{:#010x}", shadow_color)); }
+        if let Some(ref bold) = chat.bold { debug.field("bold", bold); }
+        if let Some(ref italic) = chat.italic { debug.field("italic", italic); }
+        if let Some(ref underlined) = chat.underlined { debug.field("underlined", underlined); }
+        if let Some(ref strikethrough) = chat.strikethrough { debug.field("strikethrough", strikethrough); }
+        if let Some(ref obfuscated) = chat.obfuscated { debug.field("obfuscated", obfuscated); }
+        if let Some(ref click_event) = details.click_event { debug.field("click_event", click_event); }
+        if let Some(ref hover_event) = details.hover_event { debug.field("hover_event", hover_event); }
+        if let Some(ref insertion) = details.insertion { debug.field("insertion", insertion); }
+        if let Some(ref font) = details.font { debug.field("font", font); }
+        #[cfg(feature = "preserve-unknown-fields")] if !details.extra_fields.is_empty() { debug.field("extra_fields", &details.extra_fields); }
+        if !chat.extra.is_empty() { debug.field("extra", &chat.extra.iter().map(DebugTree).collect::<Vec<_>>()); }
+        debug.finish()
     }
 }
 
 impl fmt::Display for Chat {
+    /// Writes this text component as compact JSON, or as indented JSON with `{:#}`.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", serde_json::to_string(self).map_err(|_| fmt::Error)?)
+        if f.alternate() {
+            self.serialize_into(f, serde_json::ser::PrettyFormatter::new(), &JsonOptions::default())
+        } else {
+            self.serialize_into(f, serde_json::ser::CompactFormatter, &JsonOptions::default())
+        }.map_err(|_| fmt::Error)
+    }
+}
+
+/// Serde helpers for text components stored as JSON strings, as in NBT before 1.20.5, e.g. for custom names or scoreboard display names.
+///
+/// Use with `#[serde(with = "minecraft::chat::json_string")]`. Deserialization also accepts a text component given directly, and falls back to plain text if the string is not valid JSON, like vanilla.
+pub mod json_string {
+    use {
+        serde::{
+            Deserialize,
+            Deserializer,
+            Serializer,
+            de,
+            ser,
+        },
+        super::Chat,
+    };
+
+    /// Serializes the text component as a JSON string.
+    pub fn serialize<S: Serializer>(chat: &Chat, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&chat.to_json().map_err(ser::Error::custom)?)
+    }
+
+    /// Deserializes a text component from a JSON string or directly.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Chat, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::String(json) => match serde_json::from_str(&json) {
+                Ok(value) => super::chat_from_value(value).map(|chat| *chat).map_err(de::Error::custom),
+                Err(_) => Ok(Chat::from(json)),
+            },
+            value => super::chat_from_value(value).map(|chat| *chat).map_err(de::Error::custom),
+        }
     }
 }
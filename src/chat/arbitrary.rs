@@ -0,0 +1,238 @@
+//! [`Arbitrary`] implementations for the types in [`crate::chat`], enabled by the `arbitrary` feature.
+//!
+//! Generated values always serialize to JSON which deserializes to an equal value: colors are valid, identifiers and URLs are plausible, and unknown fields are never generated. The nesting depth and total number of components are bounded by values read from the start of the unstructured data, so the fuzzer can tune them.
+
+use {
+    arbitrary::{
+        Arbitrary,
+        Result,
+        Unstructured,
+    },
+    uuid::Uuid,
+    crate::{
+        entity::EntityKind,
+        identifier::Identifier,
+    },
+    super::{
+        Chat,
+        ChatDetails,
+        ClickEvent,
+        Color,
+        HoverEvent,
+        NamedColor,
+        Style,
+    },
+};
+
+/// The maximum nesting depth of generated components, counting `extra`, `with`, and hover text.
+const MAX_DEPTH: usize = 8;
+/// The maximum number of components in a generated value.
+const MAX_COMPONENTS: usize = 64;
+
+/// How much is left to generate of the current value.
+struct Budget {
+    depth: usize,
+    components: usize,
+}
+
+impl Budget {
+    fn arbitrary(u: &mut Unstructured<'_>) -> Result<Budget> {
+        Ok(Budget {
+            depth: u.int_in_range(0..=MAX_DEPTH)?,
+            components: u.int_in_range(1..=MAX_COMPONENTS)?,
+        })
+    }
+
+    /// Whether another nested component may be generated.
+    fn can_nest(&self) -> bool {
+        self.depth > 0 && self.components > 0
+    }
+
+    /// Generates a nested component one level deeper.
+    fn nested(&mut self, u: &mut Unstructured<'_>) -> Result<Chat> {
+        self.depth -= 1;
+        let chat = self.component(u);
+        self.depth += 1;
+        chat
+    }
+
+    /// Generates zero or more nested components.
+    fn nested_list(&mut self, u: &mut Unstructured<'_>) -> Result<Vec<Chat>> {
+        let mut list = Vec::default();
+        while self.can_nest() && u.arbitrary()? {
+            list.push(self.nested(u)?);
+        }
+        Ok(list)
+    }
+
+    fn component(&mut self, u: &mut Unstructured<'_>) -> Result<Chat> {
+        self.components = self.components.saturating_sub(1);
+        let text = u.arbitrary()?;
+        let Style { color, shadow_color, bold, italic, underlined, strikethrough, obfuscated, insertion, font } = u.arbitrary()?;
+        let details = if u.ratio(1, 4)? {
+            let mut details = self.details(u)?;
+            details.insertion = insertion;
+            details.font = font;
+            details.into_box()
+        } else {
+            None
+        };
+        let extra = self.nested_list(u)?;
+        Ok(Chat { text, color, shadow_color, bold, italic, underlined, strikethrough, obfuscated, details, extra })
+    }
+
+    fn details(&mut self, u: &mut Unstructured<'_>) -> Result<ChatDetails> {
+        let translate = if u.arbitrary()? { Some(translation_key(u)?) } else { None };
+        let with = if translate.is_some() { self.nested_list(u)? } else { Vec::default() };
+        Ok(ChatDetails {
+            translate,
+            with,
+            click_event: u.arbitrary()?,
+            hover_event: if u.arbitrary()? { Some(self.hover_event(u)?) } else { None },
+            insertion: None,
+            font: None,
+            #[cfg(feature = "preserve-unknown-fields")]
+            extra_fields: Default::default(),
+        })
+    }
+
+    fn hover_event(&mut self, u: &mut Unstructured<'_>) -> Result<HoverEvent> {
+        Ok(match u.int_in_range(0..=2)? {
+            0 if self.can_nest() => HoverEvent::ShowText(Box::new(self.nested(u)?)),
+            0 => HoverEvent::ShowText(Box::default()),
+            1 => HoverEvent::ShowItem {
+                id: identifier(u)?.to_string(),
+                count: u.arbitrary()?,
+                tag: u.arbitrary()?,
+                components: if u.arbitrary()? {
+                    Some(serde_json::json!({"minecraft:damage": u.arbitrary::<u16>()?}))
+                } else {
+                    None
+                },
+            },
+            _ => HoverEvent::ShowEntity {
+                name: if self.can_nest() && u.arbitrary()? { Some(Box::new(self.nested(u)?)) } else { None },
+                entity_type: if u.ratio(1, 8)? {
+                    // vanilla IDs would be parsed back as known entity types
+                    EntityKind::Other(Identifier::new("example", word(u, PATH_CHARS)?).expect("generated from valid characters"))
+                } else {
+                    u.choose(EntityKind::ALL)?.clone()
+                },
+                id: Uuid::from_bytes(u.arbitrary()?),
+            },
+        })
+    }
+}
+
+const NAMESPACE_CHARS: &[char] = &['a', 'b', 'c', 'e', 'm', 'x', 'z', '0', '9', '_', '-', '.'];
+const PATH_CHARS: &[char] = &['a', 'b', 'c', 'e', 'm', 'x', 'z', '0', '9', '_', '-', '.', '/'];
+
+/// A nonempty string of characters from the given set.
+fn word(u: &mut Unstructured<'_>, chars: &[char]) -> Result<String> {
+    let len = u.int_in_range(1..=12)?;
+    (0..len).map(|_| u.choose(chars).copied()).collect()
+}
+
+fn identifier(u: &mut Unstructured<'_>) -> Result<Identifier> {
+    Ok(if u.arbitrary()? {
+        Identifier::new("minecraft", word(u, PATH_CHARS)?)
+    } else {
+        Identifier::new(word(u, NAMESPACE_CHARS)?, word(u, PATH_CHARS)?)
+    }.expect("generated from valid characters"))
+}
+
+/// A dot-separated translation key like `chat.type.text`.
+fn translation_key(u: &mut Unstructured<'_>) -> Result<String> {
+    let segments = u.int_in_range(1..=4)?;
+    Ok((0..segments).map(|_| word(u, &NAMESPACE_CHARS[..NAMESPACE_CHARS.len() - 1])).collect::<Result<Vec<_>>>()?.join("."))
+}
+
+/// Generates components of bounded size, which round-trip through JSON.
+///
+/// # Example
+///
+/// ```
+/// use {
+///     arbitrary::{
+///         Arbitrary as _,
+///         Unstructured,
+///     },
+///     minecraft::chat::Chat,
+/// };
+///
+/// let mut state = 0x2545_f491_4f6c_dd1d_u64;
+/// for len in (0..2000).step_by(50) {
+///     let bytes = (0..len).map(|_| {
+///         state ^= state << 13;
+///         state ^= state >> 7;
+///         state ^= state << 17;
+///         state as u8
+///     }).collect::<Vec<_>>();
+///     let chat = Chat::arbitrary(&mut Unstructured::new(&bytes))?;
+///     assert!(chat.component_count() <= 64);
+///     let json = chat.to_json()?;
+///     assert_eq!(Chat::from_json(&json)?.to_json()?, json);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl<'a> Arbitrary<'a> for Chat {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Chat> {
+        Budget::arbitrary(u)?.component(u)
+    }
+}
+
+impl<'a> Arbitrary<'a> for NamedColor {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<NamedColor> {
+        u.choose(&NamedColor::ALL).copied()
+    }
+
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Color {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Color> {
+        Ok(match u.int_in_range(0..=9)? {
+            0..=3 => Color::Named(u.arbitrary()?),
+            4..=7 => Color::Hex(u.arbitrary()?),
+            8 => Color::Reset,
+            _ => Color::None,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Style {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Style> {
+        Ok(Style {
+            color: u.arbitrary()?,
+            shadow_color: u.arbitrary()?,
+            bold: u.arbitrary()?,
+            italic: u.arbitrary()?,
+            underlined: u.arbitrary()?,
+            strikethrough: u.arbitrary()?,
+            obfuscated: u.arbitrary()?,
+            insertion: if u.ratio(1, 4)? { Some(u.arbitrary()?) } else { None },
+            font: if u.ratio(1, 4)? { Some(identifier(u)?.to_string()) } else { None },
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ClickEvent {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<ClickEvent> {
+        Ok(match u.int_in_range(0..=5)? {
+            0 => ClickEvent::OpenUrl(format!("https://{}.example/{}", word(u, NAMESPACE_CHARS)?, word(u, PATH_CHARS)?)),
+            1 => ClickEvent::OpenFile(format!("screenshots/{}.png", word(u, NAMESPACE_CHARS)?)),
+            2 => ClickEvent::RunCommand(format!("/{} {}", word(u, NAMESPACE_CHARS)?, u.arbitrary::<&str>()?)),
+            3 => ClickEvent::SuggestCommand(format!("/{} ", word(u, NAMESPACE_CHARS)?)),
+            4 => ClickEvent::ChangePage(u.int_in_range(1..=100u8)?.to_string()),
+            _ => ClickEvent::CopyToClipboard(u.arbitrary()?),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for HoverEvent {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<HoverEvent> {
+        Budget::arbitrary(u)?.hover_event(u)
+    }
+}
@@ -0,0 +1,532 @@
+//! This module contains [`ChatRef`], a version of [`Chat`] which borrows its strings from the input when deserializing.
+//!
+//! This is useful for programs like proxies which deserialize, inspect, and reserialize many text components without modifying most of them. With `serde_json::from_str`, strings which don't contain escape sequences are borrowed instead of copied, including text, translation keys, URLs, and item IDs.
+
+use {
+    std::{
+        borrow::Cow,
+        fmt,
+        mem,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::{
+            DeserializeSeed,
+            MapAccess,
+            SeqAccess,
+            Visitor,
+            value::MapAccessDeserializer,
+        },
+        ser::SerializeMap as _,
+    },
+    uuid::Uuid,
+    crate::{
+        entity::EntityKind,
+        util,
+    },
+    super::{
+        Chat,
+        ChatDetails,
+        ClickEvent,
+        Color,
+        HoverDataSeed,
+        HoverEvent,
+    },
+};
+
+/// A text component which may borrow its strings, see the [module-level documentation](self).
+///
+/// The fields are those of [`Chat`] and [`ChatDetails`] in one struct, and their (de)serialization is the same as in [`Chat`]. Unlike [`Chat::from_json_limited`], deserialization doesn't limit the number of components.
+///
+/// # Example
+///
+/// ```
+/// use {
+///     std::borrow::Cow,
+///     minecraft::chat::{
+///         Chat,
+///         borrowed::{
+///             ChatRef,
+///             ClickEventRef,
+///             HoverEventRef,
+///         },
+///     },
+/// };
+///
+/// let json = r#"{"text":"wiki","clickEvent":{"action":"open_url","value":"https://minecraft.wiki/"},"hoverEvent":{"action":"show_text","contents":{"text":"Click to open"}},"extra":[{"text":"line\nbreak"}]}"#;
+/// let chat = serde_json::from_str::<ChatRef<'_>>(json)?;
+/// assert!(matches!(chat.text, Cow::Borrowed("wiki")));
+/// assert!(matches!(chat.click_event, Some(ClickEventRef::OpenUrl(Cow::Borrowed(_)))));
+/// assert!(matches!(&chat.hover_event, Some(HoverEventRef::ShowText(text)) if matches!(text.text, Cow::Borrowed("Click to open"))));
+/// assert!(matches!(chat.extra[0].text, Cow::Owned(_))); // contains an escape sequence
+/// assert_eq!(serde_json::to_string(&chat)?, json);
+/// assert_eq!(chat.to_owned().to_json().unwrap(), Chat::from_json(json).unwrap().to_json().unwrap());
+///
+/// // legacy hover events are converted to the current format
+/// let legacy = serde_json::from_str::<ChatRef<'_>>(r#"{"text":"","hoverEvent":{"action":"show_item","value":"{id:\"minecraft:stone\",Count:2b}"}}"#)?;
+/// assert!(matches!(legacy.hover_event, Some(HoverEventRef::ShowItem { count: Some(2), .. })));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[cfg_attr(not(feature = "preserve-unknown-fields"), serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+#[allow(missing_docs)] // fields are documented on Chat
+pub struct ChatRef<'a> {
+    #[serde(default, borrow)]
+    pub text: Cow<'a, str>,
+    #[serde(default, borrow)]
+    pub translate: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "deserialize_args")]
+    pub with: Vec<ChatRef<'a>>,
+    pub color: Option<Color>,
+    #[serde(rename = "shadow_color", default, with = "super::shadow_color")]
+    pub shadow_color: Option<u32>,
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub underlined: Option<bool>,
+    pub strikethrough: Option<bool>,
+    pub obfuscated: Option<bool>,
+    #[serde(default, borrow, alias = "click_event")]
+    pub click_event: Option<ClickEventRef<'a>>,
+    #[serde(default, borrow, alias = "hover_event")]
+    pub hover_event: Option<HoverEventRef<'a>>,
+    #[serde(default, borrow)]
+    pub insertion: Option<Cow<'a, str>>,
+    #[serde(default, borrow)]
+    pub font: Option<Cow<'a, str>>,
+    #[serde(default, borrow)]
+    pub extra: Vec<ChatRef<'a>>,
+    #[cfg(feature = "preserve-unknown-fields")]
+    #[serde(flatten)]
+    pub extra_fields: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+impl<'a> ChatRef<'a> {
+    /// Converts to a [`Chat`], copying any borrowed strings.
+    pub fn to_owned(&self) -> Chat {
+        Chat {
+            text: self.text.to_string(),
+            color: self.color,
+            shadow_color: self.shadow_color,
+            bold: self.bold,
+            italic: self.italic,
+            underlined: self.underlined,
+            strikethrough: self.strikethrough,
+            obfuscated: self.obfuscated,
+            details: ChatDetails {
+                translate: self.translate.as_ref().map(|translate| translate.to_string()),
+                with: self.with.iter().map(ChatRef::to_owned).collect(),
+                click_event: self.click_event.as_ref().map(ClickEventRef::to_owned),
+                hover_event: self.hover_event.as_ref().map(HoverEventRef::to_owned),
+                insertion: self.insertion.as_ref().map(|insertion| insertion.to_string()),
+                font: self.font.as_ref().map(|font| font.to_string()),
+                #[cfg(feature = "preserve-unknown-fields")]
+                extra_fields: self.extra_fields.clone(),
+            }.into_box(),
+            extra: self.extra.iter().map(ChatRef::to_owned).collect(),
+        }
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for ChatRef<'a> {
+    fn from(text: Cow<'a, str>) -> ChatRef<'a> {
+        ChatRef {
+            text,
+            ..ChatRef::default()
+        }
+    }
+}
+
+impl From<Chat> for ChatRef<'static> {
+    fn from(mut chat: Chat) -> ChatRef<'static> {
+        let details = chat.details.take().map(|details| *details).unwrap_or_default();
+        ChatRef {
+            text: Cow::Owned(mem::take(&mut chat.text)),
+            translate: details.translate.map(Cow::Owned),
+            with: details.with.into_iter().map(ChatRef::from).collect(),
+            color: chat.color,
+            shadow_color: chat.shadow_color,
+            bold: chat.bold,
+            italic: chat.italic,
+            underlined: chat.underlined,
+            strikethrough: chat.strikethrough,
+            obfuscated: chat.obfuscated,
+            click_event: details.click_event.map(ClickEventRef::from),
+            hover_event: details.hover_event.map(HoverEventRef::from),
+            insertion: details.insertion.map(Cow::Owned),
+            font: details.font.map(Cow::Owned),
+            extra: mem::take(&mut chat.extra).into_iter().map(ChatRef::from).collect(),
+            #[cfg(feature = "preserve-unknown-fields")]
+            extra_fields: details.extra_fields,
+        }
+    }
+}
+
+impl Serialize for ChatRef<'_> {
+    /// Serializes the same way as [`Chat`].
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        if self.translate.is_none() || !self.text.is_empty() { map.serialize_entry("text", &self.text)?; }
+        if let Some(ref translate) = self.translate { map.serialize_entry("translate", translate)?; }
+        if !self.with.is_empty() { map.serialize_entry("with", &self.with)?; }
+        if let Some(ref color) = self.color { map.serialize_entry("color", color)?; }
+        if let Some(shadow_color) = self.shadow_color { map.serialize_entry("shadow_color", &(shadow_color as i32))?; }
+        if let Some(ref bold) = self.bold { map.serialize_entry("bold", bold)?; }
+        if let Some(ref italic) = self.italic { map.serialize_entry("italic", italic)?; }
+        if let Some(ref underlined) = self.underlined { map.serialize_entry("underlined", underlined)?; }
+        if let Some(ref strikethrough) = self.strikethrough { map.serialize_entry("strikethrough", strikethrough)?; }
+        if let Some(ref obfuscated) = self.obfuscated { map.serialize_entry("obfuscated", obfuscated)?; }
+        if let Some(ref click_event) = self.click_event { map.serialize_entry("clickEvent", click_event)?; }
+        if let Some(ref hover_event) = self.hover_event { map.serialize_entry("hoverEvent", hover_event)?; }
+        if let Some(ref insertion) = self.insertion { map.serialize_entry("insertion", insertion)?; }
+        if let Some(ref font) = self.font { map.serialize_entry("font", font)?; }
+        #[cfg(feature = "preserve-unknown-fields")] for (key, value) in &self.extra_fields { map.serialize_entry(key, value)?; }
+        if !self.extra.is_empty() { map.serialize_entry("extra", &self.extra)?; }
+        map.end()
+    }
+}
+
+/// Deserializes a text component which may also be given as a string or a list of components, and if `args` is set, as a number or boolean.
+#[derive(Clone, Copy)]
+struct Lenient {
+    args: bool,
+}
+
+impl<'de> DeserializeSeed<'de> for Lenient {
+    type Value = ChatRef<'de>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<ChatRef<'de>, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Lenient {
+    type Value = ChatRef<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a text component")
+    }
+
+    fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<ChatRef<'de>, E> {
+        Ok(ChatRef::from(Cow::Borrowed(v)))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<ChatRef<'de>, E> {
+        Ok(ChatRef::from(Cow::Owned(v.to_owned())))
+    }
+
+    fn visit_string<E: serde::de::Error>(self, v: String) -> Result<ChatRef<'de>, E> {
+        Ok(ChatRef::from(Cow::Owned(v)))
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<ChatRef<'de>, E> {
+        if !self.args { return Err(E::invalid_type(serde::de::Unexpected::Bool(v), &self)) }
+        Ok(ChatRef::from(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<ChatRef<'de>, E> {
+        if !self.args { return Err(E::invalid_type(serde::de::Unexpected::Signed(v), &self)) }
+        Ok(ChatRef::from(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<ChatRef<'de>, E> {
+        if !self.args { return Err(E::invalid_type(serde::de::Unexpected::Unsigned(v), &self)) }
+        Ok(ChatRef::from(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<ChatRef<'de>, E> {
+        if !self.args { return Err(E::invalid_type(serde::de::Unexpected::Float(v), &self)) }
+        // format like serde_json, as for Chat
+        Ok(ChatRef::from(Cow::Owned(serde_json::Number::from_f64(v).map_or_else(|| v.to_string(), |n| n.to_string()))))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<ChatRef<'de>, A::Error> {
+        // like in vanilla, the first element of a list is the root and the rest are appended to its extra
+        let mut chat = seq.next_element_seed(Lenient { args: false })?.unwrap_or_default();
+        while let Some(extra) = seq.next_element_seed(Lenient { args: false })? {
+            chat.extra.push(extra);
+        }
+        Ok(chat)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<ChatRef<'de>, A::Error> {
+        ChatRef::deserialize(MapAccessDeserializer::new(map))
+    }
+}
+
+fn deserialize_args<'de: 'a, 'a, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<ChatRef<'a>>, D::Error> {
+    struct ArgsVisitor;
+
+    impl<'de> Visitor<'de> for ArgsVisitor {
+        type Value = Vec<ChatRef<'de>>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a list of translation arguments")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<ChatRef<'de>>, A::Error> {
+            let mut args = Vec::default();
+            while let Some(arg) = seq.next_element_seed(Lenient { args: true })? {
+                args.push(arg);
+            }
+            Ok(args)
+        }
+    }
+
+    deserializer.deserialize_seq(ArgsVisitor)
+}
+
+fn deserialize_lenient<'de: 'a, 'a, D: Deserializer<'de>>(deserializer: D) -> Result<Box<ChatRef<'a>>, D::Error> {
+    Lenient { args: false }.deserialize(deserializer).map(Box::new)
+}
+
+fn deserialize_lenient_option<'de: 'a, 'a, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Box<ChatRef<'a>>>, D::Error> {
+    deserialize_lenient(deserializer).map(Some)
+}
+
+/// A borrowed version of [`ClickEvent`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(tag = "action", content = "value", rename_all = "snake_case")]
+#[allow(missing_docs)] // variants are documented on ClickEvent
+pub enum ClickEventRef<'a> {
+    OpenUrl(#[serde(borrow)] Cow<'a, str>),
+    OpenFile(#[serde(borrow)] Cow<'a, str>),
+    RunCommand(#[serde(borrow)] Cow<'a, str>),
+    SuggestCommand(#[serde(borrow)] Cow<'a, str>),
+    ChangePage(#[serde(borrow)] Cow<'a, str>),
+    CopyToClipboard(#[serde(borrow)] Cow<'a, str>),
+}
+
+impl ClickEventRef<'_> {
+    /// Converts to a [`ClickEvent`], copying the value if it's borrowed.
+    pub fn to_owned(&self) -> ClickEvent {
+        match self {
+            ClickEventRef::OpenUrl(value) => ClickEvent::OpenUrl(value.to_string()),
+            ClickEventRef::OpenFile(value) => ClickEvent::OpenFile(value.to_string()),
+            ClickEventRef::RunCommand(value) => ClickEvent::RunCommand(value.to_string()),
+            ClickEventRef::SuggestCommand(value) => ClickEvent::SuggestCommand(value.to_string()),
+            ClickEventRef::ChangePage(value) => ClickEvent::ChangePage(value.to_string()),
+            ClickEventRef::CopyToClipboard(value) => ClickEvent::CopyToClipboard(value.to_string()),
+        }
+    }
+}
+
+impl From<ClickEvent> for ClickEventRef<'static> {
+    fn from(event: ClickEvent) -> ClickEventRef<'static> {
+        match event {
+            ClickEvent::OpenUrl(value) => ClickEventRef::OpenUrl(Cow::Owned(value)),
+            ClickEvent::OpenFile(value) => ClickEventRef::OpenFile(Cow::Owned(value)),
+            ClickEvent::RunCommand(value) => ClickEventRef::RunCommand(Cow::Owned(value)),
+            ClickEvent::SuggestCommand(value) => ClickEventRef::SuggestCommand(Cow::Owned(value)),
+            ClickEvent::ChangePage(value) => ClickEventRef::ChangePage(Cow::Owned(value)),
+            ClickEvent::CopyToClipboard(value) => ClickEventRef::CopyToClipboard(Cow::Owned(value)),
+        }
+    }
+}
+
+/// A borrowed version of [`HoverEvent`].
+///
+/// Hover events in the format used before 1.16 are accepted like for [`HoverEvent`], but since their data is parsed from SNBT, nothing in them is borrowed. The same applies if `contents` comes before `action`.
+///
+/// # Example
+///
+/// ```
+/// use {
+///     std::borrow::Cow,
+///     minecraft::chat::borrowed::HoverEventRef,
+/// };
+///
+/// let event = serde_json::from_str::<HoverEventRef<'_>>(r#"{"action":"show_item","contents":{"id":"minecraft:stone","count":2}}"#)?;
+/// assert!(matches!(event, HoverEventRef::ShowItem { id: Cow::Borrowed("minecraft:stone"), count: Some(2), .. }));
+/// let event = serde_json::from_str::<HoverEventRef<'_>>(r#"{"contents":"minecraft:stone","action":"show_item"}"#)?;
+/// assert!(matches!(event, HoverEventRef::ShowItem { id: Cow::Owned(_), .. }));
+/// let error = serde_json::from_str::<HoverEventRef<'_>>(r#"{"action":"show_entity","contents":{"type":"minecraft:pig"}}"#).unwrap_err();
+/// assert!(error.to_string().starts_with("missing field `id`"));
+/// let error = serde_json::from_str::<HoverEventRef<'_>>(r#"{"action":"show_achievement","contents":"foo"}"#).unwrap_err();
+/// assert!(error.to_string().starts_with("unknown hover event action"));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "action", content = "contents", rename_all = "snake_case")]
+#[allow(missing_docs)] // variants are documented on HoverEvent
+pub enum HoverEventRef<'a> {
+    ShowText(Box<ChatRef<'a>>),
+    ShowItem {
+        id: Cow<'a, str>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        count: Option<i32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tag: Option<Cow<'a, str>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        components: Option<serde_json::Value>,
+    },
+    ShowEntity {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<Box<ChatRef<'a>>>,
+        #[serde(rename = "type")]
+        entity_type: EntityKind,
+        id: Uuid,
+    },
+}
+
+impl HoverEventRef<'_> {
+    /// Converts to a [`HoverEvent`], copying any borrowed strings.
+    pub fn to_owned(&self) -> HoverEvent {
+        match self {
+            HoverEventRef::ShowText(text) => HoverEvent::ShowText(Box::new(ChatRef::to_owned(text))),
+            HoverEventRef::ShowItem { id, count, tag, components } => HoverEvent::ShowItem {
+                id: id.to_string(),
+                count: *count,
+                tag: tag.as_ref().map(|tag| tag.to_string()),
+                components: components.clone(),
+            },
+            HoverEventRef::ShowEntity { name, entity_type, id } => HoverEvent::ShowEntity {
+                name: name.as_ref().map(|name| Box::new(ChatRef::to_owned(name))),
+                entity_type: entity_type.clone(),
+                id: *id,
+            },
+        }
+    }
+}
+
+impl From<HoverEvent> for HoverEventRef<'static> {
+    fn from(event: HoverEvent) -> HoverEventRef<'static> {
+        match event {
+            HoverEvent::ShowText(text) => HoverEventRef::ShowText(Box::new(ChatRef::from(*text))),
+            HoverEvent::ShowItem { id, count, tag, components } => HoverEventRef::ShowItem {
+                id: Cow::Owned(id),
+                count,
+                tag: tag.map(Cow::Owned),
+                components,
+            },
+            HoverEvent::ShowEntity { name, entity_type, id } => HoverEventRef::ShowEntity {
+                name: name.map(|name| Box::new(ChatRef::from(*name))),
+                entity_type,
+                id,
+            },
+        }
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for HoverEventRef<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<HoverEventRef<'a>, D::Error> {
+        deserializer.deserialize_map(HoverEventRefVisitor)
+    }
+}
+
+/// Deserializes a hover event, dispatching on `action` like [`HoverEvent`].
+///
+/// The contents can only be borrowed if `action` comes first, as written by vanilla. Otherwise, and for the pre-1.16 format, they're deserialized like for [`HoverEvent`] and converted.
+struct HoverEventRefVisitor;
+
+impl<'de> Visitor<'de> for HoverEventRefVisitor {
+    type Value = HoverEventRef<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a hover event")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<HoverEventRef<'de>, A::Error> {
+        let mut action = None::<Cow<'de, str>>;
+        // whether the data is from `contents` rather than `value`, and the data, which is preferred from `contents`
+        let mut parsed = None::<(bool, HoverEventRef<'de>)>;
+        let mut buffered = None::<(bool, serde_json::Value)>;
+        while let Some(key) = map.next_key::<Cow<'de, str>>()? {
+            match &*key {
+                "action" => {
+                    if action.is_some() { return Err(serde::de::Error::duplicate_field("action")) }
+                    action = Some(map.next_value()?);
+                }
+                "contents" | "value" => {
+                    let modern = key == "contents";
+                    if parsed.as_ref().map(|&(modern, _)| modern).or(buffered.as_ref().map(|&(modern, _)| modern)) == Some(true) {
+                        if modern { return Err(serde::de::Error::duplicate_field("contents")) }
+                        map.next_value::<serde::de::IgnoredAny>()?;
+                        continue
+                    }
+                    match action {
+                        Some(ref action) if modern => parsed = Some((modern, map.next_value_seed(HoverContentsRefSeed { action })?)),
+                        Some(ref action) => parsed = Some((modern, map.next_value_seed(HoverDataSeed { action, modern })?.into())),
+                        None => buffered = Some((modern, map.next_value()?)),
+                    }
+                }
+                _ => { map.next_value::<serde::de::IgnoredAny>()?; }
+            }
+        }
+        let action = action.ok_or_else(|| serde::de::Error::missing_field("action"))?;
+        if let Some((_, event)) = parsed { return Ok(event) }
+        let (modern, data) = buffered.ok_or_else(|| serde::de::Error::custom("hover event has neither contents nor value"))?;
+        HoverDataSeed { action: &action, modern }.deserialize(data).map(HoverEventRef::from).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Deserializes the `contents` of a hover event with the given action, borrowing strings where possible.
+struct HoverContentsRefSeed<'b> {
+    action: &'b str,
+}
+
+impl<'de> DeserializeSeed<'de> for HoverContentsRefSeed<'_> {
+    type Value = HoverEventRef<'de>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<HoverEventRef<'de>, D::Error> {
+        Ok(match self.action {
+            "show_text" => HoverEventRef::ShowText(deserialize_lenient(deserializer)?),
+            "show_item" => {
+                let ShowItemContents { id, count, tag, components } = deserializer.deserialize_any(ShowItemContentsVisitor)?;
+                HoverEventRef::ShowItem { id, count, tag, components }
+            }
+            "show_entity" => {
+                let ShowEntityContents { name, entity_type, id } = ShowEntityContents::deserialize(deserializer)?;
+                HoverEventRef::ShowEntity { name, entity_type, id }
+            }
+            action => return Err(serde::de::Error::custom(format_args!("unknown hover event action: {:?}", action))),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct ShowItemContents<'a> {
+    #[serde(borrow)]
+    id: Cow<'a, str>,
+    #[serde(default, deserialize_with = "util::lenient_i32::deserialize")]
+    count: Option<i32>,
+    #[serde(default, borrow)]
+    tag: Option<Cow<'a, str>>,
+    #[serde(default)]
+    components: Option<serde_json::Value>,
+}
+
+/// Deserializes the contents of a `show_item` hover event, which may also be just the item ID.
+struct ShowItemContentsVisitor;
+
+impl<'de> Visitor<'de> for ShowItemContentsVisitor {
+    type Value = ShowItemContents<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an item ID or item stack")
+    }
+
+    fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<ShowItemContents<'de>, E> {
+        Ok(ShowItemContents { id: Cow::Borrowed(v), count: None, tag: None, components: None })
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<ShowItemContents<'de>, E> {
+        Ok(ShowItemContents { id: Cow::Owned(v.to_owned()), count: None, tag: None, components: None })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<ShowItemContents<'de>, A::Error> {
+        ShowItemContents::deserialize(MapAccessDeserializer::new(map))
+    }
+}
+
+#[derive(Deserialize)]
+struct ShowEntityContents<'a> {
+    #[serde(default, borrow, deserialize_with = "deserialize_lenient_option")]
+    name: Option<Box<ChatRef<'a>>>,
+    #[serde(rename = "type")]
+    entity_type: EntityKind,
+    id: Uuid,
+}
@@ -0,0 +1,174 @@
+//! [Proptest](https://docs.rs/proptest) strategies for [`Chat`] and related types, enabled by the `proptest` feature.
+//!
+//! The signatures of these functions are stable, but the exact distributions of the generated values are not covered by semver and may change in any release. Generated values serialize to JSON which deserializes to an equal value, and shrink toward smaller trees and plainer styles. Generated text never contains `§`, which [`Chat::from_legacy`] would read as a formatting code.
+//!
+//! # Examples
+//!
+//! ```
+//! use {
+//!     proptest::test_runner::TestRunner,
+//!     minecraft::chat::{
+//!         Chat,
+//!         JsonOptions,
+//!         proptest::arb_chat,
+//!     },
+//! };
+//!
+//! let mut runner = TestRunner::default();
+//! // serde round trip
+//! runner.run(&arb_chat(4), |chat| {
+//!     let json = chat.to_json().unwrap();
+//!     assert_eq!(Chat::from_json(&json).unwrap().to_json().unwrap(), json);
+//!     Ok(())
+//! })?;
+//! // legacy round trip
+//! runner.run(&arb_chat(4), |chat| {
+//!     for &hex in &[false, true] {
+//!         let legacy = chat.to_legacy(hex);
+//!         assert_eq!(Chat::from_legacy(&legacy).to_legacy(hex), legacy);
+//!     }
+//!     Ok(())
+//! })?;
+//! // omitting redundant formatting flags preserves rendering
+//! runner.run(&arb_chat(4), |chat| {
+//!     let compact = Chat::from_json(&chat.to_json_with(&JsonOptions { omit_redundant_flags: true }).unwrap()).unwrap();
+//!     assert_eq!(compact.render_fingerprint(), chat.render_fingerprint());
+//!     Ok(())
+//! })?;
+//! # Ok::<(), proptest::test_runner::TestError<Chat>>(())
+//! ```
+
+use {
+    proptest::{
+        collection::vec,
+        option,
+        prelude::*,
+        sample::select,
+    },
+    uuid::Uuid,
+    crate::entity::EntityKind,
+    super::{
+        Chat,
+        ChatDetails,
+        ClickEvent,
+        Color,
+        HoverEvent,
+        NamedColor,
+        Style,
+    },
+};
+
+/// Text for a single component, without `§` and control characters.
+fn arb_text() -> impl Strategy<Value = String> {
+    "[^§\\p{C}]{0,16}"
+}
+
+/// A namespaced identifier like `minecraft:stone`, as a string.
+fn arb_identifier() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[a-z0-9_./-]{1,12}".prop_map(|path| format!("minecraft:{}", path)),
+        "[a-z0-9_.-]{1,8}:[a-z0-9_./-]{1,12}",
+    ]
+}
+
+/// A dot-separated translation key like `chat.type.text`.
+fn arb_translation_key() -> impl Strategy<Value = String> {
+    "[a-z0-9_]{1,8}(\\.[a-z0-9_]{1,8}){0,3}"
+}
+
+/// One of the 16 named colors.
+pub fn arb_named_color() -> impl Strategy<Value = NamedColor> {
+    select(&NamedColor::ALL[..])
+}
+
+/// A named, hex, or resetting color, shrinking toward named colors.
+pub fn arb_color() -> impl Strategy<Value = Color> {
+    prop_oneof![
+        4 => arb_named_color().prop_map(Color::Named),
+        4 => any::<[u8; 3]>().prop_map(Color::Hex),
+        1 => Just(Color::Reset),
+        1 => Just(Color::None),
+    ]
+}
+
+/// A style with each field independently set or unset, shrinking toward unset fields.
+pub fn arb_style() -> impl Strategy<Value = Style> {
+    (
+        option::of(arb_color()),
+        option::weighted(0.125, any::<u32>()),
+        [option::of(any::<bool>()), option::of(any::<bool>()), option::of(any::<bool>()), option::of(any::<bool>()), option::of(any::<bool>())],
+        option::weighted(0.125, arb_text()),
+        option::weighted(0.125, arb_identifier()),
+    ).prop_map(|(color, shadow_color, [bold, italic, underlined, strikethrough, obfuscated], insertion, font)| Style {
+        color, shadow_color, bold, italic, underlined, strikethrough, obfuscated, insertion, font,
+    })
+}
+
+/// A click event of any kind with a plausible value, e.g. a URL for `open_url`.
+pub fn arb_click_event() -> impl Strategy<Value = ClickEvent> {
+    prop_oneof![
+        "[a-z0-9-]{1,12}\\.example/[a-z0-9_/-]{0,16}".prop_map(|url| ClickEvent::OpenUrl(format!("https://{}", url))),
+        "[a-z0-9_-]{1,12}".prop_map(|name| ClickEvent::OpenFile(format!("screenshots/{}.png", name))),
+        "/[a-z]{1,12}( [a-z0-9@~.-]{1,8}){0,3}".prop_map(ClickEvent::RunCommand),
+        "/[a-z]{1,12} ".prop_map(ClickEvent::SuggestCommand),
+        (1..=100u8).prop_map(|page| ClickEvent::ChangePage(page.to_string())),
+        arb_text().prop_map(ClickEvent::CopyToClipboard),
+    ]
+}
+
+/// A hover event of any kind. Hover text and entity names are generated with [`arb_chat`] with the given depth.
+pub fn arb_hover_event(depth: u32) -> impl Strategy<Value = HoverEvent> {
+    hover_event(arb_chat(depth).boxed())
+}
+
+fn hover_event(text: BoxedStrategy<Chat>) -> impl Strategy<Value = HoverEvent> {
+    prop_oneof![
+        text.clone().prop_map(|text| HoverEvent::ShowText(Box::new(text))),
+        (arb_identifier(), option::of(1..=64i32), option::weighted(0.125, "\\{[a-zA-Z]{1,8}:[0-9]{1,3}\\}")).prop_map(|(id, count, tag)| HoverEvent::ShowItem {
+            id, count, tag,
+            components: None,
+        }),
+        (option::of(text), select(EntityKind::ALL), any::<[u8; 16]>()).prop_map(|(name, entity_type, id)| HoverEvent::ShowEntity {
+            name: name.map(Box::new),
+            entity_type,
+            id: Uuid::from_bytes(id),
+        }),
+    ]
+}
+
+/// A component without children, translation arguments, or hover text.
+fn arb_leaf() -> impl Strategy<Value = Chat> {
+    (arb_text(), arb_style(), option::weighted(0.125, arb_translation_key()), option::weighted(0.125, arb_click_event())).prop_map(|(text, style, translate, click_event)| {
+        let mut chat = Chat::from(text);
+        chat.set_style(style);
+        if translate.is_some() || click_event.is_some() {
+            let details = chat.details_mut();
+            details.translate = translate;
+            details.click_event = click_event;
+        }
+        chat
+    })
+}
+
+/// A tree of text components at most `depth` levels deep, counting `extra`, translation arguments, and hover text.
+///
+/// Shrinks toward fewer components and plainer styles.
+pub fn arb_chat(depth: u32) -> impl Strategy<Value = Chat> {
+    arb_leaf().prop_recursive(depth, 64, 4, |inner| (
+        arb_leaf(),
+        option::weighted(0.125, vec(inner.clone(), 0..4)),
+        option::weighted(0.125, hover_event(inner.clone())),
+        vec(inner, 0..4),
+    ).prop_map(|(mut chat, with, hover_event, extra)| {
+        if with.is_some() || hover_event.is_some() {
+            let ChatDetails { translate, with: args, hover_event: hover, .. } = chat.details_mut();
+            if let Some(with) = with {
+                translate.get_or_insert_with(|| "chat.type.text".to_owned());
+                *args = with;
+            }
+            *hover = hover_event;
+        }
+        chat.extra = extra;
+        chat
+    }))
+}
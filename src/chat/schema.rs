@@ -0,0 +1,218 @@
+//! [`JsonSchema`] implementations for the types in [`crate::chat`] which are (de)serialized by hand, enabled by the `schemars` feature.
+//!
+//! These describe the forms accepted when deserializing, e.g. plain strings and lists in places where vanilla accepts them instead of a text component object.
+
+use {
+    std::borrow::Cow,
+    schemars::{
+        JsonSchema,
+        Schema,
+        SchemaGenerator,
+        json_schema,
+    },
+    super::{
+        Chat,
+        ClickEvent,
+        Color,
+        HoverEvent,
+        NamedColor,
+        Style,
+    },
+};
+
+/// A text component in any of its forms: an object, a plain string, or a list whose first element is the root and whose other elements are appended to its `extra`.
+struct TextComponent;
+
+impl JsonSchema for TextComponent {
+    fn schema_name() -> Cow<'static, str> {
+        "TextComponent".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "anyOf": [
+                {"type": "string"},
+                {"type": "array", "items": generator.subschema_for::<TextComponent>()},
+                generator.subschema_for::<Chat>(),
+            ],
+        })
+    }
+}
+
+/// A shadow color, given as a signed ARGB integer or a list of four floats from 0 to 1 in RGBA order.
+fn shadow_color(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "anyOf": [
+            {"type": "integer", "minimum": i32::MIN, "maximum": i32::MAX},
+            {"type": "array", "items": {"type": "number", "minimum": 0, "maximum": 1}, "minItems": 4, "maxItems": 4},
+        ],
+    })
+}
+
+/// Validates the object form of text components. Where vanilla also accepts plain strings and lists, e.g. translation arguments and hover text, so does the schema.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::chat::Chat;
+///
+/// let schema = serde_json::to_value(schemars::schema_for!(Chat))?;
+/// let validator = jsonschema::validator_for(&schema)?;
+/// for valid in [
+///     serde_json::json!({"text": "hi"}),
+///     serde_json::json!({"text": "", "extra": [{"text": "a", "color": "#ff55aa", "bold": true}, {"text": "b", "color": "dark_red"}]}),
+///     serde_json::json!({"translate": "chat.type.text", "with": ["Notch", 42, true, [{"text": "a"}, "b"]]}),
+///     serde_json::json!({"text": "click", "clickEvent": {"action": "run_command", "value": "/help"}, "hoverEvent": {"action": "show_text", "contents": ["a", {"text": "b"}]}}),
+///     serde_json::json!({"text": "item", "hoverEvent": {"action": "show_item", "contents": {"id": "minecraft:diamond", "count": 3}}}),
+///     serde_json::json!({"text": "Notch", "hoverEvent": {"action": "show_entity", "contents": {"type": "minecraft:player", "id": "069a79f4-44e9-4726-a5be-fca90e38aaf5", "name": "Notch"}}}),
+///     serde_json::json!({"text": "old", "hoverEvent": {"action": "show_text", "value": "legacy"}, "shadow_color": [1.0, 0.5, 0.0, 1.0]}),
+/// ] {
+///     assert!(validator.is_valid(&valid), "{}", valid);
+///     Chat::deserialize(&valid)?;
+/// }
+/// for invalid in [
+///     serde_json::json!({"text": 42}),
+///     serde_json::json!({"text": "hi", "color": "#ff55a"}),
+///     serde_json::json!({"text": "hi", "color": "rainbow"}),
+///     serde_json::json!({"text": "hi", "bold": "yes"}),
+///     serde_json::json!({"text": "hi", "clickEvent": {"action": "explode", "value": "now"}}),
+///     serde_json::json!({"text": "hi", "hoverEvent": {"action": "show_text"}}),
+///     serde_json::json!({"text": "hi", "extra": "not a list"}),
+/// ] {
+///     assert!(!validator.is_valid(&invalid), "{}", invalid);
+///     assert!(Chat::deserialize(&invalid).is_err());
+/// }
+/// # use serde::Deserialize as _;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl JsonSchema for Chat {
+    fn schema_name() -> Cow<'static, str> {
+        "Chat".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let flag = json_schema!({"type": "boolean"});
+        let mut schema = json_schema!({
+            "type": "object",
+            "properties": {
+                "text": {"type": "string"},
+                "translate": {"type": "string"},
+                "with": {
+                    "type": "array",
+                    "items": {"anyOf": [generator.subschema_for::<TextComponent>(), {"type": "number"}, {"type": "boolean"}]},
+                },
+                "color": generator.subschema_for::<Color>(),
+                "shadow_color": shadow_color(generator),
+                "bold": flag,
+                "italic": flag,
+                "underlined": flag,
+                "strikethrough": flag,
+                "obfuscated": flag,
+                "clickEvent": generator.subschema_for::<ClickEvent>(),
+                "click_event": generator.subschema_for::<ClickEvent>(),
+                "hoverEvent": generator.subschema_for::<HoverEvent>(),
+                "hover_event": generator.subschema_for::<HoverEvent>(),
+                "insertion": {"type": "string"},
+                "font": {"type": "string"},
+                "extra": {"type": "array", "items": generator.subschema_for::<Chat>()},
+            },
+        });
+        if !cfg!(feature = "preserve-unknown-fields") {
+            schema.insert("additionalProperties".to_owned(), false.into());
+        }
+        schema
+    }
+}
+
+impl JsonSchema for Style {
+    fn schema_name() -> Cow<'static, str> {
+        "Style".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let flag = json_schema!({"type": "boolean"});
+        json_schema!({
+            "type": "object",
+            "properties": {
+                "color": generator.subschema_for::<Color>(),
+                "shadow_color": shadow_color(generator),
+                "bold": flag,
+                "italic": flag,
+                "underlined": flag,
+                "strikethrough": flag,
+                "obfuscated": flag,
+                "insertion": {"type": "string"},
+                "font": {"type": "string"},
+            },
+        })
+    }
+}
+
+/// Named colors are only accepted in their canonical snake_case form, even though [`Color`]'s [`FromStr`](std::str::FromStr) impl is more lenient.
+impl JsonSchema for Color {
+    fn schema_name() -> Cow<'static, str> {
+        "Color".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let names = NamedColor::ALL.iter().map(NamedColor::name).chain(["reset", "none"]).collect::<Vec<_>>();
+        json_schema!({
+            "type": "string",
+            "anyOf": [
+                {"enum": names},
+                {"pattern": "^#([0-9a-fA-F]{3}|[0-9a-fA-F]{6})$"},
+            ],
+        })
+    }
+}
+
+/// Accepts both the `contents` form used since 1.16 and the legacy `value` form.
+impl JsonSchema for HoverEvent {
+    fn schema_name() -> Cow<'static, str> {
+        "HoverEvent".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let text = generator.subschema_for::<TextComponent>();
+        let snbt = json_schema!({"type": "string"});
+        let event = |action: &str, key: &str, data: &Schema| json_schema!({
+            "type": "object",
+            "properties": {"action": {"const": action}, key: data},
+            "required": ["action", key],
+        });
+        let item = json_schema!({
+            "anyOf": [
+                {"type": "string"},
+                {
+                    "type": "object",
+                    "properties": {
+                        "id": {"type": "string"},
+                        "count": {"anyOf": [{"type": "integer"}, {"type": "string", "pattern": "^[+-]?[0-9]+$"}]},
+                        "tag": {"type": "string"},
+                        "components": {"type": "object"},
+                    },
+                    "required": ["id"],
+                },
+            ],
+        });
+        let entity = json_schema!({
+            "type": "object",
+            "properties": {
+                "type": {"type": "string"},
+                "id": {"type": "string", "format": "uuid"},
+                "name": text,
+            },
+            "required": ["type", "id"],
+        });
+        json_schema!({
+            "anyOf": [
+                event("show_text", "contents", &text),
+                event("show_text", "value", &text),
+                event("show_item", "contents", &item),
+                event("show_item", "value", &snbt),
+                event("show_entity", "contents", &entity),
+                event("show_entity", "value", &snbt),
+            ],
+        })
+    }
+}
@@ -0,0 +1,361 @@
+//! This module contains types for [signed chat messages](https://wiki.vg/Chat#Signed_messages) as used since 1.19.3, and for the evidence in chat reports.
+//!
+//! This crate doesn't verify signatures itself. Instead, the `to_signable_bytes` methods and [`signable_message_bytes`] produce the exact bytes which vanilla signs with SHA-256 with RSA, so they can be verified with any RSA implementation.
+
+use {
+    std::{
+        convert::TryFrom,
+        fmt,
+        time::{
+            Duration,
+            SystemTime,
+        },
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::Error as _,
+    },
+    uuid::Uuid,
+    crate::util::base64,
+};
+
+/// The length of a [`MessageSignature`] in bytes.
+pub const SIGNATURE_LENGTH: usize = 256;
+
+/// The RSA signature of a chat message, serialized as base64.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageSignature(pub [u8; SIGNATURE_LENGTH]);
+
+impl fmt::Debug for MessageSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MessageSignature({})", base64::encode(&self.0))
+    }
+}
+
+impl TryFrom<&[u8]> for MessageSignature {
+    type Error = usize;
+
+    /// Fails with the actual length if the slice is not [`SIGNATURE_LENGTH`] bytes long.
+    fn try_from(bytes: &[u8]) -> Result<MessageSignature, usize> {
+        <[u8; SIGNATURE_LENGTH]>::try_from(bytes).map(MessageSignature).map_err(|_| bytes.len())
+    }
+}
+
+impl Serialize for MessageSignature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        base64::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageSignature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<MessageSignature, D::Error> {
+        let bytes = base64::deserialize(deserializer)?;
+        MessageSignature::try_from(&*bytes).map_err(|len| D::Error::invalid_length(len, &"a 256-byte message signature"))
+    }
+}
+
+/// The nanoseconds since the Unix epoch, negative before it.
+fn unix_nanos(time: SystemTime) -> i128 {
+    match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(duration) => duration.as_nanos() as i128,
+        Err(e) => -(e.duration().as_nanos() as i128),
+    }
+}
+
+/// The inverse of [`unix_nanos`], or `None` if the time can't be represented on this platform.
+fn from_unix_nanos(nanos: i128) -> Option<SystemTime> {
+    let duration = |nanos: i128| Some(Duration::new(u64::try_from(nanos / 1_000_000_000).ok()?, (nanos % 1_000_000_000) as u32));
+    if nanos >= 0 {
+        SystemTime::UNIX_EPOCH.checked_add(duration(nanos)?)
+    } else {
+        SystemTime::UNIX_EPOCH.checked_sub(duration(-nanos)?)
+    }
+}
+
+/// The seconds since the Unix epoch, rounded down like Java's `Instant.getEpochSecond`.
+fn unix_seconds(time: SystemTime) -> i64 {
+    unix_nanos(time).div_euclid(1_000_000_000) as i64
+}
+
+/// The milliseconds since the Unix epoch, rounded down like Java's `Instant.toEpochMilli`.
+fn unix_millis(time: SystemTime) -> i64 {
+    unix_nanos(time).div_euclid(1_000_000) as i64
+}
+
+/// (De)serializes a point in time as an ISO 8601 string in UTC, like Java's `DateTimeFormatter.ISO_INSTANT`, e.g. `2024-06-13T16:04:22.512Z`.
+mod iso_instant {
+    use {
+        std::time::SystemTime,
+        serde::{
+            Deserialize as _,
+            Deserializer,
+            Serializer,
+            de,
+        },
+        crate::util,
+    };
+
+    pub(super) fn serialize<S: Serializer>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error> {
+        let nanos = super::unix_nanos(*time);
+        let seconds = nanos.div_euclid(1_000_000_000) as i64;
+        let fraction = nanos.rem_euclid(1_000_000_000);
+        let (year, month, day) = util::civil_from_days(seconds.div_euclid(86400));
+        let second_of_day = seconds.rem_euclid(86400);
+        let mut s = format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}", year, month, day, second_of_day / 3600, second_of_day / 60 % 60, second_of_day % 60);
+        // like Java, the fraction is written in groups of three digits
+        if fraction % 1_000_000 == 0 {
+            if fraction != 0 { s.push_str(&format!(".{:03}", fraction / 1_000_000)) }
+        } else if fraction % 1_000 == 0 {
+            s.push_str(&format!(".{:06}", fraction / 1_000));
+        } else {
+            s.push_str(&format!(".{:09}", fraction));
+        }
+        s.push('Z');
+        serializer.serialize_str(&s)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SystemTime, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        parse(&s).ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&s), &"an ISO 8601 instant like 2024-06-13T16:04:22Z"))
+    }
+
+    /// The range of years supported by Java's `Instant`.
+    const MAX_YEAR: i64 = 1_000_000_000;
+
+    fn parse(s: &str) -> Option<SystemTime> {
+        let number = |s: &str| if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) { s.parse::<i64>().ok() } else { None };
+        let (date, time) = s.strip_suffix('Z')?.split_once('T')?;
+        let (year, date) = date.get(..date.len().checked_sub(6)?).zip(date.get(date.len() - 6..))?;
+        let year = match year.strip_prefix('-') {
+            Some(year) => -number(year)?,
+            None => number(year.strip_prefix('+').unwrap_or(year))?,
+        };
+        if !(-MAX_YEAR..=MAX_YEAR).contains(&year) { return None }
+        let month = number(date.strip_prefix('-')?.get(..2)?)?;
+        let day = number(date.get(4..)?)?;
+        if date.get(3..4)? != "-" || !(1..=12).contains(&month) || !(1..=31).contains(&day) { return None }
+        let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+        let mut parts = time.split(':');
+        let hour = number(parts.next().filter(|part| part.len() == 2)?)?;
+        let minute = number(parts.next().filter(|part| part.len() == 2)?)?;
+        let second = number(parts.next().filter(|part| part.len() == 2)?)?;
+        if parts.next().is_some() || hour > 23 || minute > 59 || second > 59 { return None }
+        let nanos = if fraction.is_empty() {
+            0
+        } else {
+            if fraction.len() > 9 { return None }
+            number(fraction)? * 10i64.pow(9 - fraction.len() as u32)
+        };
+        let seconds = util::days_from_civil(year, month as u8, day as u8) * 86400 + hour * 3600 + minute * 60 + second;
+        super::from_unix_nanos(i128::from(seconds) * 1_000_000_000 + i128::from(nanos))
+    }
+}
+
+/// The signed contents of a chat message.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SignedMessageBody {
+    /// The message as typed by the player.
+    pub content: String,
+    /// When the message was sent. Only whole seconds are signed.
+    pub timestamp: SystemTime,
+    /// A random number chosen by the client.
+    pub salt: i64,
+    /// The signatures of the messages the sender had seen, which are signed to preserve the context of the message.
+    pub last_seen: Vec<MessageSignature>,
+}
+
+impl SignedMessageBody {
+    /// The bytes vanilla includes in the signature for this message body.
+    pub fn to_signable_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(24 + self.content.len() + self.last_seen.len() * SIGNATURE_LENGTH);
+        bytes.extend_from_slice(&self.salt.to_be_bytes());
+        bytes.extend_from_slice(&unix_seconds(self.timestamp).to_be_bytes());
+        bytes.extend_from_slice(&(self.content.len() as i32).to_be_bytes());
+        bytes.extend_from_slice(self.content.as_bytes());
+        bytes.extend_from_slice(&(self.last_seen.len() as i32).to_be_bytes());
+        for signature in &self.last_seen {
+            bytes.extend_from_slice(&signature.0);
+        }
+        bytes
+    }
+}
+
+/// The position of a message in the chain of messages signed by a player during a chat session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignedMessageLink {
+    /// The number of messages sent by the player earlier in the session.
+    pub index: i32,
+    /// The UUID of the player who sent the message.
+    pub sender: Uuid,
+    /// The ID of the chat session, chosen by the client when joining.
+    pub session_id: Uuid,
+}
+
+impl SignedMessageLink {
+    /// The bytes vanilla includes in the signature for this link.
+    pub fn to_signable_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(36);
+        bytes.extend_from_slice(self.sender.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.index.to_be_bytes());
+        bytes
+    }
+}
+
+/// The complete bytes vanilla signs for a chat message, i.e. the format version followed by the link and body.
+///
+/// The message is valid if its signature verifies against these bytes using SHA-256 with RSA and the sender's [`ProfilePublicKey`].
+pub fn signable_message_bytes(link: &SignedMessageLink, body: &SignedMessageBody) -> Vec<u8> {
+    let mut bytes = 1i32.to_be_bytes().to_vec();
+    bytes.extend(link.to_signable_bytes());
+    bytes.extend(body.to_signable_bytes());
+    bytes
+}
+
+/// The public key of a player's chat session, issued by Mojang and signed with Mojang's key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ProfilePublicKey {
+    /// When the key stops being valid.
+    #[serde(with = "iso_instant")]
+    pub expires_at: SystemTime,
+    /// The RSA public key in DER encoding, serialized in PEM format.
+    #[serde(with = "pem_public_key")]
+    pub key: Vec<u8>,
+    /// Mojang's signature of the key.
+    #[serde(rename = "signature_v2", with = "base64")]
+    pub signature: Vec<u8>,
+}
+
+impl ProfilePublicKey {
+    /// The bytes signed by Mojang for this key when issued to the player with the given UUID.
+    pub fn to_signable_bytes(&self, profile_id: Uuid) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(24 + self.key.len());
+        bytes.extend_from_slice(profile_id.as_bytes());
+        bytes.extend_from_slice(&unix_millis(self.expires_at).to_be_bytes());
+        bytes.extend_from_slice(&self.key);
+        bytes
+    }
+
+    /// Whether the key has expired at the given time.
+    pub fn is_expired_at(&self, time: SystemTime) -> bool {
+        time >= self.expires_at
+    }
+}
+
+/// (De)serializes a DER-encoded RSA public key in the PEM format used by vanilla.
+mod pem_public_key {
+    use {
+        serde::{
+            Deserialize as _,
+            Deserializer,
+            Serializer,
+            de,
+        },
+        crate::util::base64,
+    };
+
+    const HEADER: &str = "-----BEGIN RSA PUBLIC KEY-----";
+    const FOOTER: &str = "-----END RSA PUBLIC KEY-----";
+
+    pub(super) fn serialize<S: Serializer>(key: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        // Java's MIME encoder wraps lines at 76 characters
+        let encoded = base64::encode(key);
+        let lines = encoded.as_bytes().chunks(76).map(|line| std::str::from_utf8(line).expect("base64 is ASCII")).collect::<Vec<_>>();
+        serializer.serialize_str(&format!("{}\n{}\n{}\n", HEADER, lines.join("\r\n"), FOOTER))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let body = s.trim();
+        let body = body.strip_prefix(HEADER).unwrap_or(body);
+        let body = body.strip_suffix(FOOTER).unwrap_or(body);
+        base64::decode(&body.chars().filter(|c| !c.is_ascii_whitespace()).collect::<String>())
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&s), &"a PEM-encoded RSA public key"))
+    }
+}
+
+/// A message included as evidence in a chat report, in the JSON format sent to the abuse report API.
+///
+/// # Example
+///
+/// ```
+/// use {
+///     std::time::{
+///         Duration,
+///         SystemTime,
+///     },
+///     minecraft::chat::signing::{
+///         ReportedChatMessage,
+///         signable_message_bytes,
+///     },
+/// };
+///
+/// let signature = "A".repeat(340) + "AA==";
+/// let json = format!(r#"{{"profileId":"853c80ef-3c37-49fd-aa49-938b674adae6","sessionId":"0b1d3e5f-0000-4000-8000-000000000000","index":2,"timestamp":"2024-06-13T16:04:22.512Z","salt":-42,"lastSeen":["{0}"],"message":"hi","messageSignature":"{0}","messageReported":true}}"#, signature);
+/// let message = serde_json::from_str::<ReportedChatMessage>(&json)?;
+/// assert_eq!(serde_json::to_string(&message)?, json);
+/// assert_eq!(message.timestamp, SystemTime::UNIX_EPOCH + Duration::from_millis(1_718_294_662_512));
+///
+/// let bytes = signable_message_bytes(&message.link(), &message.body());
+/// assert_eq!(bytes.len(), 4 + (16 + 16 + 4) + (8 + 8 + 4 + 2 + 4 + 256));
+/// assert_eq!(bytes[..4], [0, 0, 0, 1]); // version
+/// assert_eq!(bytes[36..40], [0, 0, 0, 2]); // index
+/// assert_eq!(bytes[40..48], (-42i64).to_be_bytes()); // salt
+/// assert_eq!(bytes[48..56], 1_718_294_662i64.to_be_bytes()); // whole seconds
+/// assert_eq!(bytes[56..62], [0, 0, 0, 2, b'h', b'i']); // content
+///
+/// // timestamps outside the range of Java's `Instant` are an error
+/// let with_timestamp = |timestamp| json.replace("2024-06-13T16:04:22.512Z", timestamp);
+/// assert!(serde_json::from_str::<ReportedChatMessage>(&with_timestamp("+10000-01-01T00:00:00Z")).is_ok());
+/// assert!(serde_json::from_str::<ReportedChatMessage>(&with_timestamp("+1000000001-01-01T00:00:00Z")).is_err());
+/// assert!(serde_json::from_str::<ReportedChatMessage>(&with_timestamp("99999999999999-01-01T00:00:00Z")).is_err());
+/// assert!(serde_json::from_str::<ReportedChatMessage>(&with_timestamp("-99999999999999-01-01T00:00:00Z")).is_err());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportedChatMessage {
+    /// The UUID of the player who sent the message.
+    pub profile_id: Uuid,
+    /// The ID of the sender's chat session.
+    pub session_id: Uuid,
+    /// The index of the message in the sender's chat session.
+    pub index: i32,
+    /// When the message was sent.
+    #[serde(with = "iso_instant")]
+    pub timestamp: SystemTime,
+    /// The salt chosen by the client.
+    pub salt: i64,
+    /// The signatures of the messages the sender had seen.
+    pub last_seen: Vec<MessageSignature>,
+    /// The message as typed by the player.
+    pub message: String,
+    /// The signature of the message.
+    pub message_signature: MessageSignature,
+    /// Whether this is one of the messages selected by the reporter, as opposed to a message included for context.
+    pub message_reported: bool,
+}
+
+impl ReportedChatMessage {
+    /// The position of the message in the sender's chat session.
+    pub fn link(&self) -> SignedMessageLink {
+        SignedMessageLink {
+            index: self.index,
+            sender: self.profile_id,
+            session_id: self.session_id,
+        }
+    }
+
+    /// The signed contents of the message.
+    pub fn body(&self) -> SignedMessageBody {
+        SignedMessageBody {
+            content: self.message.clone(),
+            timestamp: self.timestamp,
+            salt: self.salt,
+            last_seen: self.last_seen.clone(),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! This module contains types for how chat messages are displayed: the [`ChatPosition`] of the legacy chat packet and the [chat types](https://minecraft.fandom.com/wiki/Chat_type) registry added in 1.19.
+
+use {
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::chat::{
+        Chat,
+        ChatDetails,
+        Style,
+    },
+};
+
+/// Where a message is displayed, as sent in the chat packet before 1.19.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChatPosition {
+    /// A message sent by a player, which is hidden if the player has chat disabled.
+    Chat,
+    /// A message from the server, like command feedback, which is only hidden if the player has chat fully disabled.
+    System,
+    /// A message shown above the hotbar, like the action bar.
+    GameInfo,
+}
+
+impl ChatPosition {
+    /// All positions, in ID order.
+    pub const ALL: [ChatPosition; 3] = [ChatPosition::Chat, ChatPosition::System, ChatPosition::GameInfo];
+
+    /// The numeric ID used in the chat packet.
+    pub fn id(&self) -> u8 {
+        match self {
+            ChatPosition::Chat => 0,
+            ChatPosition::System => 1,
+            ChatPosition::GameInfo => 2,
+        }
+    }
+
+    /// Looks up a position by its numeric ID.
+    pub fn from_id(id: u8) -> Option<ChatPosition> {
+        match id {
+            0 => Some(ChatPosition::Chat),
+            1 => Some(ChatPosition::System),
+            2 => Some(ChatPosition::GameInfo),
+            _ => None,
+        }
+    }
+}
+
+/// A value substituted into a [`ChatTypeDecoration`]'s translation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatTypeParameter {
+    /// The display name of the sender.
+    Sender,
+    /// The display name of the recipient, e.g. in `/msg`, or empty if there is none.
+    Target,
+    /// The message itself.
+    Content,
+}
+
+/// How a message of a [`ChatType`] is displayed or narrated: a translation key whose arguments are the given parameters, with a style applied to the whole.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::{
+///     chat::Chat,
+///     chat_type::ChatType,
+/// };
+///
+/// // the vanilla minecraft:msg_command_incoming chat type
+/// let chat_type = serde_json::from_str::<ChatType>(r#"{
+///     "chat": {"translation_key": "commands.message.display.incoming", "parameters": ["sender", "content"], "style": {"color": "gray", "italic": true}},
+///     "narration": {"translation_key": "chat.type.text.narrate", "parameters": ["sender", "content"]}
+/// }"#)?;
+/// let message = chat_type.chat.decorate(Chat::from("Notch"), Chat::from("hi"), None);
+/// assert_eq!(message.to_json().unwrap(), r#"{"translate":"commands.message.display.incoming","with":[{"text":"Notch"},{"text":"hi"}],"color":"gray","italic":true}"#);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ChatTypeDecoration {
+    /// The translation key, e.g. `chat.type.text`, which is `<%s> %s` in English.
+    pub translation_key: String,
+    /// The values substituted into the translation, in order.
+    pub parameters: Vec<ChatTypeParameter>,
+    /// The style applied to the translated text. Parameters inherit it unless they override it.
+    #[serde(default, skip_serializing_if = "is_default_style")]
+    pub style: Style,
+}
+
+fn is_default_style(style: &Style) -> bool {
+    *style == Style::default()
+}
+
+impl ChatTypeDecoration {
+    /// Creates a decoration without a style.
+    pub fn new(translation_key: impl Into<String>, parameters: Vec<ChatTypeParameter>) -> ChatTypeDecoration {
+        ChatTypeDecoration {
+            translation_key: translation_key.into(),
+            parameters,
+            style: Style::default(),
+        }
+    }
+
+    /// The message as displayed by the client, given the sender's display name, the message content, and the recipient's display name if any.
+    pub fn decorate(&self, sender: Chat, content: Chat, target: Option<Chat>) -> Chat {
+        let mut chat = Chat::default();
+        chat.details = Some(Box::new(ChatDetails {
+            translate: Some(self.translation_key.clone()),
+            with: self.parameters.iter().map(|parameter| match parameter {
+                ChatTypeParameter::Sender => sender.clone(),
+                ChatTypeParameter::Target => target.clone().unwrap_or_default(),
+                ChatTypeParameter::Content => content.clone(),
+            }).collect(),
+            ..ChatDetails::default()
+        }));
+        chat.set_style(self.style.clone());
+        chat
+    }
+}
+
+/// An entry of the `minecraft:chat_type` registry, as found in data packs and the registry data sent to clients.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ChatType {
+    /// How messages are shown in chat.
+    pub chat: ChatTypeDecoration,
+    /// How messages are read by the narrator.
+    pub narration: ChatTypeDecoration,
+}
@@ -0,0 +1,182 @@
+//! This module contains the [`Difficulty`] type, representing the [difficulty](https://minecraft.fandom.com/wiki/Difficulty) setting.
+
+use {
+    std::{
+        convert::TryFrom,
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::{
+            self,
+            Visitor,
+        },
+    },
+    crate::util::nbt_bool,
+};
+
+/// One of the four difficulty levels.
+///
+/// Serializes as the lowercase name by default; use [`id`] with `#[serde(with = "...")]` to serialize as the numeric ID instead. Deserializes from either form, case-insensitively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)] // variants are obvious
+pub enum Difficulty {
+    Peaceful,
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// All difficulties, in ID order.
+    pub const ALL: [Difficulty; 4] = [Difficulty::Peaceful, Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    /// The numeric ID used in `level.dat` and packets.
+    pub fn id(&self) -> u8 {
+        match self {
+            Difficulty::Peaceful => 0,
+            Difficulty::Easy => 1,
+            Difficulty::Normal => 2,
+            Difficulty::Hard => 3,
+        }
+    }
+
+    /// Looks up a difficulty by its numeric ID.
+    pub fn from_id(id: u8) -> Option<Difficulty> {
+        match id {
+            0 => Some(Difficulty::Peaceful),
+            1 => Some(Difficulty::Easy),
+            2 => Some(Difficulty::Normal),
+            3 => Some(Difficulty::Hard),
+            _ => None,
+        }
+    }
+
+    /// The lowercase name used in `server.properties` and commands, e.g. `hard`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Peaceful => "peaceful",
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        }
+    }
+}
+
+/// The error returned when parsing a [`Difficulty`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DifficultyParseError(pub String);
+
+impl fmt::Display for DifficultyParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown difficulty: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for DifficultyParseError {}
+
+impl FromStr for Difficulty {
+    type Err = DifficultyParseError;
+
+    /// Parses a difficulty from its name (case-insensitively) or its numeric ID.
+    fn from_str(s: &str) -> Result<Difficulty, DifficultyParseError> {
+        let trimmed = s.trim();
+        Difficulty::ALL.iter()
+            .find(|difficulty| trimmed.eq_ignore_ascii_case(difficulty.name()))
+            .copied()
+            .or_else(|| trimmed.parse().ok().and_then(Difficulty::from_id))
+            .ok_or_else(|| DifficultyParseError(s.to_owned()))
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Serialize for Difficulty {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+struct DifficultyVisitor;
+
+impl<'de> Visitor<'de> for DifficultyVisitor {
+    type Value = Difficulty;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a difficulty name or numeric ID")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Difficulty, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Difficulty, E> {
+        u8::try_from(v).ok().and_then(Difficulty::from_id).ok_or_else(|| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Difficulty, E> {
+        u8::try_from(v).ok().and_then(Difficulty::from_id).ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Difficulty {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Difficulty, D::Error> {
+        deserializer.deserialize_any(DifficultyVisitor)
+    }
+}
+
+/// Serde helpers for [`Difficulty`] fields stored as numeric IDs, as in `level.dat`.
+///
+/// Use with `#[serde(with = "minecraft::difficulty::id")]`. Deserialization accepts names as well.
+pub mod id {
+    use {
+        serde::{
+            Deserialize as _,
+            Deserializer,
+            Serializer,
+        },
+        super::Difficulty,
+    };
+
+    /// Serializes the difficulty as its numeric ID.
+    pub fn serialize<S: Serializer>(difficulty: &Difficulty, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(difficulty.id())
+    }
+
+    /// Deserializes a difficulty from its numeric ID or name.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Difficulty, D::Error> {
+        Difficulty::deserialize(deserializer)
+    }
+}
+
+/// The difficulty-related fields of `level.dat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DifficultySetting {
+    /// The difficulty level.
+    #[serde(rename = "Difficulty", with = "id")]
+    pub difficulty: Difficulty,
+    /// Whether the world is in hardcore mode. The difficulty is always hard in this case.
+    #[serde(default, with = "nbt_bool")]
+    pub hardcore: bool,
+    /// Whether the difficulty has been locked in the options menu.
+    #[serde(rename = "DifficultyLocked", default, with = "nbt_bool")]
+    pub locked: bool,
+}
+
+impl Default for DifficultySetting {
+    fn default() -> DifficultySetting {
+        DifficultySetting {
+            difficulty: Difficulty::Normal,
+            hardcore: false,
+            locked: false,
+        }
+    }
+}
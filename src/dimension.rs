@@ -0,0 +1,105 @@
+//! This module contains the [`Dimension`] type, representing a [dimension](https://minecraft.fandom.com/wiki/Dimension).
+
+use {
+    std::{
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::{
+        identifier::{
+            Identifier,
+            IdentifierParseError,
+        },
+        position::Vec3,
+    },
+};
+
+/// A dimension, identified by its namespaced ID.
+///
+/// Besides the three vanilla dimensions, data packs can add custom ones. Serializes as the full namespaced ID.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Dimension(pub Identifier);
+
+impl Dimension {
+    /// `minecraft:overworld`
+    pub const OVERWORLD: Dimension = Dimension(Identifier::minecraft("overworld"));
+    /// `minecraft:the_nether`
+    pub const THE_NETHER: Dimension = Dimension(Identifier::minecraft("the_nether"));
+    /// `minecraft:the_end`
+    pub const THE_END: Dimension = Dimension(Identifier::minecraft("the_end"));
+
+    /// Converts the numeric dimension IDs used before 1.16 (-1 for the Nether, 0 for the Overworld, 1 for the End).
+    pub fn from_legacy_id(id: i32) -> Option<Dimension> {
+        match id {
+            -1 => Some(Dimension::THE_NETHER),
+            0 => Some(Dimension::OVERWORLD),
+            1 => Some(Dimension::THE_END),
+            _ => None,
+        }
+    }
+
+    /// The numeric dimension ID used before 1.16, if this is a vanilla dimension.
+    pub fn legacy_id(&self) -> Option<i32> {
+        if *self == Dimension::THE_NETHER {
+            Some(-1)
+        } else if *self == Dimension::OVERWORLD {
+            Some(0)
+        } else if *self == Dimension::THE_END {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    /// How many blocks in the Overworld one block in this dimension corresponds to, i.e. 8 for the Nether and 1 otherwise.
+    ///
+    /// Custom dimension types can have other scales, but this information is not available from the ID alone.
+    pub fn coordinate_scale(&self) -> f64 {
+        if *self == Dimension::THE_NETHER { 8.0 } else { 1.0 }
+    }
+
+    /// Whether this dimension has a bedrock ceiling, i.e. whether it's the Nether.
+    pub fn has_ceiling(&self) -> bool {
+        *self == Dimension::THE_NETHER
+    }
+
+    /// Converts horizontal coordinates from this dimension to the corresponding coordinates in another, e.g. for linking Nether portals.
+    ///
+    /// The y coordinate is left unchanged.
+    pub fn convert_pos(&self, pos: Vec3, target: &Dimension) -> Vec3 {
+        let factor = self.coordinate_scale() / target.coordinate_scale();
+        Vec3::new(pos.x * factor, pos.y, pos.z * factor)
+    }
+}
+
+impl From<Identifier> for Dimension {
+    fn from(id: Identifier) -> Dimension {
+        Dimension(id)
+    }
+}
+
+impl From<Dimension> for Identifier {
+    fn from(dimension: Dimension) -> Identifier {
+        dimension.0
+    }
+}
+
+impl FromStr for Dimension {
+    type Err = IdentifierParseError;
+
+    /// Parses a dimension ID, using the `minecraft` namespace if none is given.
+    fn from_str(s: &str) -> Result<Dimension, IdentifierParseError> {
+        s.parse().map(Dimension)
+    }
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,132 @@
+//! This module contains the [`DyeColor`] type, representing the 16 [dye](https://minecraft.fandom.com/wiki/Dye) colors.
+
+use {
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::chat::{
+        Color,
+        NamedColor,
+    },
+};
+
+/// One of the 16 dye colors, used for wool, fireworks, sign text, and so on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)] // variants are obvious
+pub enum DyeColor {
+    White,
+    Orange,
+    Magenta,
+    LightBlue,
+    Yellow,
+    Lime,
+    Pink,
+    Gray,
+    LightGray,
+    Cyan,
+    Purple,
+    Blue,
+    Brown,
+    Green,
+    Red,
+    Black,
+}
+
+impl DyeColor {
+    /// All dye colors, in ID order.
+    pub const ALL: [DyeColor; 16] = [
+        DyeColor::White,
+        DyeColor::Orange,
+        DyeColor::Magenta,
+        DyeColor::LightBlue,
+        DyeColor::Yellow,
+        DyeColor::Lime,
+        DyeColor::Pink,
+        DyeColor::Gray,
+        DyeColor::LightGray,
+        DyeColor::Cyan,
+        DyeColor::Purple,
+        DyeColor::Blue,
+        DyeColor::Brown,
+        DyeColor::Green,
+        DyeColor::Red,
+        DyeColor::Black,
+    ];
+
+    /// The numeric ID, e.g. as used for wool metadata before 1.13.
+    pub fn id(&self) -> u8 {
+        DyeColor::ALL.iter().position(|color| color == self).expect("all colors are in DyeColor::ALL") as u8
+    }
+
+    /// Looks up a dye color by its numeric ID.
+    pub fn from_id(id: u8) -> Option<DyeColor> {
+        DyeColor::ALL.get(usize::from(id)).copied()
+    }
+
+    /// The RGB value used for firework explosions of this color.
+    pub fn firework_rgb(&self) -> [u8; 3] {
+        match self {
+            DyeColor::White => [0xf0, 0xf0, 0xf0],
+            DyeColor::Orange => [0xeb, 0x88, 0x44],
+            DyeColor::Magenta => [0xc3, 0x54, 0xcd],
+            DyeColor::LightBlue => [0x66, 0x89, 0xd3],
+            DyeColor::Yellow => [0xde, 0xcf, 0x2a],
+            DyeColor::Lime => [0x41, 0xcd, 0x34],
+            DyeColor::Pink => [0xd8, 0x81, 0x98],
+            DyeColor::Gray => [0x43, 0x43, 0x43],
+            DyeColor::LightGray => [0xab, 0xab, 0xab],
+            DyeColor::Cyan => [0x28, 0x76, 0x97],
+            DyeColor::Purple => [0x7b, 0x2f, 0xbe],
+            DyeColor::Blue => [0x25, 0x31, 0x92],
+            DyeColor::Brown => [0x51, 0x30, 0x1a],
+            DyeColor::Green => [0x3b, 0x51, 0x1a],
+            DyeColor::Red => [0xb3, 0x31, 0x2c],
+            DyeColor::Black => [0x1e, 0x1b, 0x1b],
+        }
+    }
+
+    /// The RGB value used for sign text dyed with this color.
+    pub fn text_rgb(&self) -> [u8; 3] {
+        match self {
+            DyeColor::White => [0xff, 0xff, 0xff],
+            DyeColor::Orange => [0xff, 0x68, 0x1f],
+            DyeColor::Magenta => [0xff, 0x00, 0xff],
+            DyeColor::LightBlue => [0x9a, 0xc0, 0xcd],
+            DyeColor::Yellow => [0xff, 0xff, 0x00],
+            DyeColor::Lime => [0xbf, 0xff, 0x00],
+            DyeColor::Pink => [0xff, 0x69, 0xb4],
+            DyeColor::Gray => [0x80, 0x80, 0x80],
+            DyeColor::LightGray => [0xd3, 0xd3, 0xd3],
+            DyeColor::Cyan => [0x00, 0xff, 0xff],
+            DyeColor::Purple => [0xa0, 0x20, 0xf0],
+            DyeColor::Blue => [0x00, 0x00, 0xff],
+            DyeColor::Brown => [0x8b, 0x45, 0x13],
+            DyeColor::Green => [0x00, 0xff, 0x00],
+            DyeColor::Red => [0xff, 0x00, 0x00],
+            DyeColor::Black => [0x00, 0x00, 0x00],
+        }
+    }
+
+    /// The chat color with the same name, if any.
+    pub fn named_color(&self) -> Option<NamedColor> {
+        match self {
+            DyeColor::White => Some(NamedColor::White),
+            DyeColor::Yellow => Some(NamedColor::Yellow),
+            DyeColor::Gray => Some(NamedColor::Gray),
+            DyeColor::Blue => Some(NamedColor::Blue),
+            DyeColor::Green => Some(NamedColor::Green),
+            DyeColor::Red => Some(NamedColor::Red),
+            DyeColor::Black => Some(NamedColor::Black),
+            _ => None,
+        }
+    }
+}
+
+impl From<DyeColor> for Color {
+    /// Converts to the hex color used for sign text of this color.
+    fn from(color: DyeColor) -> Color {
+        Color::Hex(color.text_rgb())
+    }
+}
@@ -0,0 +1,257 @@
+//! This module contains types for [status effects](https://minecraft.fandom.com/wiki/Effect).
+
+use {
+    std::{
+        convert::TryFrom,
+        fmt,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        de::{
+            self,
+            Visitor,
+        },
+    },
+    crate::{
+        chat::{
+            Chat,
+            NamedColor,
+        },
+        ticks::Ticks,
+        util::{
+            self,
+            nbt_bool,
+            unsigned_byte,
+        },
+    },
+};
+
+registry_enum! {
+    /// A status effect, identified by its namespaced ID.
+    #[allow(missing_docs)] // variants are obvious
+    pub enum StatusEffect {
+        Speed => "speed",
+        Slowness => "slowness",
+        Haste => "haste",
+        MiningFatigue => "mining_fatigue",
+        Strength => "strength",
+        InstantHealth => "instant_health",
+        InstantDamage => "instant_damage",
+        JumpBoost => "jump_boost",
+        Nausea => "nausea",
+        Regeneration => "regeneration",
+        Resistance => "resistance",
+        FireResistance => "fire_resistance",
+        WaterBreathing => "water_breathing",
+        Invisibility => "invisibility",
+        Blindness => "blindness",
+        NightVision => "night_vision",
+        Hunger => "hunger",
+        Weakness => "weakness",
+        Poison => "poison",
+        Wither => "wither",
+        HealthBoost => "health_boost",
+        Absorption => "absorption",
+        Saturation => "saturation",
+        Glowing => "glowing",
+        Levitation => "levitation",
+        Luck => "luck",
+        Unluck => "unluck",
+        SlowFalling => "slow_falling",
+        ConduitPower => "conduit_power",
+        DolphinsGrace => "dolphins_grace",
+        BadOmen => "bad_omen",
+        HeroOfTheVillage => "hero_of_the_village",
+        Darkness => "darkness",
+        TrialOmen => "trial_omen",
+        RaidOmen => "raid_omen",
+        WindCharged => "wind_charged",
+        Weaving => "weaving",
+        Oozing => "oozing",
+        Infested => "infested",
+    }
+}
+
+impl StatusEffect {
+    /// The numeric ID used in NBT before 1.20.2, or `None` for effects added later and [`StatusEffect::Other`].
+    pub fn legacy_id(&self) -> Option<u8> {
+        let idx = StatusEffect::ALL.iter().position(|effect| effect == self)?;
+        if idx > StatusEffect::ALL.iter().position(|effect| *effect == StatusEffect::Darkness)? { return None }
+        u8::try_from(idx + 1).ok()
+    }
+
+    /// Looks up an effect by the numeric ID used in NBT before 1.20.2.
+    pub fn from_legacy_id(id: u8) -> Option<StatusEffect> {
+        StatusEffect::ALL.iter().find(|effect| effect.legacy_id() == Some(id)).cloned()
+    }
+
+    /// Whether this effect is in the beneficial category, shown in blue in tooltips.
+    ///
+    /// Returns `false` for [`StatusEffect::Other`].
+    pub fn is_beneficial(&self) -> bool {
+        matches!(self,
+            StatusEffect::Speed | StatusEffect::Haste | StatusEffect::Strength | StatusEffect::InstantHealth | StatusEffect::JumpBoost
+            | StatusEffect::Regeneration | StatusEffect::Resistance | StatusEffect::FireResistance | StatusEffect::WaterBreathing
+            | StatusEffect::Invisibility | StatusEffect::NightVision | StatusEffect::HealthBoost | StatusEffect::Absorption
+            | StatusEffect::Saturation | StatusEffect::Luck | StatusEffect::SlowFalling | StatusEffect::ConduitPower
+            | StatusEffect::DolphinsGrace | StatusEffect::HeroOfTheVillage
+        )
+    }
+
+    /// Whether this effect is in the harmful category, shown in red in tooltips.
+    ///
+    /// Effects like Glowing or Bad Omen are neither beneficial nor harmful. Returns `false` for [`StatusEffect::Other`].
+    pub fn is_harmful(&self) -> bool {
+        matches!(self,
+            StatusEffect::Slowness | StatusEffect::MiningFatigue | StatusEffect::InstantDamage | StatusEffect::Nausea
+            | StatusEffect::Blindness | StatusEffect::Hunger | StatusEffect::Weakness | StatusEffect::Poison | StatusEffect::Wither
+            | StatusEffect::Levitation | StatusEffect::Unluck | StatusEffect::Darkness | StatusEffect::WindCharged
+            | StatusEffect::Weaving | StatusEffect::Oozing | StatusEffect::Infested
+        )
+    }
+
+    /// The translation key for the name of this effect, e.g. `effect.minecraft.speed`.
+    pub fn translation_key(&self) -> String {
+        let id = self.id();
+        format!("effect.{}.{}", id.namespace(), id.path().replace('/', "."))
+    }
+
+    /// The name of this effect in American English, e.g. `Hero of the Village`.
+    ///
+    /// For [`StatusEffect::Other`], this is guessed from the ID.
+    pub fn english_name(&self) -> String {
+        match self {
+            StatusEffect::Unluck => "Bad Luck".to_owned(),
+            StatusEffect::DolphinsGrace => "Dolphin's Grace".to_owned(),
+            _ => util::title_case(self.id().path()),
+        }
+    }
+}
+
+struct StatusEffectVisitor;
+
+impl<'de> Visitor<'de> for StatusEffectVisitor {
+    type Value = StatusEffect;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a status effect ID or legacy numeric ID")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<StatusEffect, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<StatusEffect, E> {
+        u8::try_from(v).ok().and_then(StatusEffect::from_legacy_id).ok_or_else(|| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<StatusEffect, E> {
+        u8::try_from(v).ok().and_then(StatusEffect::from_legacy_id).ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+}
+
+fn deserialize_effect<'de, D: Deserializer<'de>>(deserializer: D) -> Result<StatusEffect, D::Error> {
+    deserializer.deserialize_any(StatusEffectVisitor)
+}
+
+fn default_true() -> bool { true }
+
+/// An active status effect, as stored on entities and in item components.
+///
+/// Serializes in the lowercase form used since 1.20.2. Deserialization also accepts the older form with capitalized keys like `Id` and `Amplifier` and a numeric effect ID.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::effect::{
+///     EffectInstance,
+///     StatusEffect,
+/// };
+///
+/// let effect = serde_json::from_str::<EffectInstance>(r#"{"Id":5,"Amplifier":-1,"Duration":600}"#)?;
+/// assert_eq!(effect.effect, StatusEffect::Strength);
+/// assert_eq!(effect.amplifier, 255);
+/// assert_eq!(effect.to_string(), "Strength 256 (0:30)");
+/// assert_eq!(serde_json::from_str::<EffectInstance>(r#"{"id":"minecraft:strength","amplifier":255}"#)?.amplifier, 255);
+/// assert!(serde_json::from_str::<EffectInstance>(r#"{"id":"minecraft:strength","amplifier":-129}"#).is_err());
+/// assert!(serde_json::from_str::<EffectInstance>(r#"{"id":"minecraft:strength","amplifier":256}"#).is_err());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EffectInstance {
+    /// Which effect this is.
+    #[serde(rename = "id", alias = "Id", deserialize_with = "deserialize_effect")]
+    pub effect: StatusEffect,
+    /// The effect level minus one, so 0 means level I.
+    ///
+    /// NBT stores this as a signed byte, so levels above 128 are negative there, e.g. `-1b` for level 256. These are read back as the unsigned value, like in vanilla.
+    #[serde(default, alias = "Amplifier", with = "unsigned_byte")]
+    pub amplifier: u8,
+    /// The remaining duration. -1 means infinite.
+    #[serde(default, alias = "Duration")]
+    pub duration: Ticks,
+    /// Whether this effect was applied by a beacon or conduit, which makes the particles less visible.
+    #[serde(default, alias = "Ambient", with = "nbt_bool")]
+    pub ambient: bool,
+    /// Whether the effect emits particles.
+    #[serde(default = "default_true", alias = "ShowParticles", with = "nbt_bool")]
+    pub show_particles: bool,
+    /// Whether the effect is shown in the top right corner of the screen.
+    #[serde(default = "default_true", alias = "ShowIcon", with = "nbt_bool")]
+    pub show_icon: bool,
+}
+
+impl EffectInstance {
+    /// Creates an effect instance with the given effect, amplifier, and duration, with particles and icon shown.
+    pub fn new(effect: StatusEffect, amplifier: u8, duration: Ticks) -> EffectInstance {
+        EffectInstance {
+            ambient: false,
+            show_particles: true,
+            show_icon: true,
+            effect, amplifier, duration,
+        }
+    }
+
+    /// Whether this effect lasts forever, as with the `infinite` duration in the `effect` command.
+    pub fn is_infinite(&self) -> bool {
+        self.duration == Ticks(-1)
+    }
+
+    /// The line for this effect as shown in potion tooltips, e.g. `Strength II (1:30)`.
+    ///
+    /// Like in vanilla, the line is red for harmful effects and blue otherwise.
+    pub fn to_chat(&self) -> Chat {
+        let mut chat = Chat::from(self.to_string());
+        chat.color(if self.effect.is_harmful() { NamedColor::Red } else { NamedColor::Blue });
+        chat
+    }
+}
+
+impl fmt::Display for EffectInstance {
+    /// Formats like the potion tooltip line, e.g. `Strength II (1:30)` or `Glowing (∞)`.
+    ///
+    /// Level I is not shown, and levels above X are shown as numbers.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.effect.english_name())?;
+        if self.amplifier > 0 {
+            let level = u32::from(self.amplifier) + 1;
+            match util::roman_numeral(level) {
+                Some(numeral) => write!(f, " {}", numeral)?,
+                None => write!(f, " {}", level)?,
+            }
+        }
+        if self.is_infinite() {
+            write!(f, " (∞)")
+        } else {
+            let secs = self.duration.as_secs().max(0);
+            let (hours, mins, secs) = (secs / 3600, secs / 60 % 60, secs % 60);
+            if hours > 0 {
+                write!(f, " ({}:{:02}:{:02})", hours, mins, secs)
+            } else {
+                write!(f, " ({}:{:02})", mins, secs)
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! This module contains types for [enchantments](https://minecraft.fandom.com/wiki/Enchanting).
+
+use {
+    std::fmt,
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::{
+        chat::{
+            Chat,
+            NamedColor,
+        },
+        util,
+    },
+};
+
+registry_enum! {
+    /// An enchantment, identified by its namespaced ID.
+    #[allow(missing_docs)] // variants are obvious
+    pub enum Enchantment {
+        AquaAffinity => "aqua_affinity",
+        BaneOfArthropods => "bane_of_arthropods",
+        BindingCurse => "binding_curse",
+        BlastProtection => "blast_protection",
+        Breach => "breach",
+        Channeling => "channeling",
+        Density => "density",
+        DepthStrider => "depth_strider",
+        Efficiency => "efficiency",
+        FeatherFalling => "feather_falling",
+        FireAspect => "fire_aspect",
+        FireProtection => "fire_protection",
+        Flame => "flame",
+        Fortune => "fortune",
+        FrostWalker => "frost_walker",
+        Impaling => "impaling",
+        Infinity => "infinity",
+        Knockback => "knockback",
+        Looting => "looting",
+        Loyalty => "loyalty",
+        LuckOfTheSea => "luck_of_the_sea",
+        Lure => "lure",
+        Mending => "mending",
+        Multishot => "multishot",
+        Piercing => "piercing",
+        Power => "power",
+        ProjectileProtection => "projectile_protection",
+        Protection => "protection",
+        Punch => "punch",
+        QuickCharge => "quick_charge",
+        Respiration => "respiration",
+        Riptide => "riptide",
+        Sharpness => "sharpness",
+        SilkTouch => "silk_touch",
+        Smite => "smite",
+        SoulSpeed => "soul_speed",
+        SweepingEdge => "sweeping_edge" | "sweeping",
+        SwiftSneak => "swift_sneak",
+        Thorns => "thorns",
+        Unbreaking => "unbreaking",
+        VanishingCurse => "vanishing_curse",
+        WindBurst => "wind_burst",
+    }
+}
+
+/// Groups of enchantments which can't be combined on the same item.
+const EXCLUSIVE_SETS: &[&[Enchantment]] = &[
+    &[Enchantment::Protection, Enchantment::BlastProtection, Enchantment::FireProtection, Enchantment::ProjectileProtection],
+    &[Enchantment::Sharpness, Enchantment::Smite, Enchantment::BaneOfArthropods, Enchantment::Density, Enchantment::Breach],
+    &[Enchantment::Infinity, Enchantment::Mending],
+    &[Enchantment::Fortune, Enchantment::SilkTouch],
+    &[Enchantment::DepthStrider, Enchantment::FrostWalker],
+    &[Enchantment::Riptide, Enchantment::Loyalty],
+    &[Enchantment::Riptide, Enchantment::Channeling],
+    &[Enchantment::Multishot, Enchantment::Piercing],
+];
+
+impl Enchantment {
+    /// The highest level obtainable in survival, or `None` for [`Enchantment::Other`].
+    pub fn max_level(&self) -> Option<u32> {
+        match self {
+            Enchantment::AquaAffinity | Enchantment::BindingCurse | Enchantment::Channeling | Enchantment::Flame | Enchantment::Infinity | Enchantment::Mending | Enchantment::Multishot | Enchantment::SilkTouch | Enchantment::VanishingCurse => Some(1),
+            Enchantment::FireAspect | Enchantment::FrostWalker | Enchantment::Knockback | Enchantment::Punch => Some(2),
+            Enchantment::DepthStrider | Enchantment::Fortune | Enchantment::Looting | Enchantment::Loyalty | Enchantment::LuckOfTheSea | Enchantment::Lure | Enchantment::QuickCharge | Enchantment::Respiration | Enchantment::Riptide | Enchantment::SoulSpeed | Enchantment::SweepingEdge | Enchantment::SwiftSneak | Enchantment::Thorns | Enchantment::Unbreaking | Enchantment::WindBurst => Some(3),
+            Enchantment::BlastProtection | Enchantment::Breach | Enchantment::FeatherFalling | Enchantment::FireProtection | Enchantment::Piercing | Enchantment::ProjectileProtection | Enchantment::Protection => Some(4),
+            Enchantment::BaneOfArthropods | Enchantment::Density | Enchantment::Efficiency | Enchantment::Impaling | Enchantment::Power | Enchantment::Sharpness | Enchantment::Smite => Some(5),
+            Enchantment::Other(_) => None,
+        }
+    }
+
+    /// The translation key for the name of this enchantment, e.g. `enchantment.minecraft.sharpness`.
+    pub fn translation_key(&self) -> String {
+        let id = self.id();
+        format!("enchantment.{}.{}", id.namespace(), id.path().replace('/', "."))
+    }
+
+    /// The name of this enchantment in American English, e.g. `Bane of Arthropods`.
+    ///
+    /// For [`Enchantment::Other`], this is guessed from the ID.
+    pub fn english_name(&self) -> String {
+        match self {
+            Enchantment::BindingCurse => "Curse of Binding".to_owned(),
+            Enchantment::VanishingCurse => "Curse of Vanishing".to_owned(),
+            _ => util::title_case(self.id().path()),
+        }
+    }
+
+    /// Whether this is a curse, which can't be removed with a grindstone and is shown in red.
+    pub fn is_curse(&self) -> bool {
+        matches!(self, Enchantment::BindingCurse | Enchantment::VanishingCurse)
+    }
+
+    /// Whether this enchantment can't be combined with `other` on the same item, e.g. Sharpness and Smite.
+    ///
+    /// An enchantment doesn't conflict with itself, since applying it again just changes the level. Conflicts involving [`Enchantment::Other`] are not known.
+    pub fn conflicts_with(&self, other: &Enchantment) -> bool {
+        self != other && EXCLUSIVE_SETS.iter().any(|set| set.contains(self) && set.contains(other))
+    }
+}
+
+/// An enchantment with a level, as applied to an item.
+///
+/// Serializes in the `{id, lvl}` form used in NBT before 1.20.5. Use [`levels`] for the map form used in the `enchantments` item component.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct EnchantmentInstance {
+    /// Which enchantment this is.
+    #[serde(rename = "id")]
+    pub enchantment: Enchantment,
+    /// The level, starting at 1.
+    #[serde(rename = "lvl")]
+    pub level: u32,
+}
+
+impl EnchantmentInstance {
+    /// The tooltip line for this enchantment as shown on items, e.g. `Sharpness V`.
+    ///
+    /// Like in vanilla, the line is gray (red for curses) and not italic, and the level is omitted for enchantments which only have one level. Levels above 10 are shown as numbers.
+    pub fn to_chat(&self) -> Chat {
+        let mut chat = Chat::from(self.to_string());
+        chat.color(if self.enchantment.is_curse() { NamedColor::Red } else { NamedColor::Gray }).no_italic();
+        chat
+    }
+}
+
+impl fmt::Display for EnchantmentInstance {
+    /// Formats like the tooltip line, e.g. `Sharpness V`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.enchantment.english_name())?;
+        if self.level != 1 || self.enchantment.max_level() != Some(1) {
+            match util::roman_numeral(self.level) {
+                Some(numeral) => write!(f, " {}", numeral)?,
+                None => write!(f, " {}", self.level)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Serde helpers for lists of enchantments stored as a map from enchantment ID to level, as in the `levels` field of the `enchantments` item component since 1.20.5.
+///
+/// Use with `#[serde(with = "minecraft::enchantment::levels")]` on a `Vec<EnchantmentInstance>`. Deserialization also accepts the legacy list of `{id, lvl}` objects.
+pub mod levels {
+    use {
+        std::collections::BTreeMap,
+        serde::{
+            Deserialize,
+            Deserializer,
+            Serializer,
+        },
+        super::{
+            Enchantment,
+            EnchantmentInstance,
+        },
+    };
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AnyRepr {
+        Map(BTreeMap<Enchantment, u32>),
+        List(Vec<EnchantmentInstance>),
+    }
+
+    /// Serializes the enchantments as a map from ID to level.
+    pub fn serialize<S: Serializer>(enchantments: &[EnchantmentInstance], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(enchantments.iter().map(|EnchantmentInstance { enchantment, level }| (enchantment, level)))
+    }
+
+    /// Deserializes the enchantments from a map from ID to level or a list of `{id, lvl}` objects.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<EnchantmentInstance>, D::Error> {
+        Ok(match AnyRepr::deserialize(deserializer)? {
+            AnyRepr::Map(map) => map.into_iter().map(|(enchantment, level)| EnchantmentInstance { enchantment, level }).collect(),
+            AnyRepr::List(list) => list,
+        })
+    }
+}
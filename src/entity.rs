@@ -0,0 +1,243 @@
+//! This module contains types for [entities](https://minecraft.fandom.com/wiki/Entity).
+
+registry_enum! {
+    /// An entity type, identified by its namespaced ID.
+    ///
+    /// Contains all entity types as of 1.21. Some IDs used before 1.13, like `zombie_pigman`, are accepted when parsing.
+    #[non_exhaustive]
+    #[allow(missing_docs)] // variants are obvious
+    pub enum EntityKind {
+        Allay => "allay",
+        AreaEffectCloud => "area_effect_cloud",
+        Armadillo => "armadillo",
+        ArmorStand => "armor_stand",
+        Arrow => "arrow",
+        Axolotl => "axolotl",
+        Bat => "bat",
+        Bee => "bee",
+        Blaze => "blaze",
+        BlockDisplay => "block_display",
+        Boat => "boat",
+        Bogged => "bogged",
+        Breeze => "breeze",
+        BreezeWindCharge => "breeze_wind_charge",
+        Camel => "camel",
+        Cat => "cat",
+        CaveSpider => "cave_spider",
+        ChestBoat => "chest_boat",
+        ChestMinecart => "chest_minecart",
+        Chicken => "chicken",
+        Cod => "cod",
+        CommandBlockMinecart => "command_block_minecart",
+        Cow => "cow",
+        Creeper => "creeper",
+        Dolphin => "dolphin",
+        Donkey => "donkey",
+        DragonFireball => "dragon_fireball",
+        Drowned => "drowned",
+        Egg => "egg",
+        ElderGuardian => "elder_guardian",
+        EndCrystal => "end_crystal",
+        EnderDragon => "ender_dragon",
+        EnderPearl => "ender_pearl",
+        Enderman => "enderman",
+        Endermite => "endermite",
+        Evoker => "evoker",
+        EvokerFangs => "evoker_fangs",
+        ExperienceBottle => "experience_bottle" | "xp_bottle",
+        ExperienceOrb => "experience_orb" | "xp_orb",
+        EyeOfEnder => "eye_of_ender" | "eye_of_ender_signal",
+        FallingBlock => "falling_block",
+        Fireball => "fireball",
+        FireworkRocket => "firework_rocket" | "fireworks_rocket",
+        FishingBobber => "fishing_bobber",
+        Fox => "fox",
+        Frog => "frog",
+        FurnaceMinecart => "furnace_minecart",
+        Ghast => "ghast",
+        Giant => "giant",
+        GlowItemFrame => "glow_item_frame",
+        GlowSquid => "glow_squid",
+        Goat => "goat",
+        Guardian => "guardian",
+        Hoglin => "hoglin",
+        HopperMinecart => "hopper_minecart",
+        Horse => "horse",
+        Husk => "husk",
+        Illusioner => "illusioner",
+        Interaction => "interaction",
+        IronGolem => "iron_golem",
+        Item => "item",
+        ItemDisplay => "item_display",
+        ItemFrame => "item_frame",
+        LeashKnot => "leash_knot",
+        LightningBolt => "lightning_bolt",
+        Llama => "llama",
+        LlamaSpit => "llama_spit",
+        MagmaCube => "magma_cube",
+        Marker => "marker",
+        Minecart => "minecart",
+        Mooshroom => "mooshroom",
+        Mule => "mule",
+        Ocelot => "ocelot",
+        OminousItemSpawner => "ominous_item_spawner",
+        Painting => "painting",
+        Panda => "panda",
+        Parrot => "parrot",
+        Phantom => "phantom",
+        Pig => "pig",
+        Piglin => "piglin",
+        PiglinBrute => "piglin_brute",
+        Pillager => "pillager",
+        Player => "player",
+        PolarBear => "polar_bear",
+        Potion => "potion",
+        Pufferfish => "pufferfish",
+        Rabbit => "rabbit",
+        Ravager => "ravager",
+        Salmon => "salmon",
+        Sheep => "sheep",
+        Shulker => "shulker",
+        ShulkerBullet => "shulker_bullet",
+        Silverfish => "silverfish",
+        Skeleton => "skeleton",
+        SkeletonHorse => "skeleton_horse",
+        Slime => "slime",
+        SmallFireball => "small_fireball",
+        Sniffer => "sniffer",
+        SnowGolem => "snow_golem" | "snowman",
+        Snowball => "snowball",
+        SpawnerMinecart => "spawner_minecart",
+        SpectralArrow => "spectral_arrow",
+        Spider => "spider",
+        Squid => "squid",
+        Stray => "stray",
+        Strider => "strider",
+        Tadpole => "tadpole",
+        TextDisplay => "text_display",
+        Tnt => "tnt",
+        TntMinecart => "tnt_minecart",
+        TraderLlama => "trader_llama",
+        Trident => "trident",
+        TropicalFish => "tropical_fish",
+        Turtle => "turtle",
+        Vex => "vex",
+        Villager => "villager",
+        Vindicator => "vindicator",
+        WanderingTrader => "wandering_trader",
+        Warden => "warden",
+        WindCharge => "wind_charge",
+        Witch => "witch",
+        Wither => "wither",
+        WitherSkeleton => "wither_skeleton",
+        WitherSkull => "wither_skull",
+        Wolf => "wolf",
+        Zoglin => "zoglin",
+        Zombie => "zombie",
+        ZombieHorse => "zombie_horse",
+        ZombieVillager => "zombie_villager",
+        ZombifiedPiglin => "zombified_piglin" | "zombie_pigman",
+    }
+}
+
+impl EntityKind {
+    /// Whether entities of this type are living entities, which have health and can have status effects. This includes players and armor stands.
+    ///
+    /// Returns `None` for [`EntityKind::Other`].
+    pub fn is_living(&self) -> Option<bool> {
+        match self {
+            EntityKind::Other(_) => None,
+            EntityKind::AreaEffectCloud
+            | EntityKind::Arrow
+            | EntityKind::BlockDisplay
+            | EntityKind::Boat
+            | EntityKind::BreezeWindCharge
+            | EntityKind::ChestBoat
+            | EntityKind::ChestMinecart
+            | EntityKind::CommandBlockMinecart
+            | EntityKind::DragonFireball
+            | EntityKind::Egg
+            | EntityKind::EndCrystal
+            | EntityKind::EnderPearl
+            | EntityKind::EvokerFangs
+            | EntityKind::ExperienceBottle
+            | EntityKind::ExperienceOrb
+            | EntityKind::EyeOfEnder
+            | EntityKind::FallingBlock
+            | EntityKind::Fireball
+            | EntityKind::FireworkRocket
+            | EntityKind::FishingBobber
+            | EntityKind::FurnaceMinecart
+            | EntityKind::GlowItemFrame
+            | EntityKind::HopperMinecart
+            | EntityKind::Interaction
+            | EntityKind::Item
+            | EntityKind::ItemDisplay
+            | EntityKind::ItemFrame
+            | EntityKind::LeashKnot
+            | EntityKind::LightningBolt
+            | EntityKind::LlamaSpit
+            | EntityKind::Marker
+            | EntityKind::Minecart
+            | EntityKind::OminousItemSpawner
+            | EntityKind::Painting
+            | EntityKind::Potion
+            | EntityKind::ShulkerBullet
+            | EntityKind::SmallFireball
+            | EntityKind::Snowball
+            | EntityKind::SpawnerMinecart
+            | EntityKind::SpectralArrow
+            | EntityKind::TextDisplay
+            | EntityKind::Tnt
+            | EntityKind::TntMinecart
+            | EntityKind::Trident
+            | EntityKind::WindCharge
+            | EntityKind::WitherSkull => Some(false),
+            _ => Some(true),
+        }
+    }
+
+    /// Whether entities of this type are always hostile to players. Neutral mobs like spiders and endermen are not included.
+    ///
+    /// Returns `None` for [`EntityKind::Other`].
+    pub fn is_hostile(&self) -> Option<bool> {
+        match self {
+            EntityKind::Other(_) => None,
+            EntityKind::Blaze
+            | EntityKind::Bogged
+            | EntityKind::Breeze
+            | EntityKind::Creeper
+            | EntityKind::Drowned
+            | EntityKind::ElderGuardian
+            | EntityKind::EnderDragon
+            | EntityKind::Endermite
+            | EntityKind::Evoker
+            | EntityKind::Ghast
+            | EntityKind::Giant
+            | EntityKind::Guardian
+            | EntityKind::Hoglin
+            | EntityKind::Husk
+            | EntityKind::Illusioner
+            | EntityKind::MagmaCube
+            | EntityKind::Phantom
+            | EntityKind::PiglinBrute
+            | EntityKind::Pillager
+            | EntityKind::Ravager
+            | EntityKind::Shulker
+            | EntityKind::Silverfish
+            | EntityKind::Skeleton
+            | EntityKind::Slime
+            | EntityKind::Stray
+            | EntityKind::Vex
+            | EntityKind::Vindicator
+            | EntityKind::Warden
+            | EntityKind::Witch
+            | EntityKind::Wither
+            | EntityKind::WitherSkeleton
+            | EntityKind::Zoglin
+            | EntityKind::Zombie
+            | EntityKind::ZombieVillager => Some(true),
+            _ => Some(false),
+        }
+    }
+}
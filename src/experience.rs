@@ -0,0 +1,151 @@
+//! This module contains types and formulas for [experience](https://minecraft.fandom.com/wiki/Experience).
+
+use {
+    std::fmt,
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+};
+
+/// An experience level, as displayed above the hotbar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct ExperienceLevel(pub u32);
+
+impl ExperienceLevel {
+    /// The total number of experience points needed to reach this level from zero.
+    pub fn total_points(&self) -> u64 {
+        points_for_level(self.0)
+    }
+
+    /// The number of experience points needed to go from this level to the next.
+    pub fn points_to_next(&self) -> u32 {
+        points_to_next_level(self.0)
+    }
+}
+
+impl fmt::Display for ExperienceLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+fn points_for_level_wide(level: u32) -> u128 {
+    let level = u128::from(level);
+    match level {
+        0..=16 => level * level + 6 * level,
+        17..=31 => (5 * level * level + 720 - 81 * level) / 2,
+        _ => (9 * level * level + 4440 - 325 * level) / 2,
+    }
+}
+
+/// The total number of experience points needed to reach the given level from zero.
+///
+/// Saturates at `u64::MAX` for absurdly high levels.
+///
+/// # Example
+///
+/// The formula changes at levels 16 and 31:
+///
+/// ```
+/// use minecraft::experience::*;
+///
+/// assert_eq!(points_for_level(16), 352);
+/// assert_eq!(points_for_level(17), 394);
+/// assert_eq!(points_for_level(31), 1507);
+/// assert_eq!(points_for_level(32), 1628);
+/// assert_eq!(points_to_next_level(15), 37);
+/// assert_eq!(points_to_next_level(16), 42);
+/// assert_eq!(points_to_next_level(30), 112);
+/// assert_eq!(points_to_next_level(31), 121);
+/// for level in 0..100 {
+///     assert_eq!(points_for_level(level) + u64::from(points_to_next_level(level)), points_for_level(level + 1));
+/// }
+/// ```
+pub fn points_for_level(level: u32) -> u64 {
+    let points = points_for_level_wide(level);
+    if points > u128::from(u64::MAX) { u64::MAX } else { points as u64 }
+}
+
+/// The number of experience points needed to go from the given level to the next.
+pub fn points_to_next_level(level: u32) -> u32 {
+    match level {
+        0..=15 => 2 * level + 7,
+        16..=30 => 5 * level - 38,
+        _ => level.saturating_mul(9) - 158,
+    }
+}
+
+/// The level reached with the given total number of experience points, and the progress towards the next level as a fraction in `0.0..1.0`.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::experience::level_for_points;
+///
+/// assert_eq!(level_for_points(352), (16, 0.0));
+/// assert_eq!(level_for_points(373), (16, 0.5));
+/// assert_eq!(level_for_points(394), (17, 0.0));
+/// assert_eq!(level_for_points(1506).0, 30);
+/// assert_eq!(level_for_points(1507), (31, 0.0));
+/// assert_eq!(level_for_points(1628), (32, 0.0));
+/// ```
+pub fn level_for_points(points: u64) -> (u32, f32) {
+    let points = u128::from(points);
+    // binary search for the highest level whose total is at most `points`
+    let (mut low, mut high) = (0, u32::MAX);
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        if points_for_level_wide(mid) <= points { low = mid } else { high = mid - 1 }
+    }
+    let remainder = points - points_for_level_wide(low);
+    (low, remainder as f32 / points_to_next_level(low) as f32)
+}
+
+/// A player's experience, as stored in player data.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize, Serialize)]
+pub struct PlayerExperience {
+    /// The current level.
+    #[serde(rename = "XpLevel")]
+    pub level: u32,
+    /// The progress towards the next level, as a fraction in `0.0..1.0`.
+    #[serde(rename = "XpP")]
+    pub progress: f32,
+    /// The total number of experience points, shown as the score on the death screen.
+    #[serde(rename = "XpTotal")]
+    pub total: u32,
+}
+
+impl PlayerExperience {
+    /// The experience of a player who has collected the given number of points.
+    pub fn from_total(total: u32) -> PlayerExperience {
+        let (level, progress) = level_for_points(total.into());
+        PlayerExperience { level, progress, total }
+    }
+
+    /// Makes the fields consistent with each other, treating `level` and `progress` as authoritative since those are what the game uses.
+    ///
+    /// `progress` is clamped to `0.0..1.0` and `total` is recomputed from the other two fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::experience::PlayerExperience;
+    ///
+    /// let mut xp = PlayerExperience { level: 16, progress: 0.5, total: 0 };
+    /// xp.normalize();
+    /// assert_eq!(xp, PlayerExperience { level: 16, progress: 0.5, total: 373 });
+    /// assert_eq!(PlayerExperience::from_total(373), xp);
+    ///
+    /// let mut xp = PlayerExperience { level: 31, progress: -1.0, total: 5 };
+    /// xp.normalize();
+    /// assert_eq!(xp, PlayerExperience { level: 31, progress: 0.0, total: 1507 });
+    /// ```
+    pub fn normalize(&mut self) {
+        if self.progress.is_nan() || self.progress < 0.0 { self.progress = 0.0 }
+        if self.progress >= 1.0 { self.progress = 1.0 - f32::EPSILON }
+        let points = points_for_level(self.level) + (self.progress * points_to_next_level(self.level) as f32) as u64;
+        self.total = if points > u64::from(u32::MAX) { u32::MAX } else { points as u32 };
+    }
+}
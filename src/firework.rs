@@ -0,0 +1,83 @@
+//! This module contains types for [firework](https://minecraft.fandom.com/wiki/Firework_Rocket) explosions.
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// The shape of a firework explosion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)] // variants are obvious
+pub enum Shape {
+    #[default]
+    SmallBall,
+    LargeBall,
+    Star,
+    Creeper,
+    Burst,
+}
+
+impl Shape {
+    /// The numeric ID used in the `Type` tag before 1.20.5.
+    pub fn legacy_id(&self) -> u8 {
+        match self {
+            Shape::SmallBall => 0,
+            Shape::LargeBall => 1,
+            Shape::Star => 2,
+            Shape::Creeper => 3,
+            Shape::Burst => 4,
+        }
+    }
+
+    /// Looks up a shape by the numeric ID used before 1.20.5.
+    pub fn from_legacy_id(id: u8) -> Option<Shape> {
+        match id {
+            0 => Some(Shape::SmallBall),
+            1 => Some(Shape::LargeBall),
+            2 => Some(Shape::Star),
+            3 => Some(Shape::Creeper),
+            4 => Some(Shape::Burst),
+            _ => None,
+        }
+    }
+}
+
+/// A firework explosion, as stored in the `firework_explosion` item component and in the `explosions` list of the `fireworks` component.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct FireworkExplosion {
+    /// The shape of the explosion.
+    pub shape: Shape,
+    /// The colors of the particles. See [`DyeColor::firework_rgb`](crate::dye_color::DyeColor::firework_rgb) for the colors available in survival.
+    #[serde(default, with = "rgb_list", skip_serializing_if = "Vec::is_empty")]
+    pub colors: Vec<[u8; 3]>,
+    /// The colors the particles fade to.
+    #[serde(default, with = "rgb_list", skip_serializing_if = "Vec::is_empty")]
+    pub fade_colors: Vec<[u8; 3]>,
+    /// Whether the particles twinkle, as added by glowstone dust.
+    #[serde(rename = "has_twinkle", default, skip_serializing_if = "std::ops::Not::not")]
+    pub flicker: bool,
+    /// Whether the particles leave a trail, as added by a diamond.
+    #[serde(rename = "has_trail", default, skip_serializing_if = "std::ops::Not::not")]
+    pub trail: bool,
+}
+
+/// (De)serializes RGB colors as a list of integers of the form `0xRRGGBB`.
+mod rgb_list {
+    use serde::{
+        Deserialize as _,
+        Deserializer,
+        Serializer,
+    };
+
+    pub(super) fn serialize<S: Serializer>(colors: &[[u8; 3]], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(colors.iter().map(|&[r, g, b]| i32::from_be_bytes([0, r, g, b])))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<[u8; 3]>, D::Error> {
+        Ok(Vec::<i32>::deserialize(deserializer)?.into_iter().map(|color| {
+            let [_, r, g, b] = color.to_be_bytes();
+            [r, g, b]
+        }).collect())
+    }
+}
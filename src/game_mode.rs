@@ -0,0 +1,193 @@
+//! This module contains the [`GameMode`] type, representing the [game modes](https://minecraft.fandom.com/wiki/Game_mode).
+
+use {
+    std::{
+        convert::TryFrom,
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::{
+            self,
+            Visitor,
+        },
+    },
+};
+
+/// One of the four game modes.
+///
+/// Hardcore is not a game mode but a separate flag, see [`GameMode::from_legacy_packet`].
+///
+/// Serializes as the lowercase name. Deserializes from the name, an abbreviation, or the numeric ID (as an integer or a float, since old packets used floats).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)] // variants are obvious
+pub enum GameMode {
+    Survival,
+    Creative,
+    Adventure,
+    Spectator,
+}
+
+impl GameMode {
+    /// All game modes, in ID order.
+    pub const ALL: [GameMode; 4] = [GameMode::Survival, GameMode::Creative, GameMode::Adventure, GameMode::Spectator];
+
+    /// The numeric ID used in NBT (`playerGameType`) and packets.
+    pub fn id(&self) -> i32 {
+        match self {
+            GameMode::Survival => 0,
+            GameMode::Creative => 1,
+            GameMode::Adventure => 2,
+            GameMode::Spectator => 3,
+        }
+    }
+
+    /// Looks up a game mode by its numeric ID.
+    ///
+    /// Returns `None` for unknown IDs, including the `-1` used for “undefined”. Use [`option_id`] to handle that case in serde.
+    pub fn from_id(id: i32) -> Option<GameMode> {
+        match id {
+            0 => Some(GameMode::Survival),
+            1 => Some(GameMode::Creative),
+            2 => Some(GameMode::Adventure),
+            3 => Some(GameMode::Spectator),
+            _ => None,
+        }
+    }
+
+    /// Decodes the game mode byte from pre-1.16 packets, where bit 3 was the hardcore flag and `-1` meant undefined.
+    ///
+    /// Returns the game mode (if defined and known) and whether the hardcore bit was set.
+    pub fn from_legacy_packet(value: i32) -> (Option<GameMode>, bool) {
+        if value == -1 { return (None, false) }
+        (GameMode::from_id(value & !0x8), value & 0x8 != 0)
+    }
+
+    /// The lowercase name used in commands and JSON, e.g. `survival`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameMode::Survival => "survival",
+            GameMode::Creative => "creative",
+            GameMode::Adventure => "adventure",
+            GameMode::Spectator => "spectator",
+        }
+    }
+
+    /// The short form accepted by older versions of the `/gamemode` command, e.g. `s`.
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            GameMode::Survival => "s",
+            GameMode::Creative => "c",
+            GameMode::Adventure => "a",
+            GameMode::Spectator => "sp",
+        }
+    }
+
+    /// Whether players in this game mode can take damage and interact with the world like in survival, i.e. survival or adventure mode.
+    pub fn is_survival_like(&self) -> bool {
+        matches!(self, GameMode::Survival | GameMode::Adventure)
+    }
+}
+
+/// The error returned when parsing a [`GameMode`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameModeParseError(pub String);
+
+impl fmt::Display for GameModeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown game mode: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for GameModeParseError {}
+
+impl FromStr for GameMode {
+    type Err = GameModeParseError;
+
+    /// Parses a game mode from its name, its abbreviation, or its numeric ID.
+    fn from_str(s: &str) -> Result<GameMode, GameModeParseError> {
+        GameMode::ALL.iter()
+            .find(|mode| s == mode.name() || s == mode.abbreviation())
+            .copied()
+            .or_else(|| s.parse().ok().and_then(GameMode::from_id))
+            .ok_or_else(|| GameModeParseError(s.to_owned()))
+    }
+}
+
+impl fmt::Display for GameMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Serialize for GameMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+struct GameModeVisitor {
+    allow_undefined: bool,
+}
+
+impl<'de> Visitor<'de> for GameModeVisitor {
+    type Value = Option<GameMode>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a game mode name or numeric ID")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Option<GameMode>, E> {
+        v.parse().map(Some).map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Option<GameMode>, E> {
+        if self.allow_undefined && v == -1 { return Ok(None) }
+        i32::try_from(v).ok().and_then(GameMode::from_id).map(Some).ok_or_else(|| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Option<GameMode>, E> {
+        i32::try_from(v).ok().and_then(GameMode::from_id).map(Some).ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Option<GameMode>, E> {
+        if v.fract() != 0.0 { return Err(E::invalid_value(de::Unexpected::Float(v), &self)) }
+        self.visit_i64(v as i64)
+    }
+}
+
+impl<'de> Deserialize<'de> for GameMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<GameMode, D::Error> {
+        deserializer.deserialize_any(GameModeVisitor { allow_undefined: false })?.ok_or_else(|| de::Error::custom("undefined game mode"))
+    }
+}
+
+/// Serde helpers for `Option<GameMode>` fields stored as numeric IDs with `-1` meaning `None`, such as `previousPlayerGameType` in player data.
+///
+/// Use with `#[serde(with = "minecraft::game_mode::option_id")]`.
+pub mod option_id {
+    use {
+        serde::{
+            Deserializer,
+            Serializer,
+        },
+        super::{
+            GameMode,
+            GameModeVisitor,
+        },
+    };
+
+    /// Serializes `None` as `-1` and `Some` as the numeric ID.
+    pub fn serialize<S: Serializer>(mode: &Option<GameMode>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(mode.map_or(-1, |mode| mode.id()))
+    }
+
+    /// Deserializes `-1` as `None`, and any other game mode representation as `Some`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<GameMode>, D::Error> {
+        deserializer.deserialize_any(GameModeVisitor { allow_undefined: true })
+    }
+}
@@ -0,0 +1,314 @@
+//! This module contains types for [game rules](https://minecraft.fandom.com/wiki/Game_rule).
+
+use {
+    std::{
+        collections::BTreeMap,
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::{
+            self,
+            MapAccess,
+            Visitor,
+        },
+        ser::SerializeMap as _,
+    },
+};
+
+/// The value of a game rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameRuleValue {
+    /// A boolean game rule's value.
+    Bool(bool),
+    /// An integer game rule's value.
+    Int(i32),
+}
+
+impl fmt::Display for GameRuleValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameRuleValue::Bool(value) => value.fmt(f),
+            GameRuleValue::Int(value) => value.fmt(f),
+        }
+    }
+}
+
+/// An error that can occur when parsing game rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameRuleError {
+    /// The name is not a known vanilla game rule.
+    UnknownKey(String),
+    /// The value has the wrong type for the game rule.
+    InvalidValue {
+        /// The game rule whose value was invalid.
+        key: GameRuleKey,
+        /// The invalid value.
+        value: String,
+    },
+}
+
+impl fmt::Display for GameRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameRuleError::UnknownKey(key) => write!(f, "unknown game rule: {:?}", key),
+            GameRuleError::InvalidValue { key, value } => write!(f, "invalid value for game rule {}: {:?}", key, value),
+        }
+    }
+}
+
+impl std::error::Error for GameRuleError {}
+
+trait GameRuleType: Copy {
+    fn parse(s: &str) -> Option<Self>;
+    fn into_value(self) -> GameRuleValue;
+    fn from_value(value: GameRuleValue) -> Option<Self>;
+}
+
+impl GameRuleType for bool {
+    fn parse(s: &str) -> Option<bool> { s.parse().ok() }
+    fn into_value(self) -> GameRuleValue { GameRuleValue::Bool(self) }
+
+    fn from_value(value: GameRuleValue) -> Option<bool> {
+        if let GameRuleValue::Bool(value) = value { Some(value) } else { None }
+    }
+}
+
+impl GameRuleType for i32 {
+    fn parse(s: &str) -> Option<i32> { s.parse().ok() }
+    fn into_value(self) -> GameRuleValue { GameRuleValue::Int(self) }
+
+    fn from_value(value: GameRuleValue) -> Option<i32> {
+        if let GameRuleValue::Int(value) = value { Some(value) } else { None }
+    }
+}
+
+macro_rules! game_rules {
+    ($($variant:ident => $field:ident / $setter:ident: $ty:ty = $default:literal, $name:literal;)*) => {
+        /// The name of a vanilla game rule.
+        ///
+        /// [`Display`](fmt::Display) and [`FromStr`] use the exact camelCase names used in commands and `level.dat`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[allow(missing_docs)] // variants are obvious
+        pub enum GameRuleKey {
+            $($variant,)*
+        }
+
+        impl GameRuleKey {
+            /// All vanilla game rules, in alphabetical order.
+            pub const ALL: &'static [GameRuleKey] = &[$(GameRuleKey::$variant,)*];
+
+            /// The camelCase name of this game rule, e.g. `randomTickSpeed`.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(GameRuleKey::$variant => $name,)*
+                }
+            }
+
+            /// The value this game rule has in a newly created world.
+            pub fn default_value(&self) -> GameRuleValue {
+                match self {
+                    $(GameRuleKey::$variant => GameRuleType::into_value($default as $ty),)*
+                }
+            }
+
+            /// Parses a value for this game rule from its string form.
+            pub fn parse_value(&self, value: &str) -> Result<GameRuleValue, GameRuleError> {
+                match self {
+                    $(GameRuleKey::$variant => <$ty as GameRuleType>::parse(value).map(GameRuleType::into_value),)*
+                }.ok_or_else(|| GameRuleError::InvalidValue { key: *self, value: value.to_owned() })
+            }
+        }
+
+        impl FromStr for GameRuleKey {
+            type Err = GameRuleError;
+
+            fn from_str(s: &str) -> Result<GameRuleKey, GameRuleError> {
+                match s {
+                    $($name => Ok(GameRuleKey::$variant),)*
+                    _ => Err(GameRuleError::UnknownKey(s.to_owned())),
+                }
+            }
+        }
+
+        /// The game rules of a world.
+        ///
+        /// Serializes as a map from game rule names to string values, as in `level.dat`. Rules missing when deserializing get their default values.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct GameRules {
+            $($field: $ty,)*
+            /// Game rules not known to this crate, e.g. from mods or newer versions.
+            pub other: BTreeMap<String, String>,
+        }
+
+        impl GameRules {
+            $(
+                #[doc = concat!("The value of the `", $name, "` game rule.")]
+                pub fn $field(&self) -> $ty { self.$field }
+
+                #[doc = concat!("Changes the value of the `", $name, "` game rule.")]
+                pub fn $setter(&mut self, value: $ty) -> &mut GameRules {
+                    self.$field = value;
+                    self
+                }
+            )*
+
+            /// The value of the given game rule.
+            pub fn get(&self, key: GameRuleKey) -> GameRuleValue {
+                match key {
+                    $(GameRuleKey::$variant => GameRuleType::into_value(self.$field),)*
+                }
+            }
+
+            /// Changes the value of the given game rule.
+            ///
+            /// Fails if the value has the wrong type.
+            pub fn set(&mut self, key: GameRuleKey, value: GameRuleValue) -> Result<&mut GameRules, GameRuleError> {
+                match key {
+                    $(GameRuleKey::$variant => self.$field = GameRuleType::from_value(value).ok_or_else(|| GameRuleError::InvalidValue { key, value: value.to_string() })?,)*
+                }
+                Ok(self)
+            }
+        }
+
+        impl Default for GameRules {
+            fn default() -> GameRules {
+                GameRules {
+                    $($field: $default,)*
+                    other: BTreeMap::default(),
+                }
+            }
+        }
+    };
+}
+
+game_rules! {
+    AnnounceAdvancements => announce_advancements / set_announce_advancements: bool = true, "announceAdvancements";
+    BlockExplosionDropDecay => block_explosion_drop_decay / set_block_explosion_drop_decay: bool = true, "blockExplosionDropDecay";
+    CommandBlockOutput => command_block_output / set_command_block_output: bool = true, "commandBlockOutput";
+    CommandModificationBlockLimit => command_modification_block_limit / set_command_modification_block_limit: i32 = 32768, "commandModificationBlockLimit";
+    DisableElytraMovementCheck => disable_elytra_movement_check / set_disable_elytra_movement_check: bool = false, "disableElytraMovementCheck";
+    DisablePlayerMovementCheck => disable_player_movement_check / set_disable_player_movement_check: bool = false, "disablePlayerMovementCheck";
+    DisableRaids => disable_raids / set_disable_raids: bool = false, "disableRaids";
+    DoDaylightCycle => do_daylight_cycle / set_do_daylight_cycle: bool = true, "doDaylightCycle";
+    DoEntityDrops => do_entity_drops / set_do_entity_drops: bool = true, "doEntityDrops";
+    DoFireTick => do_fire_tick / set_do_fire_tick: bool = true, "doFireTick";
+    DoImmediateRespawn => do_immediate_respawn / set_do_immediate_respawn: bool = false, "doImmediateRespawn";
+    DoInsomnia => do_insomnia / set_do_insomnia: bool = true, "doInsomnia";
+    DoLimitedCrafting => do_limited_crafting / set_do_limited_crafting: bool = false, "doLimitedCrafting";
+    DoMobLoot => do_mob_loot / set_do_mob_loot: bool = true, "doMobLoot";
+    DoMobSpawning => do_mob_spawning / set_do_mob_spawning: bool = true, "doMobSpawning";
+    DoPatrolSpawning => do_patrol_spawning / set_do_patrol_spawning: bool = true, "doPatrolSpawning";
+    DoTileDrops => do_tile_drops / set_do_tile_drops: bool = true, "doTileDrops";
+    DoTraderSpawning => do_trader_spawning / set_do_trader_spawning: bool = true, "doTraderSpawning";
+    DoVinesSpread => do_vines_spread / set_do_vines_spread: bool = true, "doVinesSpread";
+    DoWardenSpawning => do_warden_spawning / set_do_warden_spawning: bool = true, "doWardenSpawning";
+    DoWeatherCycle => do_weather_cycle / set_do_weather_cycle: bool = true, "doWeatherCycle";
+    DrowningDamage => drowning_damage / set_drowning_damage: bool = true, "drowningDamage";
+    EnderPearlsVanishOnDeath => ender_pearls_vanish_on_death / set_ender_pearls_vanish_on_death: bool = true, "enderPearlsVanishOnDeath";
+    FallDamage => fall_damage / set_fall_damage: bool = true, "fallDamage";
+    FireDamage => fire_damage / set_fire_damage: bool = true, "fireDamage";
+    ForgiveDeadPlayers => forgive_dead_players / set_forgive_dead_players: bool = true, "forgiveDeadPlayers";
+    FreezeDamage => freeze_damage / set_freeze_damage: bool = true, "freezeDamage";
+    GlobalSoundEvents => global_sound_events / set_global_sound_events: bool = true, "globalSoundEvents";
+    KeepInventory => keep_inventory / set_keep_inventory: bool = false, "keepInventory";
+    LavaSourceConversion => lava_source_conversion / set_lava_source_conversion: bool = false, "lavaSourceConversion";
+    LogAdminCommands => log_admin_commands / set_log_admin_commands: bool = true, "logAdminCommands";
+    MaxCommandChainLength => max_command_chain_length / set_max_command_chain_length: i32 = 65536, "maxCommandChainLength";
+    MaxCommandForkCount => max_command_fork_count / set_max_command_fork_count: i32 = 65536, "maxCommandForkCount";
+    MaxEntityCramming => max_entity_cramming / set_max_entity_cramming: i32 = 24, "maxEntityCramming";
+    MobExplosionDropDecay => mob_explosion_drop_decay / set_mob_explosion_drop_decay: bool = true, "mobExplosionDropDecay";
+    MobGriefing => mob_griefing / set_mob_griefing: bool = true, "mobGriefing";
+    NaturalRegeneration => natural_regeneration / set_natural_regeneration: bool = true, "naturalRegeneration";
+    PlayersNetherPortalCreativeDelay => players_nether_portal_creative_delay / set_players_nether_portal_creative_delay: i32 = 1, "playersNetherPortalCreativeDelay";
+    PlayersNetherPortalDefaultDelay => players_nether_portal_default_delay / set_players_nether_portal_default_delay: i32 = 80, "playersNetherPortalDefaultDelay";
+    PlayersSleepingPercentage => players_sleeping_percentage / set_players_sleeping_percentage: i32 = 100, "playersSleepingPercentage";
+    ProjectilesCanBreakBlocks => projectiles_can_break_blocks / set_projectiles_can_break_blocks: bool = true, "projectilesCanBreakBlocks";
+    RandomTickSpeed => random_tick_speed / set_random_tick_speed: i32 = 3, "randomTickSpeed";
+    ReducedDebugInfo => reduced_debug_info / set_reduced_debug_info: bool = false, "reducedDebugInfo";
+    SendCommandFeedback => send_command_feedback / set_send_command_feedback: bool = true, "sendCommandFeedback";
+    ShowDeathMessages => show_death_messages / set_show_death_messages: bool = true, "showDeathMessages";
+    SnowAccumulationHeight => snow_accumulation_height / set_snow_accumulation_height: i32 = 1, "snowAccumulationHeight";
+    SpawnChunkRadius => spawn_chunk_radius / set_spawn_chunk_radius: i32 = 2, "spawnChunkRadius";
+    SpawnRadius => spawn_radius / set_spawn_radius: i32 = 10, "spawnRadius";
+    SpectatorsGenerateChunks => spectators_generate_chunks / set_spectators_generate_chunks: bool = true, "spectatorsGenerateChunks";
+    TntExplosionDropDecay => tnt_explosion_drop_decay / set_tnt_explosion_drop_decay: bool = false, "tntExplosionDropDecay";
+    UniversalAnger => universal_anger / set_universal_anger: bool = false, "universalAnger";
+    WaterSourceConversion => water_source_conversion / set_water_source_conversion: bool = true, "waterSourceConversion";
+}
+
+impl fmt::Display for GameRuleKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Serialize for GameRules {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(GameRuleKey::ALL.len() + self.other.len()))?;
+        for key in GameRuleKey::ALL {
+            map.serialize_entry(key.name(), &self.get(*key).to_string())?;
+        }
+        for (key, value) in &self.other {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+/// A game rule value as found in the data, which should be a string but is accepted as a boolean or integer as well.
+struct RawValue(String);
+
+impl<'de> Deserialize<'de> for RawValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RawValue, D::Error> {
+        struct RawValueVisitor;
+
+        impl<'de> Visitor<'de> for RawValueVisitor {
+            type Value = RawValue;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a game rule value")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<RawValue, E> { Ok(RawValue(v.to_owned())) }
+            fn visit_bool<E: de::Error>(self, v: bool) -> Result<RawValue, E> { Ok(RawValue(v.to_string())) }
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<RawValue, E> { Ok(RawValue(v.to_string())) }
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<RawValue, E> { Ok(RawValue(v.to_string())) }
+        }
+
+        deserializer.deserialize_any(RawValueVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for GameRules {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<GameRules, D::Error> {
+        struct GameRulesVisitor;
+
+        impl<'de> Visitor<'de> for GameRulesVisitor {
+            type Value = GameRules;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a map of game rules")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<GameRules, A::Error> {
+                let mut rules = GameRules::default();
+                while let Some((name, RawValue(value))) = map.next_entry::<String, RawValue>()? {
+                    if let Ok(key) = name.parse::<GameRuleKey>() {
+                        let value = key.parse_value(&value).map_err(de::Error::custom)?;
+                        rules.set(key, value).map_err(de::Error::custom)?;
+                    } else {
+                        rules.other.insert(name, value);
+                    }
+                }
+                Ok(rules)
+            }
+        }
+
+        deserializer.deserialize_map(GameRulesVisitor)
+    }
+}
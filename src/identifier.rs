@@ -0,0 +1,202 @@
+//! This module contains the [`Identifier`] type, which represents [namespaced IDs](https://minecraft.fandom.com/wiki/Resource_location) like `minecraft:stone`.
+
+use {
+    std::{
+        borrow::Cow,
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::Error as _,
+    },
+};
+
+/// A namespaced ID, also called a resource location.
+///
+/// The string form is `namespace:path`. When parsing, the namespace may be omitted, in which case it defaults to `minecraft`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Identifier {
+    namespace: Cow<'static, str>,
+    path: Cow<'static, str>,
+}
+
+impl Identifier {
+    /// The default namespace, used when none is specified.
+    pub const DEFAULT_NAMESPACE: &'static str = "minecraft";
+
+    /// Creates an identifier in the `minecraft` namespace from a path known at compile time.
+    ///
+    /// The path is not validated.
+    pub const fn minecraft(path: &'static str) -> Identifier {
+        Identifier {
+            namespace: Cow::Borrowed(Identifier::DEFAULT_NAMESPACE),
+            path: Cow::Borrowed(path),
+        }
+    }
+
+    /// Creates an identifier from a namespace and path, validating both.
+    pub fn new(namespace: impl Into<String>, path: impl Into<String>) -> Result<Identifier, IdentifierParseError> {
+        let namespace = namespace.into();
+        let path = path.into();
+        if namespace.is_empty() { return Err(IdentifierParseError::EmptyNamespace) }
+        if let Some(c) = namespace.chars().find(|&c| !is_namespace_char(c)) { return Err(IdentifierParseError::InvalidNamespaceChar(c)) }
+        if path.is_empty() { return Err(IdentifierParseError::EmptyPath) }
+        if let Some(c) = path.chars().find(|&c| !is_path_char(c)) { return Err(IdentifierParseError::InvalidPathChar(c)) }
+        Ok(Identifier { namespace: Cow::Owned(namespace), path: Cow::Owned(path) })
+    }
+
+    /// The namespace, e.g. `minecraft`.
+    pub fn namespace(&self) -> &str { &self.namespace }
+
+    /// The path, e.g. `stone`.
+    pub fn path(&self) -> &str { &self.path }
+
+    /// Whether this identifier is in the `minecraft` namespace.
+    pub fn is_vanilla(&self) -> bool { self.namespace == Identifier::DEFAULT_NAMESPACE }
+}
+
+fn is_namespace_char(c: char) -> bool {
+    matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.')
+}
+
+fn is_path_char(c: char) -> bool {
+    is_namespace_char(c) || c == '/'
+}
+
+/// An error that can occur when parsing an [`Identifier`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdentifierParseError {
+    /// The namespace before the `:` was empty.
+    EmptyNamespace,
+    /// The path after the `:` was empty.
+    EmptyPath,
+    /// The namespace contained a character other than `a-z0-9_-.`.
+    InvalidNamespaceChar(char),
+    /// The path contained a character other than `a-z0-9_-./`.
+    InvalidPathChar(char),
+}
+
+impl fmt::Display for IdentifierParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdentifierParseError::EmptyNamespace => write!(f, "empty namespace in identifier"),
+            IdentifierParseError::EmptyPath => write!(f, "empty path in identifier"),
+            IdentifierParseError::InvalidNamespaceChar(c) => write!(f, "invalid character {:?} in identifier namespace", c),
+            IdentifierParseError::InvalidPathChar(c) => write!(f, "invalid character {:?} in identifier path", c),
+        }
+    }
+}
+
+impl std::error::Error for IdentifierParseError {}
+
+impl FromStr for Identifier {
+    type Err = IdentifierParseError;
+
+    fn from_str(s: &str) -> Result<Identifier, IdentifierParseError> {
+        if let Some((namespace, path)) = s.split_once(':') {
+            Identifier::new(namespace, path)
+        } else {
+            Identifier::new(Identifier::DEFAULT_NAMESPACE, s)
+        }
+    }
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.namespace, self.path)
+    }
+}
+
+impl Serialize for Identifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Identifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Identifier, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+}
+
+/// Defines an enum of known registry entries with an `Other(Identifier)` fallback.
+///
+/// Generates `id`, `From<Identifier>`, `From<Self> for Identifier`, `FromStr`, `Display`, and serde impls using the namespaced string form.
+/// Each entry can list former paths after its current one, separated by `|`, which are accepted when parsing.
+macro_rules! registry_enum {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_attr:meta])* $variant:ident => $path:literal $(| $alias:literal)*,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis enum $name {
+            $($(#[$variant_attr])* $variant,)*
+            /// An entry not known to this crate, e.g. from a mod or a newer version.
+            Other($crate::identifier::Identifier),
+        }
+
+        impl $name {
+            /// All entries known to this crate, in registry order.
+            pub const ALL: &'static [$name] = &[$($name::$variant,)*];
+
+            /// The namespaced ID of this entry.
+            pub fn id(&self) -> $crate::identifier::Identifier {
+                match self {
+                    $($name::$variant => $crate::identifier::Identifier::minecraft($path),)*
+                    $name::Other(id) => id.clone(),
+                }
+            }
+        }
+
+        impl From<$crate::identifier::Identifier> for $name {
+            fn from(id: $crate::identifier::Identifier) -> $name {
+                if id.is_vanilla() {
+                    match id.path() {
+                        $($path $(| $alias)* => return $name::$variant,)*
+                        _ => {}
+                    }
+                }
+                $name::Other(id)
+            }
+        }
+
+        impl From<$name> for $crate::identifier::Identifier {
+            fn from(entry: $name) -> $crate::identifier::Identifier {
+                entry.id()
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::identifier::IdentifierParseError;
+
+            fn from_str(s: &str) -> Result<$name, $crate::identifier::IdentifierParseError> {
+                Ok(s.parse::<$crate::identifier::Identifier>()?.into())
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                self.id().fmt(f)
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.id().serialize(serializer)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<$name, D::Error> {
+                Ok($crate::identifier::Identifier::deserialize(deserializer)?.into())
+            }
+        }
+    };
+}
@@ -0,0 +1,253 @@
+//! This module contains the [`ItemStack`] type, representing a stack of [items](https://minecraft.fandom.com/wiki/Item).
+
+use {
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        ser::SerializeStruct as _,
+    },
+    crate::{
+        chat::{
+            Chat,
+            HoverEvent,
+            deserialize_text_component,
+        },
+        identifier::Identifier,
+    },
+};
+
+/// Which format an [`ItemStack`] uses for its additional data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ItemFormat {
+    /// The format used before 1.20.5: `{id, Count, tag}`, where `tag` is an NBT compound.
+    Legacy,
+    /// The format used since 1.20.5: `{id, count, components}`, where `components` maps data component types to their values.
+    #[default]
+    Components,
+}
+
+/// A stack of items, e.g. in an inventory slot.
+///
+/// Deserializes from either [format](ItemFormat), detecting which one is used, and serializes back to the same format unless `format` is changed.
+///
+/// # Example
+///
+/// A shulker box containing other items, in both formats:
+///
+/// ```
+/// use minecraft::item::{
+///     ItemFormat,
+///     ItemStack,
+/// };
+///
+/// let legacy = serde_json::json!({
+///     "id": "minecraft:shulker_box",
+///     "Count": 1,
+///     "tag": {"BlockEntityTag": {"Items": [
+///         {"Slot": 0, "id": "minecraft:diamond", "Count": 64},
+///         {"Slot": 1, "id": "minecraft:diamond_sword", "Count": 1, "tag": {"Damage": 5, "display": {"Name": "{\"text\":\"Excalibur\"}"}}},
+///     ]}},
+/// });
+/// let stack = serde_json::from_value::<ItemStack>(legacy.clone())?;
+/// assert_eq!(stack.format, ItemFormat::Legacy);
+/// assert_eq!(serde_json::to_value(&stack)?, legacy);
+/// let items = serde_json::from_value::<Vec<ItemStack>>(stack.nbt.as_ref().unwrap()["BlockEntityTag"]["Items"].clone())?;
+/// assert_eq!(items[0].count, 64);
+/// assert_eq!(items[1].format, ItemFormat::Legacy);
+/// assert_eq!(items[1].custom_name().unwrap().text, "Excalibur");
+///
+/// let components = serde_json::json!({
+///     "id": "minecraft:shulker_box",
+///     "count": 1,
+///     "components": {"minecraft:container": [
+///         {"slot": 0, "item": {"id": "minecraft:diamond", "count": 64}},
+///         {"slot": 1, "item": {"id": "minecraft:diamond_sword", "count": 1, "components": {"minecraft:damage": 5, "minecraft:custom_name": "Excalibur"}}},
+///     ]},
+/// });
+/// let stack = serde_json::from_value::<ItemStack>(components.clone())?;
+/// assert_eq!(stack.format, ItemFormat::Components);
+/// assert_eq!(serde_json::to_value(&stack)?, components);
+/// let sword = serde_json::from_value::<ItemStack>(stack.nbt.as_ref().unwrap()["minecraft:container"][1]["item"].clone())?;
+/// assert_eq!(sword.format, ItemFormat::Components);
+/// assert_eq!(sword.custom_name().unwrap().text, "Excalibur");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemStack {
+    /// The item ID, e.g. `minecraft:diamond_sword`.
+    pub id: Identifier,
+    /// The number of items in the stack.
+    pub count: i32,
+    /// The `tag` compound in the legacy format, or the `components` map in the modern format, as JSON.
+    pub nbt: Option<serde_json::Value>,
+    /// The format to use when serializing.
+    pub format: ItemFormat,
+}
+
+impl ItemStack {
+    /// Creates a stack containing a single item with no additional data, in the modern format.
+    pub fn new(id: Identifier) -> ItemStack {
+        ItemStack {
+            count: 1,
+            nbt: None,
+            format: ItemFormat::default(),
+            id,
+        }
+    }
+
+    /// Changes the number of items in the stack.
+    pub fn with_count(mut self, count: i32) -> ItemStack {
+        self.count = count;
+        self
+    }
+
+    /// Changes the additional data.
+    pub fn with_nbt(mut self, nbt: serde_json::Value) -> ItemStack {
+        self.nbt = Some(nbt);
+        self
+    }
+
+    /// Whether this stack is considered empty by the game, i.e. it's air or its count is not positive.
+    pub fn is_empty(&self) -> bool {
+        self.count <= 0 || self.id == Identifier::minecraft("air")
+    }
+
+    /// The translation key for the default name of this item, e.g. `item.minecraft.diamond_sword`.
+    ///
+    /// Block items use keys like `block.minecraft.stone` instead, which can't be told apart by ID alone.
+    pub fn translation_key(&self) -> String {
+        format!("item.{}.{}", self.id.namespace(), self.id.path().replace('/', "."))
+    }
+
+    /// The value of a data component, with or without the `minecraft:` prefix. Always `None` in the legacy format.
+    fn component(&self, name: &str) -> Option<&serde_json::Value> {
+        if self.format != ItemFormat::Components { return None }
+        let components = self.nbt.as_ref()?;
+        components.get(format!("minecraft:{}", name)).or_else(|| components.get(name))
+    }
+
+    /// The name given to this stack, e.g. using an anvil, from the `custom_name` component or the `display.Name` tag in the legacy format.
+    pub fn custom_name(&self) -> Option<Chat> {
+        match self.format {
+            ItemFormat::Legacy => text_component(self.nbt.as_ref()?.get("display")?.get("Name")?),
+            ItemFormat::Components => text_component(self.component("custom_name")?),
+        }
+    }
+
+    /// The name of this stack as the client shows it, e.g. in tooltips and the recipe book, followed by ` ×count` if there's more than one item.
+    ///
+    /// Like in vanilla, a [custom name](ItemStack::custom_name) is shown in italics. Otherwise, the `item_name` component is used if present, falling back to the item's [translation key](ItemStack::translation_key).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::{
+    ///     identifier::Identifier,
+    ///     item::ItemStack,
+    /// };
+    ///
+    /// let sword = ItemStack::new(Identifier::minecraft("diamond_sword"));
+    /// assert_eq!(sword.to_chat().to_json()?, r#"{"translate":"item.minecraft.diamond_sword"}"#);
+    /// let named = sword.with_count(2).with_nbt(serde_json::json!({"minecraft:custom_name": r#"{"text":"Excalibur"}"#}));
+    /// assert_eq!(named.to_chat().to_json()?, r#"{"text":"","extra":[{"text":"Excalibur","italic":true},{"text":" ×2"}]}"#);
+    /// # Ok::<(), minecraft::chat::ChatError>(())
+    /// ```
+    pub fn to_chat(&self) -> Chat {
+        let name = if let Some(mut name) = self.custom_name() {
+            if name.italic.is_none() { name.italic(); }
+            name
+        } else if let Some(name) = self.component("item_name").and_then(text_component) {
+            name
+        } else {
+            let mut name = Chat::default();
+            name.details_mut().translate = Some(self.translation_key());
+            name
+        };
+        if self.count > 1 {
+            Chat::default().with_extras(vec![name, Chat::from(format!(" ×{}", self.count))])
+        } else {
+            name
+        }
+    }
+}
+
+/// Reads a text component stored in item data, which is JSON text in a string before 1.21.5 and a component directly since then.
+fn text_component(value: &serde_json::Value) -> Option<Chat> {
+    match value {
+        serde_json::Value::String(s) => Some(
+            serde_json::from_str::<serde_json::Value>(s).ok()
+                .and_then(|json| deserialize_text_component(&json).ok())
+                .unwrap_or_else(|| Chat::from(s.clone())) // plain text since 1.21.5
+        ),
+        value => deserialize_text_component(value).ok(),
+    }
+}
+
+#[derive(Deserialize)]
+struct ItemStackRepr {
+    #[serde(alias = "item")] // used by advancement icons before 1.20.5
+    id: Identifier,
+    #[serde(rename = "Count")]
+    legacy_count: Option<i32>,
+    count: Option<i32>,
+    tag: Option<serde_json::Value>,
+    components: Option<serde_json::Value>,
+}
+
+impl<'de> Deserialize<'de> for ItemStack {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ItemStack, D::Error> {
+        let ItemStackRepr { id, legacy_count, count, tag, components } = ItemStackRepr::deserialize(deserializer)?;
+        Ok(if legacy_count.is_some() || tag.is_some() {
+            ItemStack {
+                count: legacy_count.or(count).unwrap_or(1),
+                nbt: tag,
+                format: ItemFormat::Legacy,
+                id,
+            }
+        } else {
+            ItemStack {
+                count: count.unwrap_or(1),
+                nbt: components,
+                format: ItemFormat::Components,
+                id,
+            }
+        })
+    }
+}
+
+impl Serialize for ItemStack {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (count_key, nbt_key) = match self.format {
+            ItemFormat::Legacy => ("Count", "tag"),
+            ItemFormat::Components => ("count", "components"),
+        };
+        let mut state = serializer.serialize_struct("ItemStack", 2 + usize::from(self.nbt.is_some()))?;
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field(count_key, &self.count)?;
+        if let Some(ref nbt) = self.nbt {
+            state.serialize_field(nbt_key, nbt)?;
+        } else {
+            state.skip_field(nbt_key)?;
+        }
+        state.end()
+    }
+}
+
+impl From<&ItemStack> for HoverEvent {
+    /// Creates a `show_item` hover event for this item stack.
+    ///
+    /// In the legacy format, the `tag` compound is written as SNBT, of which JSON is a subset.
+    fn from(item: &ItemStack) -> HoverEvent {
+        let (tag, components) = match item.format {
+            ItemFormat::Legacy => (item.nbt.as_ref().map(|tag| tag.to_string()), None),
+            ItemFormat::Components => (None, item.nbt.clone()),
+        };
+        HoverEvent::ShowItem {
+            id: item.id.to_string(),
+            count: if item.count == 1 { None } else { Some(item.count) },
+            tag, components,
+        }
+    }
+}
@@ -0,0 +1,334 @@
+//! This module contains support for [legacy formatting codes](https://minecraft.fandom.com/wiki/Formatting_codes), which style text using a section sign `§` followed by a character.
+//!
+//! Use [`Chat::from_legacy`](crate::chat::Chat::from_legacy) to convert legacy-formatted text to a text component, and [`Chat::to_legacy`](crate::chat::Chat::to_legacy) for the reverse. For large amounts of text, e.g. for syntax highlighting, [`Scanner`] yields the styled runs without allocating.
+//!
+//! Hex colors are supported in the format used by BungeeCord and Spigot, where `§x` is followed by the six hex digits each prefixed with a section sign, e.g. `§x§f§f§5§5§a§a` for `#ff55aa`.
+
+use {
+    std::{
+        fmt,
+        ops::Range,
+    },
+    crate::chat::{
+        Color,
+        NamedColor,
+        ResolvedStyle,
+    },
+};
+
+/// The character which starts a formatting code.
+pub const SECTION_SIGN: char = '§';
+
+const COLOR_CODES: [char; 16] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f'];
+
+/// A legacy formatting code, i.e. the character following a [`SECTION_SIGN`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormattingCode {
+    /// `0`–`9` and `a`–`f`: sets the text color and turns off all formatting codes.
+    Color(NamedColor),
+    /// `k`: randomly changing characters.
+    Obfuscated,
+    /// `l`: boldface.
+    Bold,
+    /// `m`: strikethrough.
+    Strikethrough,
+    /// `n`: underline.
+    Underlined,
+    /// `o`: italics.
+    Italic,
+    /// `r`: resets color and formatting.
+    Reset,
+}
+
+impl FormattingCode {
+    /// Looks up a formatting code by its character. Case-insensitive, like in vanilla.
+    pub fn from_char(c: char) -> Option<FormattingCode> {
+        let c = c.to_ascii_lowercase();
+        Some(match c {
+            'k' => FormattingCode::Obfuscated,
+            'l' => FormattingCode::Bold,
+            'm' => FormattingCode::Strikethrough,
+            'n' => FormattingCode::Underlined,
+            'o' => FormattingCode::Italic,
+            'r' => FormattingCode::Reset,
+            _ => FormattingCode::Color(NamedColor::from_index(COLOR_CODES.iter().position(|&code| code == c)? as u8)?),
+        })
+    }
+
+    /// The lowercase character for this formatting code.
+    pub fn to_char(&self) -> char {
+        match self {
+            FormattingCode::Color(color) => COLOR_CODES[usize::from(color.index())],
+            FormattingCode::Obfuscated => 'k',
+            FormattingCode::Bold => 'l',
+            FormattingCode::Strikethrough => 'm',
+            FormattingCode::Underlined => 'n',
+            FormattingCode::Italic => 'o',
+            FormattingCode::Reset => 'r',
+        }
+    }
+}
+
+impl fmt::Display for FormattingCode {
+    /// Formats the code including the section sign, e.g. `§c`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", SECTION_SIGN, self.to_char())
+    }
+}
+
+/// Which formatting codes other than colors are in effect at some point in a legacy-formatted string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StyleFlags {
+    /// Turned on by `§k`.
+    pub obfuscated: bool,
+    /// Turned on by `§l`.
+    pub bold: bool,
+    /// Turned on by `§m`.
+    pub strikethrough: bool,
+    /// Turned on by `§n`.
+    pub underlined: bool,
+    /// Turned on by `§o`.
+    pub italic: bool,
+}
+
+/// A run of legacy-formatted text with the same style, as yielded by [`Scanner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacySpan<'a> {
+    /// The displayed text, without formatting codes. This is always the end of `byte_range` in the scanned string.
+    pub text: &'a str,
+    /// The text color, or `None` if no color code is in effect.
+    pub color: Option<Color>,
+    /// The formatting codes in effect.
+    pub flags: StyleFlags,
+    /// The position of this span in the scanned string, including the formatting codes before `text`.
+    pub byte_range: Range<usize>,
+}
+
+/// The effect of a formatting code read by [`parse_code`].
+enum Code {
+    Formatting(FormattingCode),
+    Hex([u8; 3]),
+}
+
+/// Reads the formatting code at the start of `text`, which starts with `prefix`.
+///
+/// Returns `None` if the prefix is literal text, otherwise the length of the code in bytes and its effect, if any. Malformed codes are literal text unless `remove_unknown` is true.
+fn parse_code(text: &str, prefix: char, remove_unknown: bool) -> Option<(usize, Option<Code>)> {
+    let mut chars = text[prefix.len_utf8()..].chars();
+    let (len, code) = match chars.next() {
+        Some(c @ ('x' | 'X')) => match parse_hex(chars.as_str(), prefix) {
+            Some((rgb, len)) => (c.len_utf8() + len, Some(Code::Hex(rgb))),
+            None if remove_unknown => (c.len_utf8(), None),
+            None => return None,
+        },
+        Some(c) => match FormattingCode::from_char(c) {
+            Some(code) => (c.len_utf8(), Some(Code::Formatting(code))),
+            None if remove_unknown => (c.len_utf8(), None),
+            None => return None,
+        },
+        None if remove_unknown => (0, None),
+        None => return None,
+    };
+    Some((prefix.len_utf8() + len, code))
+}
+
+/// Parses the six `prefix`-prefixed hex digits following a `§x` code, returning the color and the length of the digits in bytes.
+fn parse_hex(text: &str, prefix: char) -> Option<([u8; 3], usize)> {
+    let mut chars = text.chars();
+    let mut rgb = [0; 3];
+    for i in 0..6 {
+        if chars.next()? != prefix { return None }
+        let digit = chars.next()?.to_digit(16)? as u8;
+        rgb[i / 2] = rgb[i / 2] << 4 | digit;
+    }
+    Some((rgb, text.len() - chars.as_str().len()))
+}
+
+/// An iterator over the [spans](LegacySpan) of a legacy-formatted string, which borrows the text instead of allocating.
+///
+/// Each span consists of any formatting codes followed by the text they apply to, so the spans cover the whole string without gaps. Only the last span can have empty text, if the string ends with formatting codes.
+///
+/// A prefix followed by an unknown character, a trailing prefix, and a `§x` code which is not followed by six hex digits are kept as literal text, like in Bukkit. The vanilla client instead removes them, leaving the digits after a malformed `§x` to be read as regular codes; use [`Scanner::remove_unknown_codes`] for that behavior.
+///
+/// # Examples
+///
+/// ```
+/// use minecraft::{
+///     chat::{
+///         Color,
+///         NamedColor,
+///     },
+///     legacy::Scanner,
+/// };
+///
+/// let spans = Scanner::new("Hi §cthere§l!§z§").collect::<Vec<_>>();
+/// assert_eq!(spans.iter().map(|span| span.text).collect::<Vec<_>>(), ["Hi ", "there", "!§z§"]);
+/// assert_eq!(spans[1].color, Some(Color::Named(NamedColor::Red)));
+/// assert_eq!(spans[1].byte_range, 3..11);
+/// assert!(spans[2].flags.bold);
+///
+/// let spans = Scanner::new("Hi §cthere§l!§z§").remove_unknown_codes().collect::<Vec<_>>();
+/// assert_eq!(spans.iter().map(|span| span.text).collect::<Vec<_>>(), ["Hi ", "there", "!", ""]);
+/// assert_eq!(spans[3].byte_range, 15..20);
+///
+/// let spans = Scanner::new("&aA&z & B").with_prefix('&').collect::<Vec<_>>();
+/// assert_eq!(spans.iter().map(|span| span.text).collect::<Vec<_>>(), ["A&z & B"]);
+/// ```
+///
+/// The spans exactly cover any input:
+///
+/// ```
+/// use minecraft::legacy::Scanner;
+///
+/// let alphabet = ['§', '&', 'x', 'X', 'f', 'a', 'l', 'r', 'z', ' ', 'é'];
+/// let mut state = 0x2545_f491_u32;
+/// for _ in 0..10_000 {
+///     let mut text = String::default();
+///     for _ in 0..state % 24 {
+///         state ^= state << 13;
+///         state ^= state >> 17;
+///         state ^= state << 5;
+///         text.push(alphabet[state as usize % alphabet.len()]);
+///     }
+///     for (prefix, remove_unknown) in [('§', false), ('§', true), ('&', false), ('&', true)] {
+///         let scanner = Scanner::new(&text).with_prefix(prefix);
+///         let spans = if remove_unknown { scanner.remove_unknown_codes() } else { scanner }.collect::<Vec<_>>();
+///         let mut end = 0;
+///         for (idx, span) in spans.iter().enumerate() {
+///             assert_eq!(span.byte_range.start, end);
+///             end = span.byte_range.end;
+///             assert!(text[span.byte_range.clone()].ends_with(span.text));
+///             assert!(!span.text.is_empty() || idx == spans.len() - 1);
+///         }
+///         assert_eq!(end, text.len());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    text: &'a str,
+    prefix: char,
+    remove_unknown: bool,
+    pos: usize,
+    color: Option<Color>,
+    flags: StyleFlags,
+}
+
+impl<'a> Scanner<'a> {
+    /// Creates a scanner for text using the [`SECTION_SIGN`] prefix.
+    pub fn new(text: &'a str) -> Scanner<'a> {
+        Scanner {
+            prefix: SECTION_SIGN,
+            remove_unknown: false,
+            pos: 0,
+            color: None,
+            flags: StyleFlags::default(),
+            text,
+        }
+    }
+
+    /// Changes the character which starts a formatting code, e.g. to `&`.
+    pub fn with_prefix(mut self, prefix: char) -> Scanner<'a> {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Removes malformed formatting codes instead of yielding them as literal text, like the vanilla client when rendering text.
+    pub fn remove_unknown_codes(mut self) -> Scanner<'a> {
+        self.remove_unknown = true;
+        self
+    }
+
+    fn apply(&mut self, code: Code) {
+        match code {
+            Code::Formatting(FormattingCode::Color(color)) => self.set_color(Color::Named(color)),
+            Code::Formatting(FormattingCode::Obfuscated) => self.flags.obfuscated = true,
+            Code::Formatting(FormattingCode::Bold) => self.flags.bold = true,
+            Code::Formatting(FormattingCode::Strikethrough) => self.flags.strikethrough = true,
+            Code::Formatting(FormattingCode::Underlined) => self.flags.underlined = true,
+            Code::Formatting(FormattingCode::Italic) => self.flags.italic = true,
+            Code::Formatting(FormattingCode::Reset) => {
+                self.color = None;
+                self.flags = StyleFlags::default();
+            }
+            Code::Hex(rgb) => self.set_color(Color::Hex(rgb)),
+        }
+    }
+
+    /// Sets the color, which also turns off all formatting codes.
+    fn set_color(&mut self, color: Color) {
+        self.color = Some(color);
+        self.flags = StyleFlags::default();
+    }
+}
+
+impl<'a> Iterator for Scanner<'a> {
+    type Item = LegacySpan<'a>;
+
+    fn next(&mut self) -> Option<LegacySpan<'a>> {
+        let text = self.text;
+        let start = self.pos;
+        if start >= text.len() { return None }
+        let mut text_start = start;
+        while text[text_start..].starts_with(self.prefix) {
+            let Some((len, code)) = parse_code(&text[text_start..], self.prefix, self.remove_unknown) else { break };
+            if let Some(code) = code { self.apply(code) }
+            text_start += len;
+        }
+        let mut end = text_start;
+        while let Some(idx) = text[end..].find(self.prefix) {
+            end += idx;
+            if parse_code(&text[end..], self.prefix, self.remove_unknown).is_some() { break }
+            end += self.prefix.len_utf8();
+        }
+        if !text[end..].starts_with(self.prefix) { end = text.len() }
+        self.pos = end;
+        Some(LegacySpan {
+            text: &text[text_start..end],
+            color: self.color,
+            flags: self.flags,
+            byte_range: start..end,
+        })
+    }
+}
+
+/// Converts styled runs of text to a legacy-formatted string.
+///
+/// Formatting codes are only written when the style changes. Since color codes turn off all other formatting, a change which turns formatting off or changes the color is written as the color code (or `§r` for the default color) followed by all formatting codes which are on. Hex colors are written in the BungeeCord format if `hex` is true, otherwise as the [nearest](NamedColor::nearest) named color.
+pub(crate) fn write_runs(runs: impl IntoIterator<Item = (String, ResolvedStyle)>, hex: bool) -> String {
+    let mut legacy = String::default();
+    let mut prev = ResolvedStyle::default();
+    for (text, mut style) in runs {
+        if !hex {
+            if let Some(Color::Hex(rgb)) = style.color { style.color = Some(Color::Named(NamedColor::nearest(rgb))) }
+        }
+        let flags = [
+            (style.obfuscated, prev.obfuscated, FormattingCode::Obfuscated),
+            (style.bold, prev.bold, FormattingCode::Bold),
+            (style.strikethrough, prev.strikethrough, FormattingCode::Strikethrough),
+            (style.underlined, prev.underlined, FormattingCode::Underlined),
+            (style.italic, prev.italic, FormattingCode::Italic),
+        ];
+        let reset = style.color != prev.color || flags.iter().any(|&(on, was_on, _)| was_on && !on);
+        if reset {
+            match style.color {
+                Some(Color::Named(color)) => legacy.push_str(&FormattingCode::Color(color).to_string()),
+                Some(Color::Hex(rgb)) => {
+                    legacy.push(SECTION_SIGN);
+                    legacy.push('x');
+                    for digit in format!("{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2]).chars() {
+                        legacy.push(SECTION_SIGN);
+                        legacy.push(digit);
+                    }
+                }
+                Some(Color::Reset) | Some(Color::None) | None => legacy.push_str(&FormattingCode::Reset.to_string()),
+            }
+        }
+        for (on, was_on, code) in flags {
+            if on && (reset || !was_on) { legacy.push_str(&code.to_string()) }
+        }
+        legacy.push_str(&text);
+        prev = style;
+    }
+    legacy
+}
@@ -0,0 +1,170 @@
+//! This module contains types for the world metadata stored in [`level.dat`](https://minecraft.fandom.com/wiki/Java_Edition_level_format#level.dat_format).
+//!
+//! The types only use plain serde, so they work with any serde NBT crate as well as with NBT converted to JSON. Fields not covered here are ignored.
+
+use {
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::{
+        game_rules::GameRules,
+        position::BlockPos,
+        ticks::Ticks,
+        util::nbt_bool,
+        weather::WeatherState,
+        world_time::DayTime,
+    },
+};
+
+/// The root compound of `level.dat`, which contains everything in `Data`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LevelDat {
+    /// The world metadata.
+    #[serde(rename = "Data")]
+    pub data: LevelData,
+}
+
+/// The `Version` compound of [`LevelData`], added in 1.9.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LevelVersion {
+    /// The data version, same as [`LevelData::data_version`].
+    pub id: i32,
+    /// The version name, e.g. `1.21` or `24w14a`.
+    pub name: String,
+    /// Whether the version is a snapshot.
+    #[serde(default, with = "nbt_bool")]
+    pub snapshot: bool,
+    /// The series of the version, `main` for vanilla. Added in 1.18.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub series: Option<String>,
+}
+
+/// The `DataPacks` compound of [`LevelData`], listing data packs by ID, e.g. `vanilla` or `file/example.zip`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+#[allow(missing_docs)] // fields are obvious
+pub struct DataPacks {
+    #[serde(default)]
+    pub enabled: Vec<String>,
+    #[serde(default)]
+    pub disabled: Vec<String>,
+}
+
+/// The `WorldGenSettings` compound of [`LevelData`], added in 1.16. Only the seed and flags are covered, not the dimension generators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct WorldGenSettings {
+    /// The world seed.
+    pub seed: i64,
+    /// Whether structures are generated.
+    #[serde(default, with = "nbt_bool")]
+    pub generate_features: bool,
+    /// Whether a bonus chest was generated at the spawn point.
+    #[serde(default, with = "nbt_bool")]
+    pub bonus_chest: bool,
+}
+
+/// The contents of the `Data` compound of `level.dat`.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::{
+///     level::LevelDat,
+///     position::BlockPos,
+///     weather::Weather,
+/// };
+///
+/// // 1.16.5, converted from NBT
+/// let old = serde_json::from_str::<LevelDat>(r#"{"Data": {
+///     "LevelName": "Old World", "DataVersion": 2586, "Version": {"Id": 2586, "Name": "1.16.5", "Snapshot": 0},
+///     "WorldGenSettings": {"seed": -4172144997902289642, "generate_features": 1, "bonus_chest": 0, "dimensions": {}},
+///     "SpawnX": 64, "SpawnY": 70, "SpawnZ": -32, "SpawnAngle": 0.0,
+///     "Time": 1234567, "DayTime": 18000,
+///     "raining": 1, "rainTime": 3000, "thundering": 0, "thunderTime": 50000, "clearWeatherTime": 0,
+///     "GameRules": {"keepInventory": "true", "doFireTick": "false"},
+///     "DataPacks": {"Enabled": ["vanilla"], "Disabled": []},
+///     "GameType": 0, "hardcore": 0, "Player": {}
+/// }}"#)?.data;
+/// assert_eq!(old.seed(), Some(-4172144997902289642));
+/// assert_eq!(old.spawn(), BlockPos { x: 64, y: 70, z: -32 });
+/// assert_eq!(old.weather.current(), Weather::Rain);
+/// assert!(old.game_rules.keep_inventory());
+/// assert_eq!(old.version.unwrap().name, "1.16.5");
+///
+/// // 1.21, converted from NBT
+/// let new = serde_json::from_str::<LevelDat>(r#"{"Data": {
+///     "LevelName": "New World", "DataVersion": 3953, "Version": {"Id": 3953, "Name": "1.21", "Series": "main", "Snapshot": 0},
+///     "WorldGenSettings": {"seed": 42, "generate_features": 1, "bonus_chest": 1, "dimensions": {}},
+///     "SpawnX": 0, "SpawnY": 64, "SpawnZ": 0, "SpawnAngle": 90.0,
+///     "Time": 100, "DayTime": 100,
+///     "raining": 0, "rainTime": 0, "thundering": 0, "thunderTime": 0, "clearWeatherTime": 0,
+///     "GameRules": {"spawnChunkRadius": "0"},
+///     "DataPacks": {"Enabled": ["vanilla", "file/example.zip"], "Disabled": ["bundle", "trade_rebalance"]},
+///     "enabled_features": ["minecraft:vanilla"], "WanderingTraderSpawnChance": 25
+/// }}"#)?.data;
+/// assert_eq!(new.seed(), Some(42));
+/// assert_eq!(new.spawn_angle, 90.0);
+/// assert_eq!(new.game_rules.spawn_chunk_radius(), 0);
+/// assert_eq!(new.data_packs.disabled, ["bundle", "trade_rebalance"]);
+/// assert_eq!(new.version.unwrap().series.as_deref(), Some("main"));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LevelData {
+    /// The name of the world shown in the world list.
+    #[serde(rename = "LevelName")]
+    pub level_name: String,
+    /// The data version of the game which last saved the world. Missing before 1.9.
+    #[serde(rename = "DataVersion", default, skip_serializing_if = "Option::is_none")]
+    pub data_version: Option<i32>,
+    /// The version of the game which last saved the world. Missing before 1.9.
+    #[serde(rename = "Version", default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<LevelVersion>,
+    /// The world generation settings, used since 1.16.
+    #[serde(rename = "WorldGenSettings", default, skip_serializing_if = "Option::is_none")]
+    pub world_gen_settings: Option<WorldGenSettings>,
+    /// The world seed as stored before 1.16.
+    #[serde(rename = "RandomSeed", default, skip_serializing_if = "Option::is_none")]
+    pub random_seed: Option<i64>,
+    /// The x coordinate of the world spawn point.
+    #[serde(rename = "SpawnX")]
+    pub spawn_x: i32,
+    /// The y coordinate of the world spawn point.
+    #[serde(rename = "SpawnY")]
+    pub spawn_y: i32,
+    /// The z coordinate of the world spawn point.
+    #[serde(rename = "SpawnZ")]
+    pub spawn_z: i32,
+    /// The direction players face when spawning, in degrees. Added in 1.16.
+    #[serde(rename = "SpawnAngle", default)]
+    pub spawn_angle: f32,
+    /// The number of ticks the world has been running.
+    #[serde(rename = "Time")]
+    pub time: Ticks,
+    /// The time of day.
+    #[serde(rename = "DayTime")]
+    pub day_time: DayTime,
+    /// The weather and its timers.
+    #[serde(flatten)]
+    pub weather: WeatherState,
+    /// The game rules of the world.
+    #[serde(rename = "GameRules", default)]
+    pub game_rules: GameRules,
+    /// The data packs of the world. Added in 1.13.
+    #[serde(rename = "DataPacks", default)]
+    pub data_packs: DataPacks,
+}
+
+impl LevelData {
+    /// The world seed, from either the 1.16+ or the older location.
+    pub fn seed(&self) -> Option<i64> {
+        self.world_gen_settings.map(|settings| settings.seed).or(self.random_seed)
+    }
+
+    /// The world spawn point.
+    pub fn spawn(&self) -> BlockPos {
+        BlockPos { x: self.spawn_x, y: self.spawn_y, z: self.spawn_z }
+    }
+}
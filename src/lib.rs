@@ -3,4 +3,52 @@
 #![deny(missing_docs, rust_2018_idioms, unused, unused_crate_dependencies, unused_import_braces, unused_lifetimes, unused_qualifications, warnings)]
 #![forbid(unsafe_code)]
 
+#[cfg(test)] use {criterion as _, jsonschema as _, rsa as _, sha2 as _}; // only used by the benchmarks, doc tests, and integration tests
+
+#[macro_use] pub mod identifier;
+pub mod advancement;
+pub mod attribute;
+pub mod block_state;
+pub mod book;
+pub mod bossbar;
 pub mod chat;
+pub mod chat_type;
+pub mod difficulty;
+pub mod dimension;
+pub mod dye_color;
+pub mod effect;
+pub mod enchantment;
+pub mod entity;
+pub mod experience;
+pub mod firework;
+pub mod game_mode;
+pub mod game_rules;
+pub mod item;
+pub mod legacy;
+pub mod level;
+pub mod locale;
+pub mod log;
+pub mod map;
+pub mod pack;
+pub mod player_list;
+pub mod position;
+pub mod recipe;
+pub mod scoreboard;
+pub mod selector;
+pub mod server_lists;
+pub mod server_properties;
+pub mod sign;
+pub mod slot;
+pub mod snbt;
+pub mod sound;
+pub mod stats;
+pub mod tag;
+pub mod ticks;
+pub mod title;
+pub mod user_cache;
+pub mod version;
+pub mod weather;
+pub mod world_border;
+pub mod world_time;
+
+mod util;
@@ -0,0 +1,276 @@
+//! This module contains the [`Locale`] type, representing a [language](https://minecraft.fandom.com/wiki/Language) setting.
+
+use {
+    std::{
+        borrow::Cow,
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::Error as _,
+    },
+};
+
+/// A locale code as used by the game, e.g. `en_us` or `lol_us`.
+///
+/// These are similar to BCP 47 language tags, but lowercase and separated by underscores, and some have no region. Parsing normalizes case and accepts hyphens, so `en-US` parses as `en_us`.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::locale::Locale;
+///
+/// let locale = "de-AT".parse::<Locale>()?;
+/// assert_eq!(locale, Locale::DE_AT);
+/// assert_eq!(locale.to_string(), "de_at");
+/// assert_eq!((locale.language(), locale.region()), ("de", Some("at")));
+/// assert_eq!(locale.fallbacks(), [Locale::DE_AT, Locale::DE_DE, Locale::EN_US]);
+/// assert_eq!(Locale::ENWS.region(), None);
+/// assert_eq!("xx_yy".parse::<Locale>()?.fallbacks(), ["xx_yy".parse()?, Locale::EN_US]);
+/// # Ok::<(), minecraft::locale::LocaleParseError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Locale(Cow<'static, str>);
+
+macro_rules! locales {
+    ($($name:ident => $code:literal,)*) => {
+        impl Locale {
+            $(
+                #[allow(missing_docs)]
+                pub const $name: Locale = Locale(Cow::Borrowed($code));
+            )*
+
+            /// The locales which ship with the game as of 1.21.
+            pub const ALL: &'static [Locale] = &[$(Locale::$name,)*];
+        }
+    };
+}
+
+locales! {
+    AF_ZA => "af_za",
+    AR_SA => "ar_sa",
+    AST_ES => "ast_es",
+    AZ_AZ => "az_az",
+    BA_RU => "ba_ru",
+    BAR => "bar",
+    BE_BY => "be_by",
+    BG_BG => "bg_bg",
+    BR_FR => "br_fr",
+    BRB => "brb",
+    BS_BA => "bs_ba",
+    CA_ES => "ca_es",
+    CS_CZ => "cs_cz",
+    CY_GB => "cy_gb",
+    DA_DK => "da_dk",
+    DE_AT => "de_at",
+    DE_CH => "de_ch",
+    DE_DE => "de_de",
+    EL_GR => "el_gr",
+    EN_AU => "en_au",
+    EN_CA => "en_ca",
+    EN_GB => "en_gb",
+    EN_NZ => "en_nz",
+    EN_PT => "en_pt",
+    EN_UD => "en_ud",
+    EN_US => "en_us",
+    ENP => "enp",
+    ENWS => "enws",
+    EO_UY => "eo_uy",
+    ES_AR => "es_ar",
+    ES_CL => "es_cl",
+    ES_EC => "es_ec",
+    ES_ES => "es_es",
+    ES_MX => "es_mx",
+    ES_UY => "es_uy",
+    ES_VE => "es_ve",
+    ESAN => "esan",
+    ET_EE => "et_ee",
+    EU_ES => "eu_es",
+    FA_IR => "fa_ir",
+    FI_FI => "fi_fi",
+    FIL_PH => "fil_ph",
+    FO_FO => "fo_fo",
+    FR_CA => "fr_ca",
+    FR_FR => "fr_fr",
+    FRA_DE => "fra_de",
+    FUR_IT => "fur_it",
+    FY_NL => "fy_nl",
+    GA_IE => "ga_ie",
+    GD_GB => "gd_gb",
+    GL_ES => "gl_es",
+    HAW_US => "haw_us",
+    HE_IL => "he_il",
+    HI_IN => "hi_in",
+    HN_NO => "hn_no",
+    HR_HR => "hr_hr",
+    HU_HU => "hu_hu",
+    HY_AM => "hy_am",
+    ID_ID => "id_id",
+    IG_NG => "ig_ng",
+    IO_EN => "io_en",
+    IS_IS => "is_is",
+    ISV => "isv",
+    IT_IT => "it_it",
+    JA_JP => "ja_jp",
+    JBO_EN => "jbo_en",
+    KA_GE => "ka_ge",
+    KK_KZ => "kk_kz",
+    KN_IN => "kn_in",
+    KO_KR => "ko_kr",
+    KSH => "ksh",
+    KW_GB => "kw_gb",
+    LA_LA => "la_la",
+    LB_LU => "lb_lu",
+    LI_LI => "li_li",
+    LMO => "lmo",
+    LO_LA => "lo_la",
+    LOL_US => "lol_us",
+    LT_LT => "lt_lt",
+    LV_LV => "lv_lv",
+    LZH => "lzh",
+    MK_MK => "mk_mk",
+    MN_MN => "mn_mn",
+    MS_MY => "ms_my",
+    MT_MT => "mt_mt",
+    NAH => "nah",
+    NDS_DE => "nds_de",
+    NL_BE => "nl_be",
+    NL_NL => "nl_nl",
+    NN_NO => "nn_no",
+    NO_NO => "no_no",
+    OC_FR => "oc_fr",
+    OVD => "ovd",
+    PL_PL => "pl_pl",
+    PT_BR => "pt_br",
+    PT_PT => "pt_pt",
+    QYA_AA => "qya_aa",
+    RO_RO => "ro_ro",
+    RPR => "rpr",
+    RU_RU => "ru_ru",
+    RY_UA => "ry_ua",
+    SAH_SAH => "sah_sah",
+    SE_NO => "se_no",
+    SK_SK => "sk_sk",
+    SL_SI => "sl_si",
+    SO_SO => "so_so",
+    SQ_AL => "sq_al",
+    SR_CS => "sr_cs",
+    SR_SP => "sr_sp",
+    SV_SE => "sv_se",
+    SXU => "sxu",
+    SZL => "szl",
+    TA_IN => "ta_in",
+    TH_TH => "th_th",
+    TL_PH => "tl_ph",
+    TLH_AA => "tlh_aa",
+    TOK => "tok",
+    TR_TR => "tr_tr",
+    TT_RU => "tt_ru",
+    UK_UA => "uk_ua",
+    VAL_ES => "val_es",
+    VEC_IT => "vec_it",
+    VI_VN => "vi_vn",
+    YI_DE => "yi_de",
+    YO_NG => "yo_ng",
+    ZH_CN => "zh_cn",
+    ZH_HK => "zh_hk",
+    ZH_TW => "zh_tw",
+    ZLM_ARAB => "zlm_arab",
+}
+
+impl Locale {
+    /// The default locale, and the last fallback for missing translations.
+    pub const DEFAULT: Locale = Locale::EN_US;
+
+    /// The canonical locale code, e.g. `en_us`.
+    pub fn code(&self) -> &str {
+        &self.0
+    }
+
+    /// The language part of the code, e.g. `en` for `en_us`.
+    pub fn language(&self) -> &str {
+        self.0.split('_').next().expect("split always yields at least one item")
+    }
+
+    /// The region part of the code, e.g. `us` for `en_us`, or `None` for locales without one like `enws`.
+    pub fn region(&self) -> Option<&str> {
+        self.0.split_once('_').map(|(_, region)| region)
+    }
+
+    /// The main locale shipped with the game for the language of this one, e.g. `de_de` for `de_at`.
+    ///
+    /// This is the locale whose region matches the language if there is one, with exceptions for languages where a different region is considered the main one, like `en_us` or `pt_br`.
+    pub fn primary(&self) -> Option<Locale> {
+        let language = self.language();
+        match language {
+            "en" => return Some(Locale::EN_US),
+            "pt" => return Some(Locale::PT_BR),
+            "sr" => return Some(Locale::SR_SP),
+            "zh" => return Some(Locale::ZH_CN),
+            _ => {}
+        }
+        let candidates = Locale::ALL.iter().filter(|locale| locale.language() == language).collect::<Vec<_>>();
+        candidates.iter().find(|locale| locale.region() == Some(language)).or_else(|| candidates.first()).map(|&locale| locale.clone())
+    }
+
+    /// The locales whose translations are used for this locale, in order: this locale, the [primary](Locale::primary) locale of its language, and `en_us`.
+    pub fn fallbacks(&self) -> Vec<Locale> {
+        let mut fallbacks = vec![self.clone()];
+        for fallback in self.primary().into_iter().chain(Some(Locale::DEFAULT)) {
+            if !fallbacks.contains(&fallback) { fallbacks.push(fallback) }
+        }
+        fallbacks
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Locale {
+        Locale::DEFAULT
+    }
+}
+
+/// The error returned when parsing a [`Locale`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocaleParseError(pub String);
+
+impl fmt::Display for LocaleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid locale: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for LocaleParseError {}
+
+impl FromStr for Locale {
+    type Err = LocaleParseError;
+
+    fn from_str(s: &str) -> Result<Locale, LocaleParseError> {
+        let code = s.to_ascii_lowercase().replace('-', "_");
+        if code.split('_').any(|part| part.is_empty() || !part.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())) {
+            return Err(LocaleParseError(s.to_owned()))
+        }
+        Ok(Locale::ALL.iter().find(|locale| locale.0 == code).cloned().unwrap_or(Locale(Cow::Owned(code))))
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for Locale {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Locale, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+}
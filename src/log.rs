@@ -0,0 +1,337 @@
+//! This module contains types for parsing lines of the server log, e.g. `logs/latest.log`.
+//!
+//! Only English messages are recognized, since the server always logs in English.
+
+use {
+    std::{
+        fmt,
+        str::FromStr,
+    },
+    crate::advancement::FrameType,
+};
+
+/// The severity of a log message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)] // variants are obvious
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl LogLevel {
+    /// The uppercase name used in the log, e.g. `INFO`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+            LogLevel::Fatal => "FATAL",
+        }
+    }
+}
+
+/// The error returned when parsing a [`LogLevel`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLevelParseError(pub String);
+
+impl fmt::Display for LogLevelParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown log level: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for LogLevelParseError {}
+
+impl FromStr for LogLevel {
+    type Err = LogLevelParseError;
+
+    fn from_str(s: &str) -> Result<LogLevel, LogLevelParseError> {
+        match s {
+            "TRACE" => Ok(LogLevel::Trace),
+            "DEBUG" => Ok(LogLevel::Debug),
+            "INFO" => Ok(LogLevel::Info),
+            "WARN" | "WARNING" => Ok(LogLevel::Warn),
+            "ERROR" | "SEVERE" => Ok(LogLevel::Error),
+            "FATAL" => Ok(LogLevel::Fatal),
+            _ => Err(LogLevelParseError(s.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The English death messages recognized by [`LogLine::parse`], with their translation keys.
+///
+/// Where several keys have the same message, e.g. deaths caused by mobs and by players, only one is listed.
+const DEATH_MESSAGES: &[(&str, &str)] = &[
+    ("death.attack.anvil", "%1$s was squashed by a falling anvil"),
+    ("death.attack.arrow", "%1$s was shot by %2$s"),
+    ("death.attack.arrow.item", "%1$s was shot by %2$s using %3$s"),
+    ("death.attack.cactus", "%1$s was pricked to death"),
+    ("death.attack.cramming", "%1$s was squished too much"),
+    ("death.attack.dragonBreath", "%1$s was roasted in dragon's breath"),
+    ("death.attack.drown", "%1$s drowned"),
+    ("death.attack.drown.player", "%1$s drowned while trying to escape %2$s"),
+    ("death.attack.dryout", "%1$s died from dehydration"),
+    ("death.attack.explosion", "%1$s blew up"),
+    ("death.attack.explosion.player", "%1$s was blown up by %2$s"),
+    ("death.attack.fall", "%1$s hit the ground too hard"),
+    ("death.attack.fallingBlock", "%1$s was squashed by a falling block"),
+    ("death.attack.fallingStalactite", "%1$s was skewered by a falling stalactite"),
+    ("death.attack.fireball", "%1$s was fireballed by %2$s"),
+    ("death.attack.fireworks", "%1$s went off with a bang"),
+    ("death.attack.flyIntoWall", "%1$s experienced kinetic energy"),
+    ("death.attack.freeze", "%1$s froze to death"),
+    ("death.attack.generic", "%1$s died"),
+    ("death.attack.genericKill", "%1$s was killed"),
+    ("death.attack.hotFloor", "%1$s discovered the floor was lava"),
+    ("death.attack.inFire", "%1$s went up in flames"),
+    ("death.attack.inWall", "%1$s suffocated in a wall"),
+    ("death.attack.indirectMagic", "%1$s was killed by %2$s using magic"),
+    ("death.attack.lava", "%1$s tried to swim in lava"),
+    ("death.attack.lava.player", "%1$s tried to swim in lava to escape %2$s"),
+    ("death.attack.lightningBolt", "%1$s was struck by lightning"),
+    ("death.attack.magic", "%1$s was killed by magic"),
+    ("death.attack.mob", "%1$s was slain by %2$s"),
+    ("death.attack.mob.item", "%1$s was slain by %2$s using %3$s"),
+    ("death.attack.onFire", "%1$s burned to death"),
+    ("death.attack.outOfWorld", "%1$s fell out of the world"),
+    ("death.attack.outsideBorder", "%1$s left the confines of this world"),
+    ("death.attack.sonic_boom", "%1$s was obliterated by a sonically-charged shriek"),
+    ("death.attack.stalagmite", "%1$s was impaled on a stalagmite"),
+    ("death.attack.starve", "%1$s starved to death"),
+    ("death.attack.sting", "%1$s was stung to death"),
+    ("death.attack.sweetBerryBush", "%1$s was poked to death by a sweet berry bush"),
+    ("death.attack.thorns", "%1$s was killed while trying to hurt %2$s"),
+    ("death.attack.thrown", "%1$s was pummeled by %2$s"),
+    ("death.attack.trident", "%1$s was impaled by %2$s"),
+    ("death.attack.wither", "%1$s withered away"),
+    ("death.attack.witherSkull", "%1$s was shot by a skull from %2$s"),
+    ("death.fell.accident.generic", "%1$s fell from a high place"),
+    ("death.fell.accident.ladder", "%1$s fell off a ladder"),
+    ("death.fell.accident.vines", "%1$s fell off some vines"),
+    ("death.fell.accident.water", "%1$s fell out of the water"),
+    ("death.fell.killer", "%1$s was doomed to fall"),
+];
+
+/// The meaning of a log message, as far as it's recognized.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogEvent {
+    /// A player sent a chat message.
+    Chat {
+        /// The name of the player, as shown between the angle brackets.
+        player: String,
+        /// The text of the message.
+        message: String,
+        /// Whether the message was marked `[Not Secure]` because it wasn't signed, since 1.19.1.
+        not_secure: bool,
+    },
+    /// A player joined the server.
+    Join {
+        #[allow(missing_docs)]
+        player: String,
+    },
+    /// A player left the server.
+    Leave {
+        #[allow(missing_docs)]
+        player: String,
+    },
+    /// A player died.
+    Death {
+        #[allow(missing_docs)]
+        player: String,
+        /// The full death message.
+        message: String,
+        /// The translation key of the death message, e.g. `death.attack.fall`. Messages with the same English text are not distinguished, e.g. `death.attack.player` is reported as `death.attack.mob`.
+        translation_key: &'static str,
+    },
+    /// A player completed an advancement.
+    Advancement {
+        #[allow(missing_docs)]
+        player: String,
+        /// The title of the advancement, without the square brackets.
+        advancement_title: String,
+        /// The frame type, determined by the wording of the announcement.
+        frame: FrameType,
+    },
+    /// The server finished starting up.
+    ServerStart {
+        /// How long the startup took, in seconds.
+        startup_secs: Option<f64>,
+    },
+    /// The server is shutting down.
+    ServerStop,
+    /// The server couldn't keep up with the tick rate.
+    Overloaded {
+        /// How far behind the server is, in milliseconds.
+        millis: u64,
+        /// How far behind the server is, in ticks.
+        ticks: u64,
+    },
+    /// Any other message.
+    Unknown(String),
+}
+
+/// Whether a string looks like a player name, to avoid mistaking other messages for joins or deaths.
+///
+/// Allows the `.` prefix used by Floodgate for Bedrock players.
+fn is_player_name(s: &str) -> bool {
+    let name = s.strip_prefix('.').unwrap_or(s);
+    (1..=16).contains(&name.len()) && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+/// Matches a message against a translation string, returning the texts of the placeholders.
+fn match_template<'a>(template: &str, message: &'a str) -> Option<Vec<&'a str>> {
+    let mut args = Vec::default();
+    let mut rest = message;
+    // all placeholders in DEATH_MESSAGES have the form %n$s
+    let mut literals = template.split('%').enumerate().map(|(idx, part)| if idx == 0 { part } else { &part[3..] });
+    let first = literals.next()?;
+    rest = rest.strip_prefix(first)?;
+    let literals = literals.collect::<Vec<_>>();
+    for (idx, literal) in literals.iter().enumerate() {
+        let end = if idx == literals.len() - 1 {
+            if literal.is_empty() { rest.len() } else { rest.strip_suffix(literal).map(str::len)? }
+        } else {
+            rest.find(literal)?
+        };
+        if end == 0 { return None }
+        args.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+    if rest.is_empty() { Some(args) } else { None }
+}
+
+impl LogEvent {
+    /// Recognizes the meaning of a log message, i.e. the part after the prefix.
+    pub fn parse(message: &str) -> LogEvent {
+        let (not_secure, chat) = match message.strip_prefix("[Not Secure] ") {
+            Some(rest) => (true, rest),
+            None => (false, message),
+        };
+        if let Some((player, text)) = chat.strip_prefix('<').and_then(|rest| rest.split_once("> ")) {
+            return LogEvent::Chat { player: player.to_owned(), message: text.to_owned(), not_secure }
+        }
+        if let Some((player, rest)) = message.split_once(' ') {
+            if is_player_name(player) {
+                // the name may be followed by " (formerly known as …)"
+                if rest == "joined the game" || rest.starts_with("(formerly known as ") && rest.ends_with(") joined the game") {
+                    return LogEvent::Join { player: player.to_owned() }
+                }
+                if rest == "left the game" { return LogEvent::Leave { player: player.to_owned() } }
+                for (prefix, frame) in [("has made the advancement [", FrameType::Task), ("has reached the goal [", FrameType::Goal), ("has completed the challenge [", FrameType::Challenge)] {
+                    if let Some(title) = rest.strip_prefix(prefix).and_then(|rest| rest.strip_suffix(']')) {
+                        return LogEvent::Advancement { player: player.to_owned(), advancement_title: title.to_owned(), frame }
+                    }
+                }
+                // prefer the most specific message, e.g. "was slain by %2$s using %3$s" over "was slain by %2$s"
+                if let Some((key, _)) = DEATH_MESSAGES.iter()
+                    .filter(|(_, template)| match_template(template, message).is_some_and(|args| args[0] == player))
+                    .max_by_key(|(_, template)| template.len())
+                {
+                    return LogEvent::Death { player: player.to_owned(), message: message.to_owned(), translation_key: key }
+                }
+            }
+        }
+        if let Some(rest) = message.strip_prefix("Done (") {
+            return LogEvent::ServerStart { startup_secs: rest.split_once("s)!").and_then(|(secs, _)| secs.parse().ok()) }
+        }
+        if message == "Stopping server" || message == "Stopping the server" { return LogEvent::ServerStop }
+        if let Some(rest) = message.strip_prefix("Can't keep up! Is the server overloaded? Running ") {
+            if let Some((millis, ticks)) = rest.strip_suffix(" ticks behind").and_then(|rest| rest.split_once("ms or ")) {
+                if let (Ok(millis), Ok(ticks)) = (millis.parse(), ticks.parse()) {
+                    return LogEvent::Overloaded { millis, ticks }
+                }
+            }
+        }
+        LogEvent::Unknown(message.to_owned())
+    }
+}
+
+/// A line of the server log.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::{
+///     advancement::FrameType,
+///     log::{
+///         LogEvent,
+///         LogLevel,
+///         LogLine,
+///     },
+/// };
+///
+/// let line = LogLine::parse("[18:04:22] [Server thread/INFO]: [Not Secure] <Notch> hello world").unwrap();
+/// assert_eq!(line.time, (18, 4, 22));
+/// assert_eq!(line.thread.as_deref(), Some("Server thread"));
+/// assert_eq!(line.level, LogLevel::Info);
+/// assert_eq!(line.message, LogEvent::Chat { player: format!("Notch"), message: format!("hello world"), not_secure: true });
+///
+/// // Paper omits the thread
+/// let line = LogLine::parse("[18:04:23 INFO]: jeb_ joined the game").unwrap();
+/// assert_eq!(line.thread, None);
+/// assert_eq!(line.message, LogEvent::Join { player: format!("jeb_") });
+///
+/// let parse = |line| LogLine::parse(line).unwrap().message;
+/// assert_eq!(parse("[18:04:24] [Server thread/INFO]: jeb_ has completed the challenge [How Did We Get Here?]"), LogEvent::Advancement { player: format!("jeb_"), advancement_title: format!("How Did We Get Here?"), frame: FrameType::Challenge });
+/// assert!(matches!(parse("[18:04:25] [Server thread/INFO]: Notch was slain by Zombie using [Iron Sword]"), LogEvent::Death { translation_key: "death.attack.mob.item", .. }));
+/// assert!(matches!(parse("[18:04:26] [Server thread/INFO]: Notch fell from a high place"), LogEvent::Death { translation_key: "death.fell.accident.generic", .. }));
+/// assert_eq!(parse("[18:04:27] [Server thread/WARN]: Can't keep up! Is the server overloaded? Running 2042ms or 40 ticks behind"), LogEvent::Overloaded { millis: 2042, ticks: 40 });
+/// assert_eq!(parse(r#"[18:04:28] [Server thread/INFO]: Done (3.456s)! For help, type "help""#), LogEvent::ServerStart { startup_secs: Some(3.456) });
+/// assert_eq!(parse("[18:04:29] [Server thread/INFO]: Stopping server"), LogEvent::ServerStop);
+/// assert_eq!(parse("[18:04:30] [Server thread/INFO]: Preparing level \"world\""), LogEvent::Unknown(format!("Preparing level \"world\"")));
+/// assert!(LogLine::parse("\tat java.base/java.lang.Thread.run(Thread.java:1583)").is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    /// The local time as hours, minutes, and seconds.
+    pub time: (u8, u8, u8),
+    /// The name of the thread which logged the message, e.g. `Server thread`. Paper doesn't log this.
+    pub thread: Option<String>,
+    /// The severity of the message.
+    pub level: LogLevel,
+    /// The message and its meaning.
+    pub message: LogEvent,
+}
+
+impl LogLine {
+    /// Parses a line of the server log in the vanilla format `[hh:mm:ss] [thread/LEVEL]: message` or the Paper format `[hh:mm:ss LEVEL]: message`.
+    ///
+    /// A logger name after the thread, as added by some mod loaders, is skipped. Returns `None` if the line doesn't start with a log prefix, e.g. for continuation lines of stack traces.
+    pub fn parse(line: &str) -> Option<LogLine> {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let (prefix, rest) = line.strip_prefix('[')?.split_once(']')?;
+        let (time, paper_level) = match prefix.split_once(' ') {
+            Some((time, level)) => (time, Some(level)),
+            None => (prefix, None),
+        };
+        let mut time_parts = time.split(':').map(|part| part.parse::<u8>().ok().filter(|_| part.len() == 2));
+        let time = (time_parts.next()??, time_parts.next()??, time_parts.next()??);
+        if time_parts.next().is_some() { return None }
+        let (thread, level, rest) = match paper_level {
+            Some(level) => (None, level.parse().ok()?, rest),
+            None => {
+                let (thread_level, rest) = rest.strip_prefix(" [")?.split_once(']')?;
+                let (thread, level) = thread_level.rsplit_once('/')?;
+                (Some(thread.to_owned()), level.parse().ok()?, rest)
+            }
+        };
+        let rest = match rest.strip_prefix(" [") {
+            Some(logger) => logger.split_once(']')?.1,
+            None => rest,
+        };
+        let message = rest.strip_prefix(": ").or_else(|| rest.strip_prefix(':'))?;
+        Some(LogLine { time, thread, level, message: LogEvent::parse(message) })
+    }
+}
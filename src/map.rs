@@ -0,0 +1,183 @@
+//! This module contains the color palette of [maps](https://minecraft.fandom.com/wiki/Map_item_format#Map_colors).
+
+use {
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::util,
+};
+
+/// The RGB values of the base colors, indexed by base color ID, as of 1.17.
+///
+/// Base color 0 is transparent; its RGB value is unused.
+pub const BASE_COLORS: [[u8; 3]; 62] = [
+    [0, 0, 0],
+    [127, 178, 56],
+    [247, 233, 163],
+    [199, 199, 199],
+    [255, 0, 0],
+    [160, 160, 255],
+    [167, 167, 167],
+    [0, 124, 0],
+    [255, 255, 255],
+    [164, 168, 184],
+    [151, 109, 77],
+    [112, 112, 112],
+    [64, 64, 255],
+    [143, 119, 72],
+    [255, 252, 245],
+    [216, 127, 51],
+    [178, 76, 216],
+    [102, 153, 216],
+    [229, 229, 51],
+    [127, 204, 25],
+    [242, 127, 165],
+    [76, 76, 76],
+    [153, 153, 153],
+    [76, 127, 153],
+    [127, 63, 178],
+    [51, 76, 178],
+    [102, 76, 51],
+    [102, 127, 51],
+    [153, 51, 51],
+    [25, 25, 25],
+    [250, 238, 77],
+    [92, 219, 213],
+    [74, 128, 255],
+    [0, 217, 58],
+    [129, 86, 49],
+    [112, 2, 0],
+    [209, 177, 161],
+    [159, 82, 36],
+    [149, 87, 108],
+    [112, 108, 138],
+    [186, 133, 36],
+    [103, 117, 53],
+    [160, 77, 78],
+    [57, 41, 35],
+    [135, 107, 98],
+    [87, 92, 92],
+    [122, 73, 88],
+    [76, 62, 92],
+    [76, 50, 35],
+    [76, 82, 42],
+    [142, 60, 46],
+    [37, 22, 16],
+    [189, 48, 49],
+    [148, 63, 97],
+    [92, 25, 29],
+    [22, 126, 134],
+    [58, 142, 140],
+    [86, 44, 62],
+    [20, 180, 133],
+    [100, 100, 100],
+    [216, 175, 147],
+    [127, 167, 150],
+];
+
+/// The shade applied to a base color, which depends on the height of the block compared to the one north of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Brightness {
+    /// The block is lower than the one north of it.
+    Low,
+    /// The block is at the same height as the one north of it.
+    Normal,
+    /// The block is higher than the one north of it.
+    High,
+    /// Not produced by terrain, but can be placed on maps by other means.
+    Lowest,
+}
+
+impl Brightness {
+    /// All brightness levels, in ID order.
+    pub const ALL: [Brightness; 4] = [Brightness::Low, Brightness::Normal, Brightness::High, Brightness::Lowest];
+
+    /// The ID, i.e. the lowest two bits of a [`MapColor`].
+    pub fn id(&self) -> u8 {
+        match self {
+            Brightness::Low => 0,
+            Brightness::Normal => 1,
+            Brightness::High => 2,
+            Brightness::Lowest => 3,
+        }
+    }
+
+    /// Looks up a brightness level by its ID.
+    pub fn from_id(id: u8) -> Option<Brightness> {
+        Brightness::ALL.get(usize::from(id)).copied()
+    }
+
+    /// The factor out of 255 by which the RGB channels of the base color are multiplied.
+    pub fn multiplier(&self) -> u8 {
+        match self {
+            Brightness::Low => 180,
+            Brightness::Normal => 220,
+            Brightness::High => 255,
+            Brightness::Lowest => 135,
+        }
+    }
+}
+
+/// A color as stored in the `colors` array of map data, combining a base color ID and a [`Brightness`].
+///
+/// Serializes as the plain byte value.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::map::{
+///     Brightness,
+///     MapColor,
+/// };
+///
+/// let grass = MapColor::new(1, Brightness::High).unwrap();
+/// assert_eq!(grass.0, 6);
+/// assert_eq!(grass.rgb(), Some([127, 178, 56]));
+/// assert_eq!(MapColor::nearest([130, 180, 60]), grass);
+/// assert_eq!(MapColor::new(1, Brightness::Normal).unwrap().rgb(), Some([109, 153, 48]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct MapColor(pub u8);
+
+impl MapColor {
+    /// The transparent color of unexplored map areas.
+    pub const TRANSPARENT: MapColor = MapColor(0);
+
+    /// Combines a base color ID with a brightness, or returns `None` if the base color is not in [`BASE_COLORS`].
+    pub fn new(base: u8, brightness: Brightness) -> Option<MapColor> {
+        if usize::from(base) < BASE_COLORS.len() { Some(MapColor(base << 2 | brightness.id())) } else { None }
+    }
+
+    /// The base color ID, i.e. an index into [`BASE_COLORS`].
+    pub fn base(&self) -> u8 {
+        self.0 >> 2
+    }
+
+    /// The shade applied to the base color.
+    pub fn brightness(&self) -> Brightness {
+        Brightness::from_id(self.0 & 0b11).expect("brightness IDs cover all 2-bit values")
+    }
+
+    /// Whether this color is transparent, i.e. its base color is 0.
+    pub fn is_transparent(&self) -> bool {
+        self.base() == 0
+    }
+
+    /// The RGB value of this color as rendered, or `None` if it's transparent or the base color is unknown.
+    pub fn rgb(&self) -> Option<[u8; 3]> {
+        if self.is_transparent() { return None }
+        let base = BASE_COLORS.get(usize::from(self.base()))?;
+        let multiplier = u32::from(self.brightness().multiplier());
+        Some(base.map(|channel| (u32::from(channel) * multiplier / 255) as u8))
+    }
+
+    /// The non-transparent map color closest to the given RGB value, for converting images to map art.
+    pub fn nearest(rgb: [u8; 3]) -> MapColor {
+        (4..BASE_COLORS.len() * 4)
+            .map(|idx| MapColor(idx as u8))
+            .min_by_key(|color| util::color_distance(color.rgb().expect("non-transparent known color"), rgb))
+            .expect("there are non-transparent map colors")
+    }
+}
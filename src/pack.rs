@@ -0,0 +1,314 @@
+//! This module contains types for the [`pack.mcmeta`](https://minecraft.fandom.com/wiki/Pack.mcmeta) file of resource packs and data packs.
+
+use {
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    },
+    crate::{
+        chat::{
+            self,
+            Chat,
+        },
+        version::Version,
+    },
+};
+
+/// Whether a pack is a resource pack or a data pack, which use different [pack formats](PackFormat).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)] // variants are obvious
+pub enum PackType {
+    Resource,
+    Data,
+}
+
+/// A pack format number or a range of them, as in the `supported_formats` field and overlay entries.
+///
+/// Serializes as a single number or a `[min, max]` array. Deserialization also accepts the `{"min_inclusive": …, "max_inclusive": …}` form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackFormat {
+    /// A single pack format.
+    Single(u32),
+    /// All pack formats between `min` and `max`, inclusive.
+    #[allow(missing_docs)] // fields are obvious
+    Range { min: u32, max: u32 },
+}
+
+/// The first and last release of each pack format, as `(first, last, format)`.
+const RESOURCE_PACK_FORMATS: &[(Version, Version, u32)] = &[
+    (Version::new(1, 6, 1), Version::new(1, 8, 9), 1),
+    (Version::new(1, 9, 0), Version::new(1, 10, 2), 2),
+    (Version::new(1, 11, 0), Version::new(1, 12, 2), 3),
+    (Version::new(1, 13, 0), Version::new(1, 14, 4), 4),
+    (Version::new(1, 15, 0), Version::new(1, 16, 1), 5),
+    (Version::new(1, 16, 2), Version::new(1, 16, 5), 6),
+    (Version::new(1, 17, 0), Version::new(1, 17, 1), 7),
+    (Version::new(1, 18, 0), Version::new(1, 18, 2), 8),
+    (Version::new(1, 19, 0), Version::new(1, 19, 2), 9),
+    (Version::new(1, 19, 3), Version::new(1, 19, 3), 12),
+    (Version::new(1, 19, 4), Version::new(1, 19, 4), 13),
+    (Version::new(1, 20, 0), Version::new(1, 20, 1), 15),
+    (Version::new(1, 20, 2), Version::new(1, 20, 2), 18),
+    (Version::new(1, 20, 3), Version::new(1, 20, 4), 22),
+    (Version::new(1, 20, 5), Version::new(1, 20, 6), 32),
+    (Version::new(1, 21, 0), Version::new(1, 21, 1), 34),
+    (Version::new(1, 21, 2), Version::new(1, 21, 3), 42),
+    (Version::new(1, 21, 4), Version::new(1, 21, 4), 46),
+    (Version::new(1, 21, 5), Version::new(1, 21, 5), 55),
+    (Version::new(1, 21, 6), Version::new(1, 21, 6), 63),
+    (Version::new(1, 21, 7), Version::new(1, 21, 8), 64),
+];
+
+/// Like [`RESOURCE_PACK_FORMATS`], for data packs, which were added in 1.13.
+const DATA_PACK_FORMATS: &[(Version, Version, u32)] = &[
+    (Version::new(1, 13, 0), Version::new(1, 14, 4), 4),
+    (Version::new(1, 15, 0), Version::new(1, 16, 1), 5),
+    (Version::new(1, 16, 2), Version::new(1, 16, 5), 6),
+    (Version::new(1, 17, 0), Version::new(1, 17, 1), 7),
+    (Version::new(1, 18, 0), Version::new(1, 18, 1), 8),
+    (Version::new(1, 18, 2), Version::new(1, 18, 2), 9),
+    (Version::new(1, 19, 0), Version::new(1, 19, 3), 10),
+    (Version::new(1, 19, 4), Version::new(1, 19, 4), 12),
+    (Version::new(1, 20, 0), Version::new(1, 20, 1), 15),
+    (Version::new(1, 20, 2), Version::new(1, 20, 2), 18),
+    (Version::new(1, 20, 3), Version::new(1, 20, 4), 26),
+    (Version::new(1, 20, 5), Version::new(1, 20, 6), 41),
+    (Version::new(1, 21, 0), Version::new(1, 21, 1), 48),
+    (Version::new(1, 21, 2), Version::new(1, 21, 3), 57),
+    (Version::new(1, 21, 4), Version::new(1, 21, 4), 61),
+    (Version::new(1, 21, 5), Version::new(1, 21, 5), 71),
+    (Version::new(1, 21, 6), Version::new(1, 21, 6), 80),
+    (Version::new(1, 21, 7), Version::new(1, 21, 8), 81),
+];
+
+impl PackFormat {
+    /// The pack format used by the given release, or `None` if the release is not known to this crate or doesn't support this type of pack.
+    ///
+    /// Only releases up to 1.21.8 are known, since later releases use versioned pack formats with minor numbers.
+    pub fn for_version(pack_type: PackType, version: &Version) -> Option<u32> {
+        let table = match pack_type {
+            PackType::Resource => RESOURCE_PACK_FORMATS,
+            PackType::Data => DATA_PACK_FORMATS,
+        };
+        table.iter().find(|(first, last, _)| first <= version && version <= last).map(|&(_, _, format)| format)
+    }
+
+    /// The lowest pack format in this range.
+    pub fn min(&self) -> u32 {
+        match *self {
+            PackFormat::Single(format) => format,
+            PackFormat::Range { min, .. } => min,
+        }
+    }
+
+    /// The highest pack format in this range.
+    pub fn max(&self) -> u32 {
+        match *self {
+            PackFormat::Single(format) => format,
+            PackFormat::Range { max, .. } => max,
+        }
+    }
+
+    /// Whether the given pack format is in this range.
+    pub fn contains(&self, format: u32) -> bool {
+        self.min() <= format && format <= self.max()
+    }
+}
+
+impl From<u32> for PackFormat {
+    fn from(format: u32) -> PackFormat {
+        PackFormat::Single(format)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PackFormatRepr {
+    Single(u32),
+    Array([u32; 2]),
+    Object {
+        min_inclusive: u32,
+        max_inclusive: u32,
+    },
+}
+
+impl Serialize for PackFormat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            PackFormat::Single(format) => format.serialize(serializer),
+            PackFormat::Range { min, max } => [min, max].serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PackFormat {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<PackFormat, D::Error> {
+        Ok(match PackFormatRepr::deserialize(deserializer)? {
+            PackFormatRepr::Single(format) => PackFormat::Single(format),
+            PackFormatRepr::Array([min, max]) | PackFormatRepr::Object { min_inclusive: min, max_inclusive: max } => PackFormat::Range { min, max },
+        })
+    }
+}
+
+/// A pack format version with a minor number, as used by the `min_format` and `max_format` fields since 1.21.9.
+///
+/// Serializes as a single number if the minor version is zero, and as a `[major, minor]` array otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)] // fields are obvious
+pub struct PackVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PackVersionRepr {
+    Major(u32),
+    Array(Vec<u32>),
+}
+
+impl Serialize for PackVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.minor == 0 {
+            self.major.serialize(serializer)
+        } else {
+            [self.major, self.minor].serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PackVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<PackVersion, D::Error> {
+        match PackVersionRepr::deserialize(deserializer)? {
+            PackVersionRepr::Major(major) => Ok(PackVersion { major, minor: 0 }),
+            PackVersionRepr::Array(array) => match array[..] {
+                [major] => Ok(PackVersion { major, minor: 0 }),
+                [major, minor] => Ok(PackVersion { major, minor }),
+                _ => Err(serde::de::Error::invalid_length(array.len(), &"1 or 2 version components")),
+            },
+        }
+    }
+}
+
+/// The `pack` section of a `pack.mcmeta` file.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PackSection {
+    /// The text shown below the pack name in the pack selection screen.
+    #[serde(deserialize_with = "chat::deserialize_text_component")]
+    pub description: Chat,
+    /// The pack format this pack was made for. Required before 1.21.9.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pack_format: Option<u32>,
+    /// The range of pack formats this pack is compatible with, since 1.20.2.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supported_formats: Option<PackFormat>,
+    /// The lowest pack format this pack is compatible with, since 1.21.9.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_format: Option<PackVersion>,
+    /// The highest pack format this pack is compatible with, since 1.21.9.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_format: Option<PackVersion>,
+}
+
+impl PackSection {
+    /// Whether the pack declares compatibility with the given (major) pack format, taking all format fields into account.
+    pub fn supports(&self, format: u32) -> bool {
+        if self.min_format.is_some() || self.max_format.is_some() {
+            self.min_format.is_none_or(|min| min.major <= format) && self.max_format.is_none_or(|max| format <= max.major)
+        } else if let Some(supported_formats) = self.supported_formats {
+            supported_formats.contains(format)
+        } else {
+            self.pack_format == Some(format)
+        }
+    }
+}
+
+/// A pattern matching files from lower-priority packs, as in the `filter` section of a `pack.mcmeta` file.
+///
+/// Both fields are regular expressions. A missing field matches everything.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct FilterPattern {
+    /// The pattern for the namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// The pattern for the file path within the namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// The `filter` section of a `pack.mcmeta` file, which hides files from packs below this one.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct PackFilter {
+    /// Files matching any of these patterns are hidden.
+    pub block: Vec<FilterPattern>,
+}
+
+/// An entry of the `overlays` section of a `pack.mcmeta` file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OverlayEntry {
+    /// The pack formats for which the overlay is applied, before 1.21.9.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formats: Option<PackFormat>,
+    /// The lowest pack format for which the overlay is applied, since 1.21.9.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_format: Option<PackVersion>,
+    /// The highest pack format for which the overlay is applied, since 1.21.9.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_format: Option<PackVersion>,
+    /// The directory containing the overlay, relative to the pack root.
+    pub directory: String,
+}
+
+/// The `overlays` section of a `pack.mcmeta` file, which applies subdirectories on top of the pack depending on the game version.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct PackOverlays {
+    /// The overlays, in order of increasing priority.
+    pub entries: Vec<OverlayEntry>,
+}
+
+/// The contents of a `pack.mcmeta` file.
+///
+/// Sections not listed here, e.g. `language` in resource packs, are ignored.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::{
+///     pack::{
+///         PackFormat,
+///         PackMeta,
+///         PackType,
+///     },
+///     version::Version,
+/// };
+///
+/// let meta = serde_json::from_str::<PackMeta>(r#"{
+///     "pack": {
+///         "pack_format": 15,
+///         "supported_formats": [15, 18],
+///         "description": ["", {"text": "Example Pack", "color": "gold"}, "\nby someone"]
+///     },
+///     "overlays": {"entries": [{"formats": {"min_inclusive": 18, "max_inclusive": 18}, "directory": "overlay_18"}]}
+/// }"#)?;
+/// assert!(meta.pack.supports(18));
+/// assert!(!meta.pack.supports(22));
+/// assert_eq!(meta.overlays.unwrap().entries[0].formats, Some(PackFormat::Range { min: 18, max: 18 }));
+/// assert_eq!(PackFormat::for_version(PackType::Data, &"1.20.2".parse::<Version>()?), Some(18));
+///
+/// let meta = serde_json::from_str::<PackMeta>(r#"{"pack": {"pack_format": 6, "description": "§6Faithful"}}"#)?;
+/// assert!(meta.pack.supports(6));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PackMeta {
+    /// Information about the pack.
+    pub pack: PackSection,
+    /// Files to hide from lower-priority packs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<PackFilter>,
+    /// Subdirectories applied depending on the game version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlays: Option<PackOverlays>,
+}
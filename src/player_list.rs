@@ -0,0 +1,56 @@
+//! This module contains types for the player list, also called the tab list, shown while holding the tab key.
+
+use {
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::chat::Chat,
+};
+
+/// The text shown above and below the names in the player list.
+///
+/// An empty text component hides the respective part.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::{
+///     chat::{
+///         Chat,
+///         NamedColor,
+///     },
+///     player_list::PlayerListHeaderFooter,
+/// };
+///
+/// let mut header = Chat::from("Wurstmineberg");
+/// header.color(NamedColor::Gold).bold();
+/// let tab = PlayerListHeaderFooter::new(header, "3 players online");
+/// assert_eq!(tab.to_legacy(false), (format!("§6§lWurstmineberg"), format!("3 players online")));
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct PlayerListHeaderFooter {
+    /// The text above the names.
+    #[serde(default)]
+    pub header: Chat,
+    /// The text below the names.
+    #[serde(default)]
+    pub footer: Chat,
+}
+
+impl PlayerListHeaderFooter {
+    /// Creates a header and footer from the given text.
+    pub fn new(header: impl Into<Chat>, footer: impl Into<Chat>) -> PlayerListHeaderFooter {
+        PlayerListHeaderFooter {
+            header: header.into(),
+            footer: footer.into(),
+        }
+    }
+
+    /// The header and footer as strings with [legacy formatting codes](crate::legacy), for plugin APIs which still set them this way.
+    ///
+    /// See [`Chat::to_legacy`] for the meaning of `hex`.
+    pub fn to_legacy(&self, hex: bool) -> (String, String) {
+        (self.header.to_legacy(hex), self.footer.to_legacy(hex))
+    }
+}
@@ -0,0 +1,521 @@
+//! This module contains types for positions in a world.
+
+use {
+    std::{
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+};
+
+/// The integer coordinates of a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Serialize)]
+#[allow(missing_docs)] // fields are obvious
+pub struct BlockPos {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl BlockPos {
+    /// Creates a block position from its coordinates.
+    pub const fn new(x: i32, y: i32, z: i32) -> BlockPos {
+        BlockPos { x, y, z }
+    }
+
+    /// The position of the center of this block.
+    pub fn center(&self) -> Vec3 {
+        Vec3::new(f64::from(self.x) + 0.5, f64::from(self.y) + 0.5, f64::from(self.z) + 0.5)
+    }
+
+    /// The closest position inside the box spanned by the given corner blocks, inclusive.
+    ///
+    /// The corners may be given in any order, like in the `/fill` command. This takes precedence over [`Ord::clamp`], which compares positions lexicographically.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::position::BlockPos;
+    ///
+    /// let (a, b) = (BlockPos::new(-10, -64, -10), BlockPos::new(10, 64, -20));
+    /// assert_eq!(BlockPos::new(-15, 0, -5).clamp(a, b), BlockPos::new(-10, 0, -10));
+    /// assert_eq!(BlockPos::new(5, -100, -25).clamp(b, a), BlockPos::new(5, -64, -20));
+    /// ```
+    pub fn clamp(self, corner1: BlockPos, corner2: BlockPos) -> BlockPos {
+        let axis = |value: i32, a: i32, b: i32| value.clamp(a.min(b), a.max(b));
+        BlockPos::new(axis(self.x, corner1.x, corner2.x), axis(self.y, corner1.y, corner2.y), axis(self.z, corner1.z, corner2.z))
+    }
+
+    /// The squared Euclidean distance between the two blocks, which can be compared without taking a square root.
+    pub fn distance_squared(&self, other: &BlockPos) -> i64 {
+        let axis = |a: i32, b: i32| (i64::from(a) - i64::from(b)).pow(2);
+        axis(self.x, other.x) + axis(self.y, other.y) + axis(self.z, other.z)
+    }
+
+    /// The Euclidean distance between the two blocks.
+    pub fn distance(&self, other: &BlockPos) -> f64 {
+        (self.distance_squared(other) as f64).sqrt()
+    }
+
+    /// The taxicab distance between the two blocks, i.e. the number of steps along the axes.
+    pub fn manhattan_distance(&self, other: &BlockPos) -> u64 {
+        (i64::from(self.x) - i64::from(other.x)).unsigned_abs()
+        + (i64::from(self.y) - i64::from(other.y)).unsigned_abs()
+        + (i64::from(self.z) - i64::from(other.z)).unsigned_abs()
+    }
+}
+
+impl From<Vec3> for BlockPos {
+    /// Returns the position of the block containing the given point.
+    fn from(pos: Vec3) -> BlockPos {
+        BlockPos::new(pos.x.floor() as i32, pos.y.floor() as i32, pos.z.floor() as i32)
+    }
+}
+
+/// A precise position, e.g. of an entity.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default, Deserialize, Serialize)]
+#[allow(missing_docs)] // fields are obvious
+pub struct Vec3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Vec3 {
+    /// Creates a position from its coordinates.
+    pub const fn new(x: f64, y: f64, z: f64) -> Vec3 {
+        Vec3 { x, y, z }
+    }
+
+    /// The closest position inside the box spanned by the given corners, inclusive.
+    ///
+    /// The corners may be given in any order.
+    pub fn clamp(self, corner1: Vec3, corner2: Vec3) -> Vec3 {
+        let axis = |value: f64, a: f64, b: f64| value.clamp(a.min(b), a.max(b));
+        Vec3::new(axis(self.x, corner1.x, corner2.x), axis(self.y, corner1.y, corner2.y), axis(self.z, corner1.z, corner2.z))
+    }
+
+    /// The squared Euclidean distance between the two positions, which can be compared without taking a square root.
+    pub fn distance_squared(&self, other: &Vec3) -> f64 {
+        (self.x - other.x).powi(2) + (self.y - other.y).powi(2) + (self.z - other.z).powi(2)
+    }
+
+    /// The Euclidean distance between the two positions, as used by the `distance` selector argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::position::Vec3;
+    ///
+    /// assert_eq!(Vec3::new(-1.5, -60.0, -3.0).distance(&Vec3::new(1.5, -64.0, -3.0)), 5.0);
+    /// assert_eq!(Vec3::new(-5.0, 0.0, 0.0).clamp(Vec3::new(-2.5, -1.0, -1.0), Vec3::new(2.5, 1.0, 1.0)), Vec3::new(-2.5, 0.0, 0.0));
+    /// ```
+    pub fn distance(&self, other: &Vec3) -> f64 {
+        self.distance_squared(other).sqrt()
+    }
+}
+
+impl From<BlockPos> for Vec3 {
+    /// Returns the position of the block's lower north-west corner.
+    fn from(pos: BlockPos) -> Vec3 {
+        Vec3::new(f64::from(pos.x), f64::from(pos.y), f64::from(pos.z))
+    }
+}
+
+/// The coordinates of a chunk, a column of 16×16 blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Serialize)]
+#[allow(missing_docs)] // fields are obvious
+pub struct ChunkPos {
+    pub x: i32,
+    pub z: i32,
+}
+
+impl ChunkPos {
+    /// Creates a chunk position from its coordinates.
+    pub const fn new(x: i32, z: i32) -> ChunkPos {
+        ChunkPos { x, z }
+    }
+}
+
+impl From<BlockPos> for ChunkPos {
+    /// Returns the position of the chunk containing the given block.
+    fn from(pos: BlockPos) -> ChunkPos {
+        ChunkPos::new(pos.x >> 4, pos.z >> 4)
+    }
+}
+
+/// The coordinates of a region, a group of 32×32 chunks stored in one Anvil file.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::position::{
+///     BlockPos,
+///     ChunkPos,
+///     RegionPos,
+/// };
+///
+/// let region = RegionPos::from(ChunkPos::new(-1, 31));
+/// assert_eq!(region, RegionPos::new(-1, 0));
+/// assert_eq!(region.file_name(), "r.-1.0.mca");
+/// assert_eq!(RegionPos::from(ChunkPos::new(-32, -33)), RegionPos::new(-1, -2));
+/// assert_eq!(RegionPos::from(ChunkPos::from(BlockPos::new(-513, 64, 511))), RegionPos::new(-2, 0));
+///
+/// assert_eq!(RegionPos::parse_file_name("world/region/r.-1.0.mca"), Some(region));
+/// assert_eq!(RegionPos::parse_file_name(r"C:\world\DIM-1\region\r.3.-12.mca"), Some(RegionPos::new(3, -12)));
+/// assert_eq!(RegionPos::parse_file_name("r.1.2.mcr"), None);
+///
+/// assert_eq!(region.local_chunk_index(&ChunkPos::new(-32, 0)), Some(0));
+/// assert_eq!(region.local_chunk_index(&ChunkPos::new(-1, 31)), Some(1023));
+/// assert_eq!(region.local_chunk_index(&ChunkPos::new(0, 0)), None);
+/// let chunks = region.chunk_range().collect::<Vec<_>>();
+/// assert_eq!(chunks.len(), 1024);
+/// assert_eq!((chunks[0], chunks[1], chunks[32]), (ChunkPos::new(-32, 0), ChunkPos::new(-31, 0), ChunkPos::new(-32, 1)));
+/// assert!(chunks.iter().enumerate().all(|(idx, chunk)| region.local_chunk_index(chunk) == Some(idx)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Serialize)]
+#[allow(missing_docs)] // fields are obvious
+pub struct RegionPos {
+    pub x: i32,
+    pub z: i32,
+}
+
+impl RegionPos {
+    /// The number of chunks along each side of a region.
+    pub const CHUNKS: i32 = 32;
+
+    /// Creates a region position from its coordinates.
+    pub const fn new(x: i32, z: i32) -> RegionPos {
+        RegionPos { x, z }
+    }
+
+    /// The chunk with the lowest coordinates in this region.
+    pub fn min_chunk(&self) -> ChunkPos {
+        ChunkPos::new(self.x * RegionPos::CHUNKS, self.z * RegionPos::CHUNKS)
+    }
+
+    /// All chunks in this region, in the order of the region file header, i.e. by [`local_chunk_index`](RegionPos::local_chunk_index).
+    pub fn chunk_range(&self) -> impl Iterator<Item = ChunkPos> {
+        let min = self.min_chunk();
+        (0..RegionPos::CHUNKS).flat_map(move |z| (0..RegionPos::CHUNKS).map(move |x| ChunkPos::new(min.x + x, min.z + z)))
+    }
+
+    /// The name of the Anvil file storing this region, e.g. `r.-1.0.mca`.
+    pub fn file_name(&self) -> String {
+        format!("r.{}.{}.mca", self.x, self.z)
+    }
+
+    /// Parses the name of an Anvil file as returned by [`file_name`](RegionPos::file_name). Any directories before the file name are ignored.
+    pub fn parse_file_name(path: &str) -> Option<RegionPos> {
+        let file_name = path.rsplit(&['/', '\\'][..]).next()?;
+        let (x, z) = file_name.strip_prefix("r.")?.strip_suffix(".mca")?.split_once('.')?;
+        Some(RegionPos::new(x.parse().ok()?, z.parse().ok()?))
+    }
+
+    /// The index of the given chunk in the header of this region's file, from 0 to 1023, or `None` if the chunk is in a different region.
+    pub fn local_chunk_index(&self, chunk: &ChunkPos) -> Option<usize> {
+        if RegionPos::from(*chunk) != *self { return None }
+        Some((chunk.x.rem_euclid(RegionPos::CHUNKS) + chunk.z.rem_euclid(RegionPos::CHUNKS) * RegionPos::CHUNKS) as usize)
+    }
+}
+
+impl From<ChunkPos> for RegionPos {
+    /// Returns the position of the region containing the given chunk.
+    fn from(pos: ChunkPos) -> RegionPos {
+        RegionPos::new(pos.x >> 5, pos.z >> 5)
+    }
+}
+
+/// The orientation of an entity in degrees, as stored in its `Rotation` tag.
+///
+/// A yaw of 0 faces south (positive z) and increases clockwise when viewed from above, so 90 faces west. A pitch of 0 is horizontal, -90 faces straight up, and 90 straight down.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize, Serialize)]
+#[serde(from = "[f32; 2]", into = "[f32; 2]")]
+#[allow(missing_docs)] // fields are obvious
+pub struct Rotation {
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl Rotation {
+    /// Creates a rotation from its angles in degrees.
+    pub const fn new(yaw: f32, pitch: f32) -> Rotation {
+        Rotation { yaw, pitch }
+    }
+
+    /// The unit vector in the direction this rotation is facing.
+    pub fn forward(&self) -> Vec3 {
+        let (yaw, pitch) = (f64::from(self.yaw).to_radians(), f64::from(self.pitch).to_radians());
+        Vec3::new(-yaw.sin() * pitch.cos(), -pitch.sin(), yaw.cos() * pitch.cos())
+    }
+}
+
+impl From<[f32; 2]> for Rotation {
+    fn from([yaw, pitch]: [f32; 2]) -> Rotation {
+        Rotation { yaw, pitch }
+    }
+}
+
+impl From<Rotation> for [f32; 2] {
+    fn from(Rotation { yaw, pitch }: Rotation) -> [f32; 2] {
+        [yaw, pitch]
+    }
+}
+
+/// One coordinate of a position in a command.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CommandCoord {
+    /// A world coordinate, like `64.5`.
+    Absolute(f64),
+    /// An offset from the coordinate of the command's position, like `~1.5`.
+    Relative(f64),
+    /// An offset along an axis relative to the command's rotation, like `^1.5`. Local coordinates can't be mixed with the other kinds.
+    Local(f64),
+}
+
+impl CommandCoord {
+    fn parse(s: &str, center: bool) -> Result<CommandCoord, CommandPosError> {
+        let number = |s: &str| if s.chars().all(|c| c.is_ascii_digit() || c == '.' || c == '-') {
+            s.parse::<f64>().map_err(|_| CommandPosError::InvalidCoord(s.to_owned()))
+        } else {
+            Err(CommandPosError::InvalidCoord(s.to_owned()))
+        };
+        let offset = |rest: &str| if rest.is_empty() { Ok(0.0) } else { number(rest) };
+        if let Some(rest) = s.strip_prefix('^') {
+            Ok(CommandCoord::Local(offset(rest)?))
+        } else if let Some(rest) = s.strip_prefix('~') {
+            Ok(CommandCoord::Relative(offset(rest)?))
+        } else {
+            let value = number(s)?;
+            // like vanilla, integer coordinates on the horizontal axes refer to the center of the block
+            Ok(CommandCoord::Absolute(if center && !s.contains('.') { value + 0.5 } else { value }))
+        }
+    }
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>, center: bool) -> fmt::Result {
+        let offset = |f: &mut fmt::Formatter<'_>, prefix, value: f64| if value == 0.0 { write!(f, "{}", prefix) } else { write!(f, "{}{}", prefix, value) };
+        match *self {
+            CommandCoord::Absolute(value) if center && value.fract() == 0.0 => write!(f, "{:.1}", value),
+            CommandCoord::Absolute(value) => write!(f, "{}", value),
+            CommandCoord::Relative(value) => offset(f, "~", value),
+            CommandCoord::Local(value) => offset(f, "^", value),
+        }
+    }
+}
+
+/// The error returned when parsing or creating a [`CommandPos`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandPosError {
+    /// The position didn't consist of 3 coordinates separated by spaces.
+    WrongCount(usize),
+    /// A coordinate wasn't a number, optionally prefixed with `~` or `^`.
+    InvalidCoord(String),
+    /// Local coordinates were mixed with world coordinates.
+    MixedLocal,
+}
+
+impl fmt::Display for CommandPosError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandPosError::WrongCount(count) => write!(f, "expected 3 coordinates, got {}", count),
+            CommandPosError::InvalidCoord(coord) => write!(f, "invalid coordinate: {:?}", coord),
+            CommandPosError::MixedLocal => write!(f, "cannot mix world and local coordinates"),
+        }
+    }
+}
+
+impl std::error::Error for CommandPosError {}
+
+/// A position argument of a command, like `~ ~1 ~`, `^ ^ ^5`, or `10 64.5 -3`.
+///
+/// This follows the syntax of positions which allow decimals, as used by `/tp` and `/summon`: integer absolute coordinates on the x and z axes refer to the center of the block, so parsing `10` gives 10.5 but `10.0` gives 10. [`Display`](fmt::Display) writes coordinates such that parsing them gives the same value.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::position::{
+///     CommandCoord,
+///     CommandPos,
+///     Rotation,
+///     Vec3,
+/// };
+///
+/// let pos = "10 64 ~-2.5".parse::<CommandPos>()?;
+/// assert_eq!(pos.coords(), [CommandCoord::Absolute(10.5), CommandCoord::Absolute(64.0), CommandCoord::Relative(-2.5)]);
+/// assert_eq!(pos.to_string(), "10.5 64 ~-2.5");
+/// assert_eq!(CommandPos::new(CommandCoord::Absolute(10.0), CommandCoord::Absolute(64.0), CommandCoord::Relative(0.0))?.to_string(), "10.0 64 ~");
+/// assert!("^ ~ ^".parse::<CommandPos>().is_err());
+///
+/// // one block ahead for various rotations
+/// let forward = "^ ^ ^1".parse::<CommandPos>()?;
+/// let origin = Vec3::new(0.5, 64.0, 0.5);
+/// for (rotation, expected) in [
+///     (Rotation::new(0.0, 0.0), Vec3::new(0.5, 64.0, 1.5)), // south
+///     (Rotation::new(90.0, 0.0), Vec3::new(-0.5, 64.0, 0.5)), // west
+///     (Rotation::new(-180.0, 0.0), Vec3::new(0.5, 64.0, -0.5)), // north
+///     (Rotation::new(0.0, -90.0), Vec3::new(0.5, 65.0, 0.5)), // up
+///     (Rotation::new(-90.0, 45.0), Vec3::new(0.5 + 0.5f64.sqrt(), 64.0 - 0.5f64.sqrt(), 0.5)), // east and down
+/// ] {
+///     assert!(forward.resolve(origin, rotation).distance(&expected) < 1e-6);
+/// }
+/// // left and up
+/// assert!("^1 ^1 ^".parse::<CommandPos>()?.resolve(origin, Rotation::new(0.0, 0.0)).distance(&Vec3::new(1.5, 65.0, 0.5)) < 1e-6);
+/// # Ok::<(), minecraft::position::CommandPosError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CommandPos([CommandCoord; 3]);
+
+impl CommandPos {
+    /// Creates a position from its coordinates, which must either all or none be local.
+    pub fn new(x: CommandCoord, y: CommandCoord, z: CommandCoord) -> Result<CommandPos, CommandPosError> {
+        let coords = [x, y, z];
+        let local = coords.iter().filter(|coord| matches!(coord, CommandCoord::Local(_))).count();
+        if local != 0 && local != 3 { return Err(CommandPosError::MixedLocal) }
+        Ok(CommandPos(coords))
+    }
+
+    /// The position of the command itself, i.e. `~ ~ ~`.
+    pub const fn here() -> CommandPos {
+        CommandPos([CommandCoord::Relative(0.0); 3])
+    }
+
+    /// The coordinates, for local coordinates in the order left, up, forward.
+    pub fn coords(&self) -> [CommandCoord; 3] {
+        self.0
+    }
+
+    /// Whether this position uses local coordinates.
+    pub fn is_local(&self) -> bool {
+        matches!(self.0[0], CommandCoord::Local(_))
+    }
+
+    /// The world position this refers to when the command is executed at `origin` with `rotation`.
+    pub fn resolve(&self, origin: Vec3, rotation: Rotation) -> Vec3 {
+        if let [CommandCoord::Local(left), CommandCoord::Local(up), CommandCoord::Local(forward)] = self.0 {
+            let forward_axis = rotation.forward();
+            let up_axis = Rotation::new(rotation.yaw, rotation.pitch - 90.0).forward();
+            // left = up × forward
+            let left_axis = Vec3::new(
+                up_axis.y * forward_axis.z - up_axis.z * forward_axis.y,
+                up_axis.z * forward_axis.x - up_axis.x * forward_axis.z,
+                up_axis.x * forward_axis.y - up_axis.y * forward_axis.x,
+            );
+            Vec3::new(
+                origin.x + left_axis.x * left + up_axis.x * up + forward_axis.x * forward,
+                origin.y + left_axis.y * left + up_axis.y * up + forward_axis.y * forward,
+                origin.z + left_axis.z * left + up_axis.z * up + forward_axis.z * forward,
+            )
+        } else {
+            let axis = |coord, origin| match coord {
+                CommandCoord::Absolute(value) => value,
+                CommandCoord::Relative(offset) | CommandCoord::Local(offset) => origin + offset,
+            };
+            Vec3::new(axis(self.0[0], origin.x), axis(self.0[1], origin.y), axis(self.0[2], origin.z))
+        }
+    }
+}
+
+impl From<Vec3> for CommandPos {
+    fn from(pos: Vec3) -> CommandPos {
+        CommandPos([CommandCoord::Absolute(pos.x), CommandCoord::Absolute(pos.y), CommandCoord::Absolute(pos.z)])
+    }
+}
+
+impl FromStr for CommandPos {
+    type Err = CommandPosError;
+
+    fn from_str(s: &str) -> Result<CommandPos, CommandPosError> {
+        let parts = s.split_whitespace().collect::<Vec<_>>();
+        if let [x, y, z] = parts[..] {
+            CommandPos::new(CommandCoord::parse(x, true)?, CommandCoord::parse(y, false)?, CommandCoord::parse(z, true)?)
+        } else {
+            Err(CommandPosError::WrongCount(parts.len()))
+        }
+    }
+}
+
+impl fmt::Display for CommandPos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0[0].fmt(f, true)?;
+        write!(f, " ")?;
+        self.0[1].fmt(f, false)?;
+        write!(f, " ")?;
+        self.0[2].fmt(f, true)
+    }
+}
+
+/// One of the six axis-aligned directions, as used in block state properties like `facing`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)] // variants are obvious
+pub enum Direction {
+    Down,
+    Up,
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Direction {
+    /// The lowercase name, e.g. `north`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Direction::Down => "down",
+            Direction::Up => "up",
+            Direction::North => "north",
+            Direction::South => "south",
+            Direction::West => "west",
+            Direction::East => "east",
+        }
+    }
+
+    /// The unit offset in this direction.
+    pub fn offset(&self) -> BlockPos {
+        match self {
+            Direction::Down => BlockPos::new(0, -1, 0),
+            Direction::Up => BlockPos::new(0, 1, 0),
+            Direction::North => BlockPos::new(0, 0, -1),
+            Direction::South => BlockPos::new(0, 0, 1),
+            Direction::West => BlockPos::new(-1, 0, 0),
+            Direction::East => BlockPos::new(1, 0, 0),
+        }
+    }
+}
+
+/// The error returned when parsing a [`Direction`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectionParseError(pub String);
+
+impl fmt::Display for DirectionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown direction: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for DirectionParseError {}
+
+impl FromStr for Direction {
+    type Err = DirectionParseError;
+
+    fn from_str(s: &str) -> Result<Direction, DirectionParseError> {
+        match s {
+            "down" => Ok(Direction::Down),
+            "up" => Ok(Direction::Up),
+            "north" => Ok(Direction::North),
+            "south" => Ok(Direction::South),
+            "west" => Ok(Direction::West),
+            "east" => Ok(Direction::East),
+            _ => Err(DirectionParseError(s.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,449 @@
+//! This module contains types for [recipes](https://minecraft.fandom.com/wiki/Recipe#JSON_format) as defined in data packs.
+
+use {
+    std::{
+        collections::BTreeMap,
+        fmt,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::Error as _,
+        ser::SerializeMap as _,
+    },
+    crate::{
+        chat::Chat,
+        identifier::Identifier,
+        item::ItemStack,
+        ticks::Ticks,
+    },
+};
+
+/// A set of items accepted in a recipe slot.
+///
+/// Serializes as `{"item": …}`, `{"tag": …}`, or a list of alternatives. Deserialization also accepts the string form used since 1.21.2, e.g. `"minecraft:stick"` or `"#minecraft:planks"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Ingredient {
+    /// A single item.
+    Item(Identifier),
+    /// All items in an item tag.
+    Tag(Identifier),
+    /// Any of the given ingredients.
+    Any(Vec<Ingredient>),
+}
+
+impl Ingredient {
+    /// An ingredient matching a single item.
+    pub fn item(id: Identifier) -> Ingredient {
+        Ingredient::Item(id)
+    }
+
+    /// An ingredient matching all items in an item tag.
+    pub fn tag(id: Identifier) -> Ingredient {
+        Ingredient::Tag(id)
+    }
+}
+
+impl Serialize for Ingredient {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Ingredient::Item(id) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("item", id)?;
+                map.end()
+            }
+            Ingredient::Tag(id) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("tag", id)?;
+                map.end()
+            }
+            Ingredient::Any(alternatives) => alternatives.serialize(serializer),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IngredientRepr {
+    Item { item: Identifier },
+    Tag { tag: Identifier },
+    Any(Vec<Ingredient>),
+    String(String),
+}
+
+impl<'de> Deserialize<'de> for Ingredient {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Ingredient, D::Error> {
+        Ok(match IngredientRepr::deserialize(deserializer)? {
+            IngredientRepr::Item { item } => Ingredient::Item(item),
+            IngredientRepr::Tag { tag } => Ingredient::Tag(tag),
+            IngredientRepr::Any(alternatives) => Ingredient::Any(alternatives),
+            IngredientRepr::String(s) => if let Some(tag) = s.strip_prefix('#') {
+                Ingredient::Tag(tag.parse().map_err(D::Error::custom)?)
+            } else {
+                Ingredient::Item(s.parse().map_err(D::Error::custom)?)
+            },
+        })
+    }
+}
+
+/// The recipe book tab of a crafting recipe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)] // variants are obvious
+pub enum CraftingCategory {
+    Building,
+    Redstone,
+    Equipment,
+    #[default]
+    Misc,
+}
+
+/// The recipe book tab of a furnace, blast furnace, smoker, or campfire recipe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)] // variants are obvious
+pub enum CookingCategory {
+    Food,
+    Blocks,
+    #[default]
+    Misc,
+}
+
+/// An error found by [`Recipe::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecipeError {
+    /// A shaped recipe's pattern has no rows, or its rows are empty.
+    EmptyPattern,
+    /// A shaped recipe's pattern has more than 3 rows.
+    TooManyRows(usize),
+    /// A row of a shaped recipe's pattern is longer than 3 characters.
+    RowTooLong(String),
+    /// The rows of a shaped recipe's pattern are not all the same length.
+    UnevenRows,
+    /// A shaped recipe's pattern uses a character which is not in its key.
+    UndefinedKey(char),
+    /// A shaped recipe's key defines a character which is not used in its pattern.
+    UnusedKey(char),
+    /// A shaped recipe's key defines the space character, which is reserved for empty slots.
+    SpaceKey,
+    /// A shapeless recipe has no ingredients or more than 9.
+    IngredientCount(usize),
+}
+
+impl fmt::Display for RecipeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecipeError::EmptyPattern => write!(f, "recipe pattern is empty"),
+            RecipeError::TooManyRows(rows) => write!(f, "recipe pattern has {} rows, but at most 3 are allowed", rows),
+            RecipeError::RowTooLong(row) => write!(f, "recipe pattern row {:?} is longer than 3 characters", row),
+            RecipeError::UnevenRows => write!(f, "recipe pattern rows are not all the same length"),
+            RecipeError::UndefinedKey(c) => write!(f, "recipe pattern uses undefined key {:?}", c),
+            RecipeError::UnusedKey(c) => write!(f, "recipe key {:?} is not used in the pattern", c),
+            RecipeError::SpaceKey => write!(f, "recipe key defines the space character"),
+            RecipeError::IngredientCount(count) => write!(f, "shapeless recipe has {} ingredients, but 1 to 9 are allowed", count),
+        }
+    }
+}
+
+impl std::error::Error for RecipeError {}
+
+fn default_true() -> bool { true }
+fn is_true(b: &bool) -> bool { *b }
+
+/// A crafting recipe where the ingredients must be arranged in a pattern.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::{
+///     identifier::Identifier,
+///     item::ItemStack,
+///     recipe::{
+///         CraftingCategory,
+///         Ingredient,
+///         Recipe,
+///         ShapedRecipe,
+///     },
+/// };
+///
+/// let recipe = Recipe::CraftingShaped(ShapedRecipe::new(ItemStack::new(Identifier::minecraft("ladder")).with_count(3))
+///     .with_pattern(["# #", "###", "# #"])
+///     .with_key('#', Ingredient::item(Identifier::minecraft("stick")))
+///     .with_category(CraftingCategory::Building));
+/// recipe.validate()?;
+/// assert_eq!(
+///     serde_json::to_string(&recipe)?,
+///     r####"{"type":"minecraft:crafting_shaped","category":"building","pattern":["# #","###","# #"],"key":{"#":{"item":"minecraft:stick"}},"result":{"id":"minecraft:ladder","count":3}}"####,
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ShapedRecipe {
+    /// Recipes with the same group are shown together in the recipe book.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// The recipe book tab.
+    #[serde(default)]
+    pub category: CraftingCategory,
+    /// The rows of the crafting grid, each character standing for an entry in `key` or a space for an empty slot.
+    pub pattern: Vec<String>,
+    /// The ingredients for the characters used in `pattern`.
+    pub key: BTreeMap<char, Ingredient>,
+    /// The crafted item stack.
+    pub result: ItemStack,
+    /// Whether a toast is shown when the recipe is unlocked.
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub show_notification: bool,
+}
+
+impl ShapedRecipe {
+    /// Creates a recipe for the given result with an empty pattern.
+    pub fn new(result: ItemStack) -> ShapedRecipe {
+        ShapedRecipe {
+            group: None,
+            category: CraftingCategory::default(),
+            pattern: Vec::default(),
+            key: BTreeMap::default(),
+            show_notification: true,
+            result,
+        }
+    }
+
+    /// Replaces the pattern.
+    pub fn with_pattern(mut self, rows: impl IntoIterator<Item = impl Into<String>>) -> ShapedRecipe {
+        self.pattern = rows.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Defines the ingredient for a character used in the pattern.
+    pub fn with_key(mut self, c: char, ingredient: Ingredient) -> ShapedRecipe {
+        self.key.insert(c, ingredient);
+        self
+    }
+
+    /// Sets the recipe book group.
+    pub fn with_group(mut self, group: impl Into<String>) -> ShapedRecipe {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Sets the recipe book tab.
+    pub fn with_category(mut self, category: CraftingCategory) -> ShapedRecipe {
+        self.category = category;
+        self
+    }
+
+    /// Checks that the pattern is at most 3×3, its rows have the same length, and it uses exactly the characters defined in the key.
+    pub fn validate(&self) -> Result<(), RecipeError> {
+        if self.pattern.len() > 3 { return Err(RecipeError::TooManyRows(self.pattern.len())) }
+        let width = self.pattern.first().map_or(0, |row| row.chars().count());
+        if width == 0 { return Err(RecipeError::EmptyPattern) }
+        for row in &self.pattern {
+            let len = row.chars().count();
+            if len > 3 { return Err(RecipeError::RowTooLong(row.clone())) }
+            if len != width { return Err(RecipeError::UnevenRows) }
+        }
+        if self.key.contains_key(&' ') { return Err(RecipeError::SpaceKey) }
+        if let Some(c) = self.pattern.iter().flat_map(|row| row.chars()).find(|&c| c != ' ' && !self.key.contains_key(&c)) {
+            return Err(RecipeError::UndefinedKey(c))
+        }
+        if let Some(&c) = self.key.keys().find(|&&c| !self.pattern.iter().any(|row| row.contains(c))) {
+            return Err(RecipeError::UnusedKey(c))
+        }
+        Ok(())
+    }
+}
+
+/// A crafting recipe where the ingredients can be placed anywhere in the grid.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ShapelessRecipe {
+    /// Recipes with the same group are shown together in the recipe book.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// The recipe book tab.
+    #[serde(default)]
+    pub category: CraftingCategory,
+    /// The ingredients, one per grid slot.
+    pub ingredients: Vec<Ingredient>,
+    /// The crafted item stack.
+    pub result: ItemStack,
+}
+
+impl ShapelessRecipe {
+    /// Creates a recipe for the given result with no ingredients.
+    pub fn new(result: ItemStack) -> ShapelessRecipe {
+        ShapelessRecipe {
+            group: None,
+            category: CraftingCategory::default(),
+            ingredients: Vec::default(),
+            result,
+        }
+    }
+
+    /// Adds an ingredient.
+    pub fn with_ingredient(mut self, ingredient: Ingredient) -> ShapelessRecipe {
+        self.ingredients.push(ingredient);
+        self
+    }
+
+    /// Sets the recipe book group.
+    pub fn with_group(mut self, group: impl Into<String>) -> ShapelessRecipe {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Sets the recipe book tab.
+    pub fn with_category(mut self, category: CraftingCategory) -> ShapelessRecipe {
+        self.category = category;
+        self
+    }
+
+    /// Checks that there are between 1 and 9 ingredients.
+    pub fn validate(&self) -> Result<(), RecipeError> {
+        if (1..=9).contains(&self.ingredients.len()) { Ok(()) } else { Err(RecipeError::IngredientCount(self.ingredients.len())) }
+    }
+}
+
+/// A recipe for a furnace, blast furnace, smoker, or campfire.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CookingRecipe {
+    /// Recipes with the same group are shown together in the recipe book.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// The recipe book tab.
+    #[serde(default)]
+    pub category: CookingCategory,
+    /// The item which is cooked.
+    pub ingredient: Ingredient,
+    /// The cooked item stack.
+    pub result: ItemStack,
+    /// The experience awarded when taking the result out of the furnace.
+    #[serde(default)]
+    pub experience: f32,
+    /// How long cooking takes. If absent, this is 200 ticks for smelting and 100 ticks otherwise.
+    #[serde(rename = "cookingtime", default, skip_serializing_if = "Option::is_none")]
+    pub cooking_time: Option<Ticks>,
+}
+
+impl CookingRecipe {
+    /// Creates a recipe with no experience and the default cooking time.
+    pub fn new(ingredient: Ingredient, result: ItemStack) -> CookingRecipe {
+        CookingRecipe {
+            group: None,
+            category: CookingCategory::default(),
+            experience: 0.0,
+            cooking_time: None,
+            ingredient, result,
+        }
+    }
+
+    /// Sets the experience awarded.
+    pub fn with_experience(mut self, experience: f32) -> CookingRecipe {
+        self.experience = experience;
+        self
+    }
+
+    /// Sets the cooking time.
+    pub fn with_cooking_time(mut self, cooking_time: Ticks) -> CookingRecipe {
+        self.cooking_time = Some(cooking_time);
+        self
+    }
+
+    /// Sets the recipe book group.
+    pub fn with_group(mut self, group: impl Into<String>) -> CookingRecipe {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Sets the recipe book tab.
+    pub fn with_category(mut self, category: CookingCategory) -> CookingRecipe {
+        self.category = category;
+        self
+    }
+}
+
+/// A stonecutter recipe.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct StonecuttingRecipe {
+    /// Recipes with the same group are shown together in the recipe book.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// The item which is cut.
+    pub ingredient: Ingredient,
+    /// The resulting item stack.
+    pub result: ItemStack,
+}
+
+/// A smithing table recipe which changes the item type while keeping its data, e.g. for netherite upgrades.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SmithingTransformRecipe {
+    /// The smithing template.
+    pub template: Ingredient,
+    /// The item which is upgraded.
+    pub base: Ingredient,
+    /// The material added to the item.
+    pub addition: Ingredient,
+    /// The resulting item, which receives the data of the base item.
+    pub result: ItemStack,
+}
+
+/// A data pack recipe, tagged with its `type`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type")]
+#[allow(missing_docs)] // variants are obvious
+pub enum Recipe {
+    #[serde(rename = "minecraft:crafting_shaped", alias = "crafting_shaped")]
+    CraftingShaped(ShapedRecipe),
+    #[serde(rename = "minecraft:crafting_shapeless", alias = "crafting_shapeless")]
+    CraftingShapeless(ShapelessRecipe),
+    #[serde(rename = "minecraft:smelting", alias = "smelting")]
+    Smelting(CookingRecipe),
+    #[serde(rename = "minecraft:blasting", alias = "blasting")]
+    Blasting(CookingRecipe),
+    #[serde(rename = "minecraft:smoking", alias = "smoking")]
+    Smoking(CookingRecipe),
+    #[serde(rename = "minecraft:campfire_cooking", alias = "campfire_cooking")]
+    CampfireCooking(CookingRecipe),
+    #[serde(rename = "minecraft:stonecutting", alias = "stonecutting")]
+    Stonecutting(StonecuttingRecipe),
+    #[serde(rename = "minecraft:smithing_transform", alias = "smithing_transform")]
+    SmithingTransform(SmithingTransformRecipe),
+}
+
+impl Recipe {
+    /// The recipe's item stack.
+    pub fn result(&self) -> &ItemStack {
+        match self {
+            Recipe::CraftingShaped(recipe) => &recipe.result,
+            Recipe::CraftingShapeless(recipe) => &recipe.result,
+            Recipe::Smelting(recipe) | Recipe::Blasting(recipe) | Recipe::Smoking(recipe) | Recipe::CampfireCooking(recipe) => &recipe.result,
+            Recipe::Stonecutting(recipe) => &recipe.result,
+            Recipe::SmithingTransform(recipe) => &recipe.result,
+        }
+    }
+
+    /// The name of the recipe's result as the client shows it, e.g. `Diamond Sword` or `Ladder ×3`. See [`ItemStack::to_chat`].
+    pub fn result_chat(&self) -> Chat {
+        self.result().to_chat()
+    }
+
+    /// How long a cooking recipe takes, taking the default for its type into account, or `None` for other recipes.
+    pub fn cooking_time(&self) -> Option<Ticks> {
+        match self {
+            Recipe::Smelting(recipe) => Some(recipe.cooking_time.unwrap_or(Ticks(200))),
+            Recipe::Blasting(recipe) | Recipe::Smoking(recipe) | Recipe::CampfireCooking(recipe) => Some(recipe.cooking_time.unwrap_or(Ticks(100))),
+            _ => None,
+        }
+    }
+
+    /// Checks the constraints the game enforces when loading the recipe which are not expressed in the types.
+    pub fn validate(&self) -> Result<(), RecipeError> {
+        match self {
+            Recipe::CraftingShaped(recipe) => recipe.validate(),
+            Recipe::CraftingShapeless(recipe) => recipe.validate(),
+            _ => Ok(()),
+        }
+    }
+}
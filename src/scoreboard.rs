@@ -0,0 +1,457 @@
+//! This module contains types for the [scoreboard](https://minecraft.fandom.com/wiki/Scoreboard) and [teams](https://minecraft.fandom.com/wiki/Commands/team), as stored in `data/scoreboard.dat` and managed by the `/scoreboard` command.
+
+use {
+    std::{
+        collections::BTreeSet,
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::Error as _,
+    },
+    crate::{
+        chat::{
+            self,
+            Chat,
+            Color,
+            NamedColor,
+        },
+        identifier::Identifier,
+        util::nbt_bool,
+    },
+};
+
+/// What causes the scores of an objective to change.
+///
+/// The [`FromStr`] and [`Display`](fmt::Display) impls use the syntax of the `/scoreboard objectives add` command, e.g. `deathCount`, `teamkill.red`, or `minecraft.used:minecraft.carrot_on_a_stick`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Criterion {
+    /// Only changed by commands.
+    Dummy,
+    /// Only changed by commands, but players can change their own score using `/trigger` if enabled.
+    Trigger,
+    /// Incremented when the player dies.
+    DeathCount,
+    /// Incremented when the player kills another player.
+    PlayerKillCount,
+    /// Incremented when the player kills a player or mob.
+    TotalKillCount,
+    /// The player's health, in half-hearts.
+    Health,
+    /// The player's total experience points.
+    Xp,
+    /// The player's experience level.
+    Level,
+    /// The player's food level, in half-drumsticks.
+    Food,
+    /// The player's remaining air, in ticks.
+    Air,
+    /// The player's armor points.
+    Armor,
+    /// Incremented when the player kills a member of a team with the given color.
+    TeamKill(NamedColor),
+    /// Incremented when the player is killed by a member of a team with the given color.
+    KilledByTeam(NamedColor),
+    /// A statistic, e.g. `minecraft.custom:minecraft.jump`.
+    Stat {
+        /// The statistic type, e.g. `minecraft:custom` or `minecraft:mined`.
+        stat_type: Identifier,
+        /// The statistic within that type, e.g. `minecraft:jump` or `minecraft:stone`.
+        stat: Identifier,
+    },
+}
+
+impl Criterion {
+    fn simple_name(&self) -> Option<&'static str> {
+        Some(match self {
+            Criterion::Dummy => "dummy",
+            Criterion::Trigger => "trigger",
+            Criterion::DeathCount => "deathCount",
+            Criterion::PlayerKillCount => "playerKillCount",
+            Criterion::TotalKillCount => "totalKillCount",
+            Criterion::Health => "health",
+            Criterion::Xp => "xp",
+            Criterion::Level => "level",
+            Criterion::Food => "food",
+            Criterion::Air => "air",
+            Criterion::Armor => "armor",
+            Criterion::TeamKill(_) | Criterion::KilledByTeam(_) | Criterion::Stat { .. } => return None,
+        })
+    }
+
+    /// Whether scores for this criterion can only be changed by commands, i.e. `dummy` or `trigger`.
+    pub fn is_writable(&self) -> bool {
+        matches!(self, Criterion::Dummy | Criterion::Trigger)
+    }
+}
+
+/// The error returned when parsing a [`Criterion`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CriterionParseError(pub String);
+
+impl fmt::Display for CriterionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown scoreboard criterion: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for CriterionParseError {}
+
+/// Parses an identifier written with a `.` instead of a `:`, as in statistic criteria.
+fn parse_dotted_identifier(s: &str) -> Option<Identifier> {
+    let (namespace, path) = s.split_once('.')?;
+    Identifier::new(namespace, path).ok()
+}
+
+fn named_color(name: &str) -> Option<NamedColor> {
+    NamedColor::ALL.iter().find(|color| color.name() == name).copied()
+}
+
+impl FromStr for Criterion {
+    type Err = CriterionParseError;
+
+    fn from_str(s: &str) -> Result<Criterion, CriterionParseError> {
+        let error = || CriterionParseError(s.to_owned());
+        if let Some((stat_type, stat)) = s.split_once(':') {
+            return Ok(Criterion::Stat {
+                stat_type: parse_dotted_identifier(stat_type).ok_or_else(error)?,
+                stat: parse_dotted_identifier(stat).ok_or_else(error)?,
+            })
+        }
+        if let Some(color) = s.strip_prefix("teamkill.") {
+            return named_color(color).map(Criterion::TeamKill).ok_or_else(error)
+        }
+        if let Some(color) = s.strip_prefix("killedByTeam.") {
+            return named_color(color).map(Criterion::KilledByTeam).ok_or_else(error)
+        }
+        [
+            Criterion::Dummy, Criterion::Trigger, Criterion::DeathCount, Criterion::PlayerKillCount, Criterion::TotalKillCount,
+            Criterion::Health, Criterion::Xp, Criterion::Level, Criterion::Food, Criterion::Air, Criterion::Armor,
+        ].iter().find(|criterion| criterion.simple_name() == Some(s)).cloned().ok_or_else(error)
+    }
+}
+
+impl fmt::Display for Criterion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Criterion::TeamKill(color) => write!(f, "teamkill.{}", color.name()),
+            Criterion::KilledByTeam(color) => write!(f, "killedByTeam.{}", color.name()),
+            Criterion::Stat { stat_type, stat } => write!(f, "{}.{}:{}.{}", stat_type.namespace(), stat_type.path(), stat.namespace(), stat.path()),
+            _ => write!(f, "{}", self.simple_name().expect("checked for compound criteria above")),
+        }
+    }
+}
+
+impl Serialize for Criterion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Criterion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Criterion, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+}
+
+/// How the scores of an objective are shown in the player list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderType {
+    /// As numbers.
+    #[default]
+    Integer,
+    /// As hearts, like health.
+    Hearts,
+}
+
+impl RenderType {
+    /// The lowercase name used in commands and NBT, e.g. `hearts`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            RenderType::Integer => "integer",
+            RenderType::Hearts => "hearts",
+        }
+    }
+}
+
+/// A scoreboard objective, as stored in the `Objectives` list of `scoreboard.dat`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Objective {
+    /// The internal name, used in commands.
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// What causes scores to change.
+    #[serde(rename = "CriteriaName")]
+    pub criterion: Criterion,
+    /// The name shown in the sidebar and elsewhere.
+    #[serde(rename = "DisplayName", with = "chat::json_string")]
+    pub display_name: Chat,
+    /// How scores are shown in the player list.
+    #[serde(rename = "RenderType", default)]
+    pub render_type: RenderType,
+}
+
+impl Objective {
+    /// Creates an objective whose display name is its internal name, with scores rendered as numbers.
+    pub fn new(name: impl Into<String>, criterion: Criterion) -> Objective {
+        let name = name.into();
+        Objective {
+            display_name: Chat::from(name.clone()),
+            render_type: RenderType::default(),
+            name, criterion,
+        }
+    }
+
+    /// The `/scoreboard objectives` commands (without leading slashes) which create this objective on a server.
+    pub fn to_commands(&self) -> Vec<String> {
+        let mut commands = vec![match self.display_name.to_json() {
+            Ok(display_name) => format!("scoreboard objectives add {} {} {}", self.name, self.criterion, display_name),
+            Err(_) => format!("scoreboard objectives add {} {}", self.name, self.criterion),
+        }];
+        if self.render_type != RenderType::default() {
+            commands.push(format!("scoreboard objectives modify {} rendertype {}", self.name, self.render_type.name()));
+        }
+        commands
+    }
+}
+
+/// A place where the scores of an objective can be displayed.
+///
+/// Serializes as the name used by the `/scoreboard objectives setdisplay` command since 1.20.2, e.g. `below_name` or `sidebar.team.red`. Deserialization also accepts the older `belowName`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisplaySlot {
+    /// The player list.
+    List,
+    /// The sidebar on the right side of the screen.
+    Sidebar,
+    /// Below the names of players.
+    BelowName,
+    /// The sidebar, only shown to members of teams with the given color.
+    SidebarTeam(NamedColor),
+}
+
+impl DisplaySlot {
+    /// The numeric ID used in the Display Objective packet and, before 1.20.2, in `scoreboard.dat`.
+    pub fn id(&self) -> u8 {
+        match self {
+            DisplaySlot::List => 0,
+            DisplaySlot::Sidebar => 1,
+            DisplaySlot::BelowName => 2,
+            DisplaySlot::SidebarTeam(color) => 3 + color.index(),
+        }
+    }
+
+    /// Looks up a display slot by its numeric ID.
+    pub fn from_id(id: u8) -> Option<DisplaySlot> {
+        match id {
+            0 => Some(DisplaySlot::List),
+            1 => Some(DisplaySlot::Sidebar),
+            2 => Some(DisplaySlot::BelowName),
+            _ => NamedColor::from_index(id - 3).map(DisplaySlot::SidebarTeam),
+        }
+    }
+
+    /// The `/scoreboard objectives setdisplay` command (without leading slash) which shows the given objective in this slot, or clears it if `None`.
+    pub fn to_command(&self, objective: Option<&str>) -> String {
+        match objective {
+            Some(objective) => format!("scoreboard objectives setdisplay {} {}", self, objective),
+            None => format!("scoreboard objectives setdisplay {}", self),
+        }
+    }
+}
+
+/// The error returned when parsing a [`DisplaySlot`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplaySlotParseError(pub String);
+
+impl fmt::Display for DisplaySlotParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown scoreboard display slot: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for DisplaySlotParseError {}
+
+impl FromStr for DisplaySlot {
+    type Err = DisplaySlotParseError;
+
+    fn from_str(s: &str) -> Result<DisplaySlot, DisplaySlotParseError> {
+        match s {
+            "list" => Ok(DisplaySlot::List),
+            "sidebar" => Ok(DisplaySlot::Sidebar),
+            "below_name" | "belowName" => Ok(DisplaySlot::BelowName),
+            _ => s.strip_prefix("sidebar.team.")
+                .and_then(named_color)
+                .map(DisplaySlot::SidebarTeam)
+                .ok_or_else(|| DisplaySlotParseError(s.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for DisplaySlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DisplaySlot::List => write!(f, "list"),
+            DisplaySlot::Sidebar => write!(f, "sidebar"),
+            DisplaySlot::BelowName => write!(f, "below_name"),
+            DisplaySlot::SidebarTeam(color) => write!(f, "sidebar.team.{}", color.name()),
+        }
+    }
+}
+
+impl Serialize for DisplaySlot {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DisplaySlot {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<DisplaySlot, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+}
+
+/// Who can see the name tags or death messages of a team's members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Visibility {
+    /// Visible to everyone.
+    #[default]
+    Always,
+    /// Visible to no one.
+    Never,
+    /// Only visible to members of the same team.
+    HideForOtherTeams,
+    /// Only visible to players not on the team.
+    HideForOwnTeam,
+}
+
+impl Visibility {
+    /// The name used in commands and NBT, e.g. `hideForOtherTeams`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Visibility::Always => "always",
+            Visibility::Never => "never",
+            Visibility::HideForOtherTeams => "hideForOtherTeams",
+            Visibility::HideForOwnTeam => "hideForOwnTeam",
+        }
+    }
+}
+
+/// Which entities a team's members collide with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CollisionRule {
+    /// Collide with all entities.
+    #[default]
+    Always,
+    /// Don't collide with any entities.
+    Never,
+    /// Only collide with members of the same team.
+    PushOtherTeams,
+    /// Only collide with entities not on the team.
+    PushOwnTeam,
+}
+
+impl CollisionRule {
+    /// The name used in commands and NBT, e.g. `pushOtherTeams`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CollisionRule::Always => "always",
+            CollisionRule::Never => "never",
+            CollisionRule::PushOtherTeams => "pushOtherTeams",
+            CollisionRule::PushOwnTeam => "pushOwnTeam",
+        }
+    }
+}
+
+fn default_team_color() -> Color { Color::Reset }
+fn default_true() -> bool { true }
+
+fn deserialize_team_color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    match Color::deserialize(deserializer)? {
+        Color::Hex(_) => Err(D::Error::custom("team colors must be named colors")),
+        color => Ok(color),
+    }
+}
+
+/// A scoreboard team, as stored in the `Teams` list of `scoreboard.dat`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Team {
+    /// The internal name, used in commands.
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// The name of the team shown in the sidebar and in commands.
+    #[serde(rename = "DisplayName", with = "chat::json_string")]
+    pub display_name: Chat,
+    /// Shown before the names of members.
+    #[serde(rename = "TeamPrefix", with = "chat::json_string", default)]
+    pub prefix: Chat,
+    /// Shown after the names of members.
+    #[serde(rename = "TeamSuffix", with = "chat::json_string", default)]
+    pub suffix: Chat,
+    /// The color of members' names, which is always a named color or [`Color::Reset`].
+    #[serde(rename = "TeamColor", default = "default_team_color", deserialize_with = "deserialize_team_color")]
+    pub color: Color,
+    /// Whether members can hurt each other.
+    #[serde(rename = "AllowFriendlyFire", default = "default_true", with = "nbt_bool")]
+    pub friendly_fire: bool,
+    /// Whether members can see invisible members of the same team.
+    #[serde(rename = "SeeFriendlyInvisibles", default = "default_true", with = "nbt_bool")]
+    pub see_friendly_invisibles: bool,
+    /// Who can see members' name tags.
+    #[serde(rename = "NameTagVisibility", default)]
+    pub name_tag_visibility: Visibility,
+    /// Who sees chat messages when members die.
+    #[serde(rename = "DeathMessageVisibility", default)]
+    pub death_message_visibility: Visibility,
+    /// Which entities members collide with.
+    #[serde(rename = "CollisionRule", default)]
+    pub collision_rule: CollisionRule,
+    /// The names of players and UUIDs of other entities on the team.
+    #[serde(rename = "Players", default)]
+    pub members: BTreeSet<String>,
+}
+
+impl Team {
+    /// Creates a team with no members and the default settings, whose display name is its internal name.
+    pub fn new(name: impl Into<String>) -> Team {
+        let name = name.into();
+        Team {
+            display_name: Chat::from(name.clone()),
+            prefix: Chat::default(),
+            suffix: Chat::default(),
+            color: Color::Reset,
+            friendly_fire: true,
+            see_friendly_invisibles: true,
+            name_tag_visibility: Visibility::default(),
+            death_message_visibility: Visibility::default(),
+            collision_rule: CollisionRule::default(),
+            members: BTreeSet::default(),
+            name,
+        }
+    }
+
+    /// The `/team` commands (without leading slashes) which create this team with its settings and members on a server.
+    pub fn to_commands(&self) -> Vec<String> {
+        let json = |chat: &Chat| chat.to_json().unwrap_or_else(|_| String::from("\"\""));
+        let mut commands = vec![
+            format!("team add {} {}", self.name, json(&self.display_name)),
+            format!("team modify {} color {}", self.name, self.color),
+            format!("team modify {} prefix {}", self.name, json(&self.prefix)),
+            format!("team modify {} suffix {}", self.name, json(&self.suffix)),
+            format!("team modify {} friendlyFire {}", self.name, self.friendly_fire),
+            format!("team modify {} seeFriendlyInvisibles {}", self.name, self.see_friendly_invisibles),
+            format!("team modify {} nametagVisibility {}", self.name, self.name_tag_visibility.name()),
+            format!("team modify {} deathMessageVisibility {}", self.name, self.death_message_visibility.name()),
+            format!("team modify {} collisionRule {}", self.name, self.collision_rule.name()),
+        ];
+        commands.extend(self.members.iter().map(|member| format!("team join {} {}", self.name, member)));
+        commands
+    }
+}
@@ -0,0 +1,631 @@
+//! This module contains the [`TargetSelector`] type, representing [target selectors](https://minecraft.fandom.com/wiki/Target_selectors) like `@a[distance=..10]` in commands.
+
+use {
+    std::{
+        fmt,
+        str::FromStr,
+    },
+    crate::game_mode::GameMode,
+};
+
+/// An error that can occur when parsing or [validating](TargetSelector::validate) a target selector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectorError {
+    /// The selector doesn't start with `@` followed by a known variable.
+    UnknownVariable(String),
+    /// The selector is not syntactically valid.
+    Syntax {
+        /// The byte offset in the input where the error was found.
+        position: usize,
+        /// What was expected or found.
+        message: &'static str,
+    },
+    /// The value of an argument couldn't be parsed for its type.
+    InvalidValue {
+        /// The argument key, e.g. `distance`.
+        key: String,
+        /// The unparseable value.
+        value: String,
+    },
+    /// The argument can't be used with this variable, e.g. `limit` on `@s`.
+    NotApplicable(&'static str),
+    /// The argument is given more than once, but may only be given once (or once without negation).
+    Duplicate(&'static str),
+    /// The value of the argument is out of range, e.g. a negative `distance` or a `limit` of 0.
+    OutOfRange(&'static str),
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelectorError::UnknownVariable(s) => write!(f, "unknown target selector variable: {:?}", s),
+            SelectorError::Syntax { position, message } => write!(f, "invalid target selector at position {}: {}", position, message),
+            SelectorError::InvalidValue { key, value } => write!(f, "invalid value for target selector argument {}: {:?}", key, value),
+            SelectorError::NotApplicable(key) => write!(f, "target selector argument {} isn't applicable here", key),
+            SelectorError::Duplicate(key) => write!(f, "target selector argument {} is given more than once", key),
+            SelectorError::OutOfRange(key) => write!(f, "target selector argument {} is out of range", key),
+        }
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+/// The variable of a target selector, which determines the default set of targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelectorVariable {
+    /// `@p`, the nearest player.
+    NearestPlayer,
+    /// `@r`, a random player.
+    RandomPlayer,
+    /// `@a`, all players.
+    AllPlayers,
+    /// `@e`, all entities.
+    AllEntities,
+    /// `@s`, the entity executing the command.
+    Executor,
+    /// `@n`, the nearest entity. Added in 1.21.
+    NearestEntity,
+}
+
+impl SelectorVariable {
+    /// All selector variables.
+    pub const ALL: [SelectorVariable; 6] = [
+        SelectorVariable::NearestPlayer,
+        SelectorVariable::RandomPlayer,
+        SelectorVariable::AllPlayers,
+        SelectorVariable::AllEntities,
+        SelectorVariable::Executor,
+        SelectorVariable::NearestEntity,
+    ];
+
+    /// The character after the `@`.
+    pub fn char(&self) -> char {
+        match self {
+            SelectorVariable::NearestPlayer => 'p',
+            SelectorVariable::RandomPlayer => 'r',
+            SelectorVariable::AllPlayers => 'a',
+            SelectorVariable::AllEntities => 'e',
+            SelectorVariable::Executor => 's',
+            SelectorVariable::NearestEntity => 'n',
+        }
+    }
+
+    /// Whether this variable only selects players, so the `type` argument can't be used.
+    pub fn is_players_only(&self) -> bool {
+        matches!(self, SelectorVariable::NearestPlayer | SelectorVariable::RandomPlayer | SelectorVariable::AllPlayers)
+    }
+}
+
+/// A range of numbers as used in selector arguments and predicates, written like `1..5`, `..10`, `3..`, or `7`.
+///
+/// Both ends are inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MinMaxBounds<T> {
+    /// The lower bound, if any.
+    pub min: Option<T>,
+    /// The upper bound, if any.
+    pub max: Option<T>,
+}
+
+impl<T: Copy> MinMaxBounds<T> {
+    /// A range containing only the given value.
+    pub fn exactly(value: T) -> MinMaxBounds<T> {
+        MinMaxBounds { min: Some(value), max: Some(value) }
+    }
+
+    /// A range with only a lower bound.
+    pub fn at_least(min: T) -> MinMaxBounds<T> {
+        MinMaxBounds { min: Some(min), max: None }
+    }
+
+    /// A range with only an upper bound.
+    pub fn at_most(max: T) -> MinMaxBounds<T> {
+        MinMaxBounds { min: None, max: Some(max) }
+    }
+}
+
+impl<T: PartialOrd> MinMaxBounds<T> {
+    /// Whether the value is within the range.
+    pub fn contains(&self, value: &T) -> bool {
+        self.min.as_ref().is_none_or(|min| min <= value) && self.max.as_ref().is_none_or(|max| value <= max)
+    }
+}
+
+/// The error returned when parsing a [`MinMaxBounds`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinMaxBoundsParseError(pub String);
+
+impl fmt::Display for MinMaxBoundsParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid range: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for MinMaxBoundsParseError {}
+
+impl<T: FromStr + PartialOrd + Copy> FromStr for MinMaxBounds<T> {
+    type Err = MinMaxBoundsParseError;
+
+    fn from_str(s: &str) -> Result<MinMaxBounds<T>, MinMaxBoundsParseError> {
+        let err = || MinMaxBoundsParseError(s.to_owned());
+        let bound = |part: &str| if part.is_empty() { Ok(None) } else { part.parse().map(Some).map_err(|_| err()) };
+        let bounds = match s.split_once("..") {
+            Some((min, max)) => MinMaxBounds { min: bound(min)?, max: bound(max)? },
+            None => MinMaxBounds::exactly(bound(s)?.ok_or_else(err)?),
+        };
+        match (bounds.min, bounds.max) {
+            (None, None) => Err(err()),
+            (Some(min), Some(max)) if min > max => Err(err()),
+            _ => Ok(bounds),
+        }
+    }
+}
+
+impl<T: fmt::Display + PartialEq> fmt::Display for MinMaxBounds<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.min, &self.max) {
+            (Some(min), Some(max)) if min == max => min.fmt(f),
+            (min, max) => {
+                if let Some(min) = min { min.fmt(f)? }
+                write!(f, "..")?;
+                if let Some(max) = max { max.fmt(f)? }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The order in which entities are selected, as given by the `sort` argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)] // variants are obvious
+pub enum SortOrder {
+    Nearest,
+    Furthest,
+    Random,
+    Arbitrary,
+}
+
+impl SortOrder {
+    /// The lowercase name used in selectors, e.g. `nearest`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SortOrder::Nearest => "nearest",
+            SortOrder::Furthest => "furthest",
+            SortOrder::Random => "random",
+            SortOrder::Arbitrary => "arbitrary",
+        }
+    }
+}
+
+impl FromStr for SortOrder {
+    type Err = SelectorError;
+
+    fn from_str(s: &str) -> Result<SortOrder, SelectorError> {
+        match s {
+            "nearest" => Ok(SortOrder::Nearest),
+            "furthest" => Ok(SortOrder::Furthest),
+            "random" => Ok(SortOrder::Random),
+            "arbitrary" => Ok(SortOrder::Arbitrary),
+            _ => Err(SelectorError::InvalidValue { key: "sort".to_owned(), value: s.to_owned() }),
+        }
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An argument of a target selector, i.e. one `key=value` pair in the square brackets.
+///
+/// Arguments which can be negated with `!` have a `negated` field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectorArgument {
+    /// `x`, the x coordinate to measure from.
+    X(f64),
+    /// `y`, the y coordinate to measure from.
+    Y(f64),
+    /// `z`, the z coordinate to measure from.
+    Z(f64),
+    /// `dx`, the size of the selection box along the x axis.
+    Dx(f64),
+    /// `dy`, the size of the selection box along the y axis.
+    Dy(f64),
+    /// `dz`, the size of the selection box along the z axis.
+    Dz(f64),
+    /// `distance`, the range of distances from the position.
+    Distance(MinMaxBounds<f64>),
+    /// `level`, the range of experience levels.
+    Level(MinMaxBounds<i32>),
+    /// `x_rotation`, the range of vertical rotations in degrees.
+    XRotation(MinMaxBounds<f64>),
+    /// `y_rotation`, the range of horizontal rotations in degrees.
+    YRotation(MinMaxBounds<f64>),
+    /// `gamemode`, the game mode of players.
+    Gamemode {
+        #[allow(missing_docs)]
+        negated: bool,
+        #[allow(missing_docs)]
+        mode: GameMode,
+    },
+    /// `name`, the name of players or the custom name of entities.
+    Name {
+        #[allow(missing_docs)]
+        negated: bool,
+        #[allow(missing_docs)]
+        name: String,
+    },
+    /// `type`, an entity type like `minecraft:zombie` or an entity type tag like `#minecraft:skeletons`.
+    Type {
+        #[allow(missing_docs)]
+        negated: bool,
+        #[allow(missing_docs)]
+        entity_type: String,
+    },
+    /// `tag`, a scoreboard tag. An empty tag matches entities without any tags.
+    Tag {
+        #[allow(missing_docs)]
+        negated: bool,
+        #[allow(missing_docs)]
+        tag: String,
+    },
+    /// `team`, a team name. An empty name matches entities on any team.
+    Team {
+        #[allow(missing_docs)]
+        negated: bool,
+        #[allow(missing_docs)]
+        team: String,
+    },
+    /// `scores`, ranges of scores by objective name.
+    Scores(Vec<(String, MinMaxBounds<i32>)>),
+    /// `limit`, the maximum number of entities to select.
+    Limit(i32),
+    /// `sort`, the order of the selected entities.
+    Sort(SortOrder),
+    /// `nbt`, an SNBT compound which the entity data must match, kept as written.
+    Nbt {
+        #[allow(missing_docs)]
+        negated: bool,
+        #[allow(missing_docs)]
+        nbt: String,
+    },
+    /// Any other argument, e.g. `advancements` or `predicate`, with its value kept as written.
+    Other {
+        #[allow(missing_docs)]
+        key: String,
+        #[allow(missing_docs)]
+        value: String,
+    },
+}
+
+impl SelectorArgument {
+    /// The argument key, e.g. `distance`.
+    pub fn key(&self) -> &str {
+        match self {
+            SelectorArgument::X(_) => "x",
+            SelectorArgument::Y(_) => "y",
+            SelectorArgument::Z(_) => "z",
+            SelectorArgument::Dx(_) => "dx",
+            SelectorArgument::Dy(_) => "dy",
+            SelectorArgument::Dz(_) => "dz",
+            SelectorArgument::Distance(_) => "distance",
+            SelectorArgument::Level(_) => "level",
+            SelectorArgument::XRotation(_) => "x_rotation",
+            SelectorArgument::YRotation(_) => "y_rotation",
+            SelectorArgument::Gamemode { .. } => "gamemode",
+            SelectorArgument::Name { .. } => "name",
+            SelectorArgument::Type { .. } => "type",
+            SelectorArgument::Tag { .. } => "tag",
+            SelectorArgument::Team { .. } => "team",
+            SelectorArgument::Scores(_) => "scores",
+            SelectorArgument::Limit(_) => "limit",
+            SelectorArgument::Sort(_) => "sort",
+            SelectorArgument::Nbt { .. } => "nbt",
+            SelectorArgument::Other { key, .. } => key,
+        }
+    }
+
+    /// Whether the argument is negated with `!`.
+    pub fn is_negated(&self) -> bool {
+        match self {
+            SelectorArgument::Gamemode { negated, .. }
+            | SelectorArgument::Name { negated, .. }
+            | SelectorArgument::Type { negated, .. }
+            | SelectorArgument::Tag { negated, .. }
+            | SelectorArgument::Team { negated, .. }
+            | SelectorArgument::Nbt { negated, .. } => *negated,
+            _ => false,
+        }
+    }
+
+    fn parse(key: &str, value: &str) -> Result<SelectorArgument, SelectorError> {
+        let invalid = || SelectorError::InvalidValue { key: key.to_owned(), value: value.to_owned() };
+        let (negated, negatable) = match value.strip_prefix('!') {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, value),
+        };
+        let string = || unquote(negatable).ok_or_else(invalid);
+        let not_negated = |arg: SelectorArgument| if negated { Err(invalid()) } else { Ok(arg) };
+        match key {
+            "x" => not_negated(SelectorArgument::X(value.parse().map_err(|_| invalid())?)),
+            "y" => not_negated(SelectorArgument::Y(value.parse().map_err(|_| invalid())?)),
+            "z" => not_negated(SelectorArgument::Z(value.parse().map_err(|_| invalid())?)),
+            "dx" => not_negated(SelectorArgument::Dx(value.parse().map_err(|_| invalid())?)),
+            "dy" => not_negated(SelectorArgument::Dy(value.parse().map_err(|_| invalid())?)),
+            "dz" => not_negated(SelectorArgument::Dz(value.parse().map_err(|_| invalid())?)),
+            "distance" => not_negated(SelectorArgument::Distance(value.parse().map_err(|_| invalid())?)),
+            "level" => not_negated(SelectorArgument::Level(value.parse().map_err(|_| invalid())?)),
+            "x_rotation" => not_negated(SelectorArgument::XRotation(value.parse().map_err(|_| invalid())?)),
+            "y_rotation" => not_negated(SelectorArgument::YRotation(value.parse().map_err(|_| invalid())?)),
+            "limit" => not_negated(SelectorArgument::Limit(value.parse().map_err(|_| invalid())?)),
+            "sort" => not_negated(SelectorArgument::Sort(value.parse()?)),
+            "gamemode" => Ok(SelectorArgument::Gamemode { negated, mode: GameMode::ALL.iter().find(|mode| mode.name() == negatable).copied().ok_or_else(invalid)? }),
+            "name" => Ok(SelectorArgument::Name { negated, name: string()? }),
+            "type" => Ok(SelectorArgument::Type { negated, entity_type: string()? }),
+            "tag" => Ok(SelectorArgument::Tag { negated, tag: string()? }),
+            "team" => Ok(SelectorArgument::Team { negated, team: string()? }),
+            "nbt" => Ok(SelectorArgument::Nbt { negated, nbt: negatable.to_owned() }),
+            "scores" => {
+                let inner = value.strip_prefix('{').and_then(|rest| rest.strip_suffix('}')).ok_or_else(invalid)?.trim();
+                let mut scores = Vec::default();
+                if !inner.is_empty() {
+                    for entry in inner.split(',') {
+                        let (objective, range) = entry.split_once('=').ok_or_else(invalid)?;
+                        scores.push((objective.trim().to_owned(), range.trim().parse().map_err(|_| invalid())?));
+                    }
+                }
+                Ok(SelectorArgument::Scores(scores))
+            }
+            _ => Ok(SelectorArgument::Other { key: key.to_owned(), value: value.to_owned() }),
+        }
+    }
+}
+
+/// Whether a string can be written without quotes, using the characters allowed by Brigadier.
+fn is_unquoted(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+'))
+}
+
+/// Writes a string value, quoting it if necessary. Values which are resource locations or tags are written unquoted if possible.
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str, resource_location: bool) -> fmt::Result {
+    let unquoted = if resource_location {
+        !s.is_empty() && s.strip_prefix('#').unwrap_or(s).chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+' | ':' | '/'))
+    } else {
+        is_unquoted(s) || s.is_empty()
+    };
+    if unquoted {
+        write!(f, "{}", s)
+    } else {
+        write!(f, "\"")?;
+        for c in s.chars() {
+            if matches!(c, '"' | '\\') { write!(f, "\\")? }
+            write!(f, "{}", c)?;
+        }
+        write!(f, "\"")
+    }
+}
+
+/// Parses a possibly quoted string value, handling backslash escapes in quoted strings.
+fn unquote(s: &str) -> Option<String> {
+    let quote = match s.chars().next() {
+        Some(quote @ ('"' | '\'')) => quote,
+        _ => return Some(s.to_owned()),
+    };
+    let mut chars = s[1..].chars();
+    let mut unquoted = String::default();
+    loop {
+        match chars.next()? {
+            '\\' => match chars.next()? {
+                c @ ('\\' | '"' | '\'') => unquoted.push(c),
+                _ => return None,
+            },
+            c if c == quote => break,
+            c => unquoted.push(c),
+        }
+    }
+    if chars.next().is_some() { return None }
+    Some(unquoted)
+}
+
+impl fmt::Display for SelectorArgument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}=", self.key())?;
+        if self.is_negated() { write!(f, "!")? }
+        match self {
+            SelectorArgument::X(value) | SelectorArgument::Y(value) | SelectorArgument::Z(value)
+            | SelectorArgument::Dx(value) | SelectorArgument::Dy(value) | SelectorArgument::Dz(value) => value.fmt(f),
+            SelectorArgument::Distance(range) | SelectorArgument::XRotation(range) | SelectorArgument::YRotation(range) => range.fmt(f),
+            SelectorArgument::Level(range) => range.fmt(f),
+            SelectorArgument::Gamemode { mode, .. } => mode.fmt(f),
+            SelectorArgument::Name { name: value, .. } | SelectorArgument::Tag { tag: value, .. } | SelectorArgument::Team { team: value, .. } => write_string(f, value, false),
+            SelectorArgument::Type { entity_type, .. } => write_string(f, entity_type, true),
+            SelectorArgument::Scores(scores) => {
+                write!(f, "{{")?;
+                for (idx, (objective, range)) in scores.iter().enumerate() {
+                    if idx > 0 { write!(f, ",")? }
+                    write!(f, "{}={}", objective, range)?;
+                }
+                write!(f, "}}")
+            }
+            SelectorArgument::Limit(limit) => limit.fmt(f),
+            SelectorArgument::Sort(sort) => sort.fmt(f),
+            SelectorArgument::Nbt { nbt: value, .. } | SelectorArgument::Other { value, .. } => write!(f, "{}", value),
+        }
+    }
+}
+
+/// A target selector like `@a[distance=..10,gamemode=!creative,scores={deaths=1..}]`.
+///
+/// [`FromStr`] accepts any syntactically valid selector; use [`validate`](TargetSelector::validate) to check whether the game would accept the combination of arguments. [`Display`](fmt::Display) writes the selector in a canonical form which parses back to the same value, quoting strings only where necessary.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::{
+///     game_mode::GameMode,
+///     selector::{
+///         MinMaxBounds,
+///         SelectorArgument,
+///         SelectorVariable,
+///         TargetSelector,
+///     },
+/// };
+///
+/// let selector = r#"@a[distance=..10,gamemode=!creative,scores={deaths=1..},name=!"Notch \"the creator\""]"#.parse::<TargetSelector>()?;
+/// assert_eq!(selector.variable, SelectorVariable::AllPlayers);
+/// assert_eq!(selector.arguments[0], SelectorArgument::Distance(MinMaxBounds::at_most(10.0)));
+/// assert_eq!(selector.arguments[1], SelectorArgument::Gamemode { negated: true, mode: GameMode::Creative });
+/// assert_eq!(selector.arguments[3], SelectorArgument::Name { negated: true, name: format!("Notch \"the creator\"") });
+/// assert_eq!(selector.to_string().parse::<TargetSelector>()?, selector);
+/// selector.validate()?;
+///
+/// let selector = "@e[type=#minecraft:skeletons, limit=1, sort=nearest, nbt={Tags:[\"a,b\"]}, predicate=example:test]".parse::<TargetSelector>()?;
+/// assert_eq!(selector.to_string(), "@e[type=#minecraft:skeletons,limit=1,sort=nearest,nbt={Tags:[\"a,b\"]},predicate=example:test]");
+///
+/// assert!("@s[limit=1]".parse::<TargetSelector>()?.validate().is_err());
+/// assert!("@a[type=zombie]".parse::<TargetSelector>()?.validate().is_err());
+/// # Ok::<(), minecraft::selector::SelectorError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetSelector {
+    /// The variable after the `@`.
+    pub variable: SelectorVariable,
+    /// The arguments in square brackets, in the order they were given.
+    pub arguments: Vec<SelectorArgument>,
+}
+
+impl TargetSelector {
+    /// Creates a selector without arguments.
+    pub fn new(variable: SelectorVariable) -> TargetSelector {
+        TargetSelector { variable, arguments: Vec::default() }
+    }
+
+    /// Adds an argument, taking and returning ownership.
+    pub fn with_argument(mut self, argument: SelectorArgument) -> TargetSelector {
+        self.arguments.push(argument);
+        self
+    }
+
+    /// Checks whether the game would accept this combination of variable and arguments.
+    ///
+    /// This checks for arguments which aren't applicable to the variable, like `limit` and `sort` on `@s` or `type` on player-only variables, arguments given more than once, and out-of-range values. Arguments stored as [`SelectorArgument::Other`] are not checked.
+    pub fn validate(&self) -> Result<(), SelectorError> {
+        let mut seen = Vec::<&'static str>::default();
+        for argument in &self.arguments {
+            let key = match argument {
+                SelectorArgument::Other { .. } => continue,
+                // negated arguments can be given multiple times, and tags and nbt even without negation
+                SelectorArgument::Tag { .. } | SelectorArgument::Nbt { .. } => None,
+                _ if argument.is_negated() => None,
+                SelectorArgument::X(_) => Some("x"),
+                SelectorArgument::Y(_) => Some("y"),
+                SelectorArgument::Z(_) => Some("z"),
+                SelectorArgument::Dx(_) => Some("dx"),
+                SelectorArgument::Dy(_) => Some("dy"),
+                SelectorArgument::Dz(_) => Some("dz"),
+                SelectorArgument::Distance(_) => Some("distance"),
+                SelectorArgument::Level(_) => Some("level"),
+                SelectorArgument::XRotation(_) => Some("x_rotation"),
+                SelectorArgument::YRotation(_) => Some("y_rotation"),
+                SelectorArgument::Gamemode { .. } => Some("gamemode"),
+                SelectorArgument::Name { .. } => Some("name"),
+                SelectorArgument::Type { .. } => Some("type"),
+                SelectorArgument::Team { .. } => Some("team"),
+                SelectorArgument::Scores(_) => Some("scores"),
+                SelectorArgument::Limit(_) => Some("limit"),
+                SelectorArgument::Sort(_) => Some("sort"),
+            };
+            if let Some(key) = key {
+                if seen.contains(&key) { return Err(SelectorError::Duplicate(key)) }
+                seen.push(key);
+            }
+            match argument {
+                SelectorArgument::Limit(_) if self.variable == SelectorVariable::Executor => return Err(SelectorError::NotApplicable("limit")),
+                SelectorArgument::Sort(_) if self.variable == SelectorVariable::Executor => return Err(SelectorError::NotApplicable("sort")),
+                SelectorArgument::Type { .. } if self.variable.is_players_only() => return Err(SelectorError::NotApplicable("type")),
+                SelectorArgument::Limit(limit) if *limit < 1 => return Err(SelectorError::OutOfRange("limit")),
+                SelectorArgument::Distance(range) if range.min.is_some_and(|min| min < 0.0) || range.max.is_some_and(|max| max < 0.0) => return Err(SelectorError::OutOfRange("distance")),
+                SelectorArgument::Level(range) if range.min.is_some_and(|min| min < 0) || range.max.is_some_and(|max| max < 0) => return Err(SelectorError::OutOfRange("level")),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TargetSelector {
+    type Err = SelectorError;
+
+    fn from_str(s: &str) -> Result<TargetSelector, SelectorError> {
+        let mut chars = s.chars();
+        let variable = match (chars.next(), chars.next()) {
+            (Some('@'), Some(c)) => SelectorVariable::ALL.iter().find(|variable| variable.char() == c).copied(),
+            _ => None,
+        }.ok_or_else(|| SelectorError::UnknownVariable(s.to_owned()))?;
+        let mut selector = TargetSelector::new(variable);
+        let rest = &s[2..];
+        if rest.is_empty() { return Ok(selector) }
+        let syntax = |position: usize, message| SelectorError::Syntax { position, message };
+        if !rest.starts_with('[') { return Err(syntax(2, "expected '[' or end of selector")) }
+        let mut pos = 3;
+        let bytes = s.as_bytes();
+        let skip_whitespace = |pos: &mut usize| while bytes.get(*pos).is_some_and(u8::is_ascii_whitespace) { *pos += 1 };
+        skip_whitespace(&mut pos);
+        if bytes.get(pos) == Some(&b']') {
+            if pos + 1 != s.len() { return Err(syntax(pos + 1, "unexpected text after selector")) }
+            return Ok(selector)
+        }
+        loop {
+            skip_whitespace(&mut pos);
+            let key_start = pos;
+            while bytes.get(pos).is_some_and(|&b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'.' | b'+')) { pos += 1 }
+            if pos == key_start { return Err(syntax(pos, "expected argument key")) }
+            let key = &s[key_start..pos];
+            skip_whitespace(&mut pos);
+            if bytes.get(pos) != Some(&b'=') { return Err(syntax(pos, "expected '='")) }
+            pos += 1;
+            skip_whitespace(&mut pos);
+            // scan the value up to the next top-level ',' or ']', skipping quoted strings and nested brackets
+            let value_start = pos;
+            let mut depth = 0usize;
+            let mut quote = None;
+            loop {
+                let Some(&b) = bytes.get(pos) else { return Err(syntax(pos, "expected ']'")) };
+                match (quote, b) {
+                    (Some(_), b'\\') => pos += 1,
+                    (Some(q), b) if b == q => quote = None,
+                    (Some(_), _) => {}
+                    (None, b'"' | b'\'') => quote = Some(b),
+                    (None, b'{' | b'[') => depth += 1,
+                    (None, b'}' | b']') if depth > 0 => depth -= 1,
+                    (None, b',' | b']') => break,
+                    (None, _) => {}
+                }
+                pos += 1;
+            }
+            let value = s[value_start..pos].trim_end();
+            selector.arguments.push(SelectorArgument::parse(key, value)?);
+            if bytes[pos] == b']' {
+                if pos + 1 != s.len() { return Err(syntax(pos + 1, "unexpected text after selector")) }
+                return Ok(selector)
+            }
+            pos += 1;
+        }
+    }
+}
+
+impl fmt::Display for TargetSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "@{}", self.variable.char())?;
+        if !self.arguments.is_empty() {
+            write!(f, "[")?;
+            for (idx, argument) in self.arguments.iter().enumerate() {
+                if idx > 0 { write!(f, ",")? }
+                argument.fmt(f)?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,351 @@
+//! This module contains types for the player and IP lists of a dedicated server: `whitelist.json`, `ops.json`, `banned-players.json`, and `banned-ips.json`.
+
+use {
+    std::{
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::Error as _,
+    },
+    uuid::Uuid,
+    crate::util,
+};
+
+/// A point in time as written in ban lists, in the format `yyyy-MM-dd HH:mm:ss Z`, e.g. `2024-06-13 18:04:22 +0200`.
+///
+/// The fields are kept as written, without time zone conversion, so the string form round-trips exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)] // fields are obvious
+pub struct Timestamp {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    /// The offset from UTC in minutes.
+    pub offset: i16,
+}
+
+/// The error returned when parsing a [`Timestamp`] or [`Expiry`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampParseError(pub String);
+
+impl fmt::Display for TimestampParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid timestamp: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for TimestampParseError {}
+
+impl Timestamp {
+    /// The number of seconds since the Unix epoch, taking the offset into account.
+    ///
+    /// Use this to compare timestamps, since those with different offsets can't be compared field by field.
+    pub fn unix_seconds(&self) -> i64 {
+        let days = util::days_from_civil(i64::from(self.year), self.month, self.day);
+        days * 86400 + i64::from(self.hour) * 3600 + i64::from(self.minute) * 60 + i64::from(self.second) - i64::from(self.offset) * 60
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = TimestampParseError;
+
+    fn from_str(s: &str) -> Result<Timestamp, TimestampParseError> {
+        let err = || TimestampParseError(s.to_owned());
+        let field = |part: Option<&str>, len: usize| part.filter(|part| part.len() == len && part.bytes().all(|b| b.is_ascii_digit())).ok_or_else(err)?.parse::<u16>().map_err(|_| err());
+        let mut parts = s.split(' ');
+        let mut date = parts.next().ok_or_else(err)?.split('-');
+        let mut time = parts.next().ok_or_else(err)?.split(':');
+        let offset = parts.next().ok_or_else(err)?;
+        if parts.next().is_some() || date.clone().count() != 3 || time.clone().count() != 3 { return Err(err()) }
+        let timestamp = Timestamp {
+            year: field(date.next(), 4)?,
+            month: field(date.next(), 2)? as u8,
+            day: field(date.next(), 2)? as u8,
+            hour: field(time.next(), 2)? as u8,
+            minute: field(time.next(), 2)? as u8,
+            second: field(time.next(), 2)? as u8,
+            offset: {
+                let sign = match offset.get(..1) {
+                    Some("+") => 1,
+                    Some("-") => -1,
+                    _ => return Err(err()),
+                };
+                let hours = field(offset.get(1..3), 2)? as i16;
+                let minutes = field(offset.get(3..), 2)? as i16;
+                sign * (hours * 60 + minutes)
+            },
+        };
+        if !(1..=12).contains(&timestamp.month) || !(1..=31).contains(&timestamp.day) || timestamp.hour > 23 || timestamp.minute > 59 || timestamp.second > 60 { return Err(err()) }
+        Ok(timestamp)
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f, "{:04}-{:02}-{:02} {:02}:{:02}:{:02} {}{:02}{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second,
+            if self.offset < 0 { '-' } else { '+' }, self.offset.abs() / 60, self.offset.abs() % 60,
+        )
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+}
+
+/// When a ban expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Expiry {
+    /// The ban is permanent, written as `forever`.
+    #[default]
+    Forever,
+    /// The ban expires at the given time.
+    At(Timestamp),
+}
+
+impl FromStr for Expiry {
+    type Err = TimestampParseError;
+
+    fn from_str(s: &str) -> Result<Expiry, TimestampParseError> {
+        if s == "forever" { Ok(Expiry::Forever) } else { s.parse().map(Expiry::At) }
+    }
+}
+
+impl fmt::Display for Expiry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expiry::Forever => write!(f, "forever"),
+            Expiry::At(timestamp) => timestamp.fmt(f),
+        }
+    }
+}
+
+impl Serialize for Expiry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Expiry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Expiry, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+}
+
+fn default_source() -> String { "(Unknown)".to_owned() }
+fn default_reason() -> String { "Banned by an operator.".to_owned() }
+
+/// An entry in a list of players.
+pub trait PlayerEntry {
+    /// The player's UUID.
+    fn uuid(&self) -> Uuid;
+    /// The player's name when they were added to the list.
+    fn name(&self) -> &str;
+}
+
+/// An entry in `whitelist.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[allow(missing_docs)] // fields are obvious
+pub struct WhitelistEntry {
+    pub uuid: Uuid,
+    pub name: String,
+}
+
+/// An entry in `ops.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpEntry {
+    /// The player's UUID.
+    pub uuid: Uuid,
+    /// The player's name when they were made an operator.
+    pub name: String,
+    /// The permission level from 1 to 4.
+    pub level: u8,
+    /// Whether the player can join when the server is full.
+    #[serde(default)]
+    pub bypasses_player_limit: bool,
+}
+
+/// An entry in `banned-players.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct PlayerBanEntry {
+    /// The banned player's UUID.
+    pub uuid: Uuid,
+    /// The banned player's name when they were banned.
+    pub name: String,
+    /// When the ban was issued.
+    pub created: Timestamp,
+    /// Who issued the ban, e.g. a player name or `Server` for the console.
+    #[serde(default = "default_source")]
+    pub source: String,
+    /// When the ban expires.
+    #[serde(default)]
+    pub expires: Expiry,
+    /// The reason shown to the player.
+    #[serde(default = "default_reason")]
+    pub reason: String,
+}
+
+/// An entry in `banned-ips.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct IpBanEntry {
+    /// The banned IP address, as written by the server.
+    pub ip: String,
+    /// When the ban was issued.
+    pub created: Timestamp,
+    /// Who issued the ban, e.g. a player name or `Server` for the console.
+    #[serde(default = "default_source")]
+    pub source: String,
+    /// When the ban expires.
+    #[serde(default)]
+    pub expires: Expiry,
+    /// The reason shown to players connecting from the address.
+    #[serde(default = "default_reason")]
+    pub reason: String,
+}
+
+impl PlayerEntry for WhitelistEntry {
+    fn uuid(&self) -> Uuid { self.uuid }
+    fn name(&self) -> &str { &self.name }
+}
+
+impl PlayerEntry for OpEntry {
+    fn uuid(&self) -> Uuid { self.uuid }
+    fn name(&self) -> &str { &self.name }
+}
+
+impl PlayerEntry for PlayerBanEntry {
+    fn uuid(&self) -> Uuid { self.uuid }
+    fn name(&self) -> &str { &self.name }
+}
+
+/// The contents of a list file of players, i.e. a JSON array of entries.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::server_lists::{
+///     OpList,
+///     PlayerBanList,
+///     Whitelist,
+/// };
+///
+/// let whitelist = r#"[
+///   {
+///     "uuid": "069a79f4-44e9-4726-a5be-fca90e38aaf5",
+///     "name": "Notch"
+///   }
+/// ]"#;
+/// let ops = r#"[
+///   {
+///     "uuid": "069a79f4-44e9-4726-a5be-fca90e38aaf5",
+///     "name": "Notch",
+///     "level": 4,
+///     "bypassesPlayerLimit": false
+///   }
+/// ]"#;
+/// let bans = r#"[
+///   {
+///     "uuid": "853c80ef-3c37-49fd-aa49-938b674adae6",
+///     "name": "jeb_",
+///     "created": "2024-06-13 18:04:22 +0000",
+///     "source": "Notch",
+///     "expires": "2024-07-01 00:00:00 -0500",
+///     "reason": "Banned by an operator."
+///   }
+/// ]"#;
+/// let parsed = serde_json::from_str::<Whitelist>(whitelist)?;
+/// assert!(parsed.get_by_name("notch").is_some());
+/// assert_eq!(serde_json::to_string_pretty(&parsed)?, whitelist);
+/// let parsed = serde_json::from_str::<OpList>(ops)?;
+/// assert_eq!(parsed.get_by_uuid("069a79f4-44e9-4726-a5be-fca90e38aaf5".parse().unwrap()).unwrap().level, 4);
+/// assert_eq!(serde_json::to_string_pretty(&parsed)?, ops);
+/// let parsed = serde_json::from_str::<PlayerBanList>(bans)?;
+/// assert_eq!(parsed.get_by_name("JEB_").unwrap().source, "Notch");
+/// assert_eq!(serde_json::to_string_pretty(&parsed)?, bans);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct PlayerList<T>(pub Vec<T>);
+
+/// The contents of `whitelist.json`.
+pub type Whitelist = PlayerList<WhitelistEntry>;
+/// The contents of `ops.json`.
+pub type OpList = PlayerList<OpEntry>;
+/// The contents of `banned-players.json`.
+pub type PlayerBanList = PlayerList<PlayerBanEntry>;
+
+impl<T> Default for PlayerList<T> {
+    fn default() -> PlayerList<T> { PlayerList(Vec::default()) }
+}
+
+impl<T: PlayerEntry> PlayerList<T> {
+    /// Looks up the entry for the player with the given UUID.
+    pub fn get_by_uuid(&self, uuid: Uuid) -> Option<&T> {
+        self.0.iter().find(|entry| entry.uuid() == uuid)
+    }
+
+    /// Looks up the entry for the player with the given name, case-insensitively like the server.
+    pub fn get_by_name(&self, name: &str) -> Option<&T> {
+        self.0.iter().find(|entry| entry.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Whether the player with the given UUID is on the list.
+    pub fn contains(&self, uuid: Uuid) -> bool {
+        self.get_by_uuid(uuid).is_some()
+    }
+}
+
+/// The contents of `banned-ips.json`.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::server_lists::{
+///     Expiry,
+///     IpBanList,
+/// };
+///
+/// let json = r#"[
+///   {
+///     "ip": "192.0.2.1",
+///     "created": "2024-06-13 18:04:22 +0200",
+///     "source": "Server",
+///     "expires": "forever",
+///     "reason": "Banned by an operator."
+///   }
+/// ]"#;
+/// let bans = serde_json::from_str::<IpBanList>(json)?;
+/// assert_eq!(bans.get("192.0.2.1").unwrap().expires, Expiry::Forever);
+/// assert_eq!(bans.0[0].created.offset, 120);
+/// assert_eq!(serde_json::to_string_pretty(&bans)?, json);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct IpBanList(pub Vec<IpBanEntry>);
+
+impl IpBanList {
+    /// Looks up the ban for the given IP address.
+    pub fn get(&self, ip: &str) -> Option<&IpBanEntry> {
+        self.0.iter().find(|entry| entry.ip == ip)
+    }
+}
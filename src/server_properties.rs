@@ -0,0 +1,483 @@
+//! This module contains the [`ServerProperties`] type, representing the [`server.properties`](https://minecraft.fandom.com/wiki/Server.properties) file of a dedicated server.
+
+use {
+    std::{
+        collections::BTreeMap,
+        fmt,
+        io::{
+            self,
+            prelude::*,
+        },
+        str::FromStr,
+    },
+    crate::{
+        chat::Chat,
+        difficulty::Difficulty,
+        game_mode::GameMode,
+    },
+};
+
+/// An error that can occur when reading a [`ServerProperties`] file.
+#[derive(Debug)]
+pub enum ServerPropertiesError {
+    /// Reading the file failed.
+    Io(io::Error),
+    /// A `\u` escape was not followed by four hex digits.
+    MalformedEscape,
+}
+
+impl From<io::Error> for ServerPropertiesError {
+    fn from(e: io::Error) -> ServerPropertiesError {
+        ServerPropertiesError::Io(e)
+    }
+}
+
+impl fmt::Display for ServerPropertiesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServerPropertiesError::Io(e) => write!(f, "I/O error: {}", e),
+            ServerPropertiesError::MalformedEscape => write!(f, "malformed \\uxxxx escape in properties file"),
+        }
+    }
+}
+
+impl std::error::Error for ServerPropertiesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ServerPropertiesError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// The `level-seed` property, which may be a number or arbitrary text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LevelSeed {
+    /// A seed which is used as is.
+    Numeric(i64),
+    /// A seed which is hashed to get the numeric seed. If empty, a random seed is used.
+    Text(String),
+}
+
+impl LevelSeed {
+    /// The numeric seed used by the game, or `None` if a random seed is used.
+    ///
+    /// Text seeds are hashed with Java's `String.hashCode`.
+    pub fn to_i64(&self) -> Option<i64> {
+        match self {
+            LevelSeed::Numeric(seed) => Some(*seed),
+            LevelSeed::Text(text) if text.is_empty() => None,
+            LevelSeed::Text(text) => Some(i64::from(text.encode_utf16().fold(0i32, |hash, unit| hash.wrapping_mul(31).wrapping_add(i32::from(unit))))),
+        }
+    }
+}
+
+impl FromStr for LevelSeed {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<LevelSeed, std::convert::Infallible> {
+        Ok(s.parse().map_or_else(|_| LevelSeed::Text(s.to_owned()), LevelSeed::Numeric))
+    }
+}
+
+impl fmt::Display for LevelSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LevelSeed::Numeric(seed) => seed.fmt(f),
+            LevelSeed::Text(text) => text.fmt(f),
+        }
+    }
+}
+
+/// A type which can be the value of a known property.
+trait PropertyValue: Sized {
+    fn parse(s: &str) -> Option<Self>;
+    fn format(&self) -> String;
+}
+
+macro_rules! property_value_via_str {
+    ($($ty:ty),*) => {
+        $(
+            impl PropertyValue for $ty {
+                fn parse(s: &str) -> Option<$ty> { s.parse().ok() }
+                fn format(&self) -> String { self.to_string() }
+            }
+        )*
+    };
+}
+
+property_value_via_str!(u8, u16, u32, i32, i64, String, GameMode, Difficulty, LevelSeed);
+
+impl PropertyValue for bool {
+    /// Parses `true` or `false` case-insensitively.
+    fn parse(s: &str) -> Option<bool> {
+        if s.eq_ignore_ascii_case("true") {
+            Some(true)
+        } else if s.eq_ignore_ascii_case("false") {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    fn format(&self) -> String { self.to_string() }
+}
+
+macro_rules! server_properties {
+    ($($(#[$attr:meta])* $field:ident: $ty:ty => $key:literal,)*) => {
+        /// The contents of a `server.properties` file.
+        ///
+        /// Each known property is `None` if it's missing from the file, in which case the server uses its default value. Properties not known to this crate, and known properties whose values can't be parsed as their type, are kept in [`unknown`](ServerProperties::unknown), so reading and writing a file doesn't lose any properties. Comments are not preserved.
+        #[derive(Debug, Clone, Default)]
+        pub struct ServerProperties {
+            $(
+                $(#[$attr])*
+                pub $field: Option<$ty>,
+            )*
+            /// Properties not known to this crate, e.g. those added by server software other than vanilla, with their unescaped values.
+            ///
+            /// This also contains known properties whose values can't be parsed, e.g. `enable-rcon=yes` or an empty `max-players=`, for which the server uses the default value. If the corresponding field is set, it takes precedence when writing.
+            pub unknown: BTreeMap<String, String>,
+        }
+
+        impl ServerProperties {
+            fn set(&mut self, key: String, value: String) {
+                match &*key {
+                    $($key => match PropertyValue::parse(&value) {
+                        Some(value) => self.$field = Some(value),
+                        None => { self.unknown.insert(key, value); }
+                    },)*
+                    _ => { self.unknown.insert(key, value); }
+                }
+            }
+
+            fn entries(&self) -> BTreeMap<&str, String> {
+                let mut entries = self.unknown.iter().map(|(key, value)| (&**key, value.clone())).collect::<BTreeMap<_, _>>();
+                $(
+                    if let Some(ref value) = self.$field { entries.insert($key, value.format()); }
+                )*
+                entries
+            }
+        }
+    };
+}
+
+server_properties! {
+    /// `accepts-transfers`: whether players can be transferred here from other servers.
+    accepts_transfers: bool => "accepts-transfers",
+    /// `allow-flight`: whether players flying in survival are not kicked.
+    allow_flight: bool => "allow-flight",
+    /// `allow-nether`: whether players can travel to the Nether.
+    allow_nether: bool => "allow-nether",
+    /// `broadcast-console-to-ops`: whether command output from the console is sent to operators.
+    broadcast_console_to_ops: bool => "broadcast-console-to-ops",
+    /// `broadcast-rcon-to-ops`: whether command output from RCON is sent to operators.
+    broadcast_rcon_to_ops: bool => "broadcast-rcon-to-ops",
+    /// `difficulty`: the difficulty of newly created worlds.
+    difficulty: Difficulty => "difficulty",
+    /// `enable-command-block`: whether command blocks work.
+    enable_command_block: bool => "enable-command-block",
+    /// `enable-jmx-monitoring`: whether JMX beans are exposed.
+    enable_jmx_monitoring: bool => "enable-jmx-monitoring",
+    /// `enable-query`: whether the GameSpy4 query protocol is enabled.
+    enable_query: bool => "enable-query",
+    /// `enable-rcon`: whether remote console access is enabled.
+    enable_rcon: bool => "enable-rcon",
+    /// `enable-status`: whether the server appears online in the server list.
+    enable_status: bool => "enable-status",
+    /// `enforce-secure-profile`: whether players must have a Mojang-signed public key to join.
+    enforce_secure_profile: bool => "enforce-secure-profile",
+    /// `enforce-whitelist`: whether players not on the whitelist are kicked when it's reloaded.
+    enforce_whitelist: bool => "enforce-whitelist",
+    /// `entity-broadcast-range-percentage`: how far away entities are sent to clients, in percent of the default.
+    entity_broadcast_range_percentage: u32 => "entity-broadcast-range-percentage",
+    /// `force-gamemode`: whether players are put into the default game mode when joining.
+    force_gamemode: bool => "force-gamemode",
+    /// `function-permission-level`: the permission level of functions.
+    function_permission_level: u8 => "function-permission-level",
+    /// `gamemode`: the default game mode.
+    gamemode: GameMode => "gamemode",
+    /// `generate-structures`: whether structures are generated in new chunks.
+    generate_structures: bool => "generate-structures",
+    /// `generator-settings`: the settings for custom world generation, as JSON.
+    generator_settings: String => "generator-settings",
+    /// `hardcore`: whether the server is in hardcore mode.
+    hardcore: bool => "hardcore",
+    /// `hide-online-players`: whether the player list is hidden from status requests.
+    hide_online_players: bool => "hide-online-players",
+    /// `initial-disabled-packs`: the data packs not enabled when creating the world, comma-separated.
+    initial_disabled_packs: String => "initial-disabled-packs",
+    /// `initial-enabled-packs`: the data packs enabled when creating the world, comma-separated.
+    initial_enabled_packs: String => "initial-enabled-packs",
+    /// `level-name`: the name of the world directory.
+    level_name: String => "level-name",
+    /// `level-seed`: the seed for creating the world.
+    level_seed: LevelSeed => "level-seed",
+    /// `level-type`: the world preset for creating the world, e.g. `minecraft:normal`.
+    level_type: String => "level-type",
+    /// `log-ips`: whether player IP addresses are logged.
+    log_ips: bool => "log-ips",
+    /// `max-chained-neighbor-updates`: the limit of consecutive neighbor updates before skipping.
+    max_chained_neighbor_updates: i32 => "max-chained-neighbor-updates",
+    /// `max-players`: the maximum number of players online at the same time.
+    max_players: u32 => "max-players",
+    /// `max-tick-time`: the number of milliseconds a tick may take before the watchdog stops the server, or -1 to disable.
+    max_tick_time: i64 => "max-tick-time",
+    /// `max-world-size`: the maximum radius of the world border.
+    max_world_size: u32 => "max-world-size",
+    /// `motd`: the message shown in the server list, with legacy formatting codes. See [`ServerProperties::motd_chat`] for the parsed text.
+    motd: String => "motd",
+    /// `network-compression-threshold`: the packet size above which packets are compressed, or -1 to disable.
+    network_compression_threshold: i32 => "network-compression-threshold",
+    /// `online-mode`: whether players are authenticated with Mojang.
+    online_mode: bool => "online-mode",
+    /// `op-permission-level`: the default permission level of operators.
+    op_permission_level: u8 => "op-permission-level",
+    /// `player-idle-timeout`: the number of minutes after which idle players are kicked, or 0 to disable.
+    player_idle_timeout: u32 => "player-idle-timeout",
+    /// `prevent-proxy-connections`: whether players connecting from a different IP than the one used to authenticate are kicked.
+    prevent_proxy_connections: bool => "prevent-proxy-connections",
+    /// `pvp`: whether players can damage each other.
+    pvp: bool => "pvp",
+    /// `query.port`: the port of the query protocol.
+    query_port: u16 => "query.port",
+    /// `rate-limit`: the maximum number of packets per second a player may send before being kicked, or 0 to disable.
+    rate_limit: u32 => "rate-limit",
+    /// `rcon.password`: the password for remote console access.
+    rcon_password: String => "rcon.password",
+    /// `rcon.port`: the port for remote console access.
+    rcon_port: u16 => "rcon.port",
+    /// `region-file-compression`: the compression algorithm for region files.
+    region_file_compression: String => "region-file-compression",
+    /// `require-resource-pack`: whether players who decline the resource pack are kicked.
+    require_resource_pack: bool => "require-resource-pack",
+    /// `resource-pack`: the URL of the server resource pack.
+    resource_pack: String => "resource-pack",
+    /// `resource-pack-id`: the UUID of the server resource pack.
+    resource_pack_id: String => "resource-pack-id",
+    /// `resource-pack-prompt`: the message shown when prompting for the resource pack, as a JSON text component.
+    resource_pack_prompt: String => "resource-pack-prompt",
+    /// `resource-pack-sha1`: the SHA-1 hash of the server resource pack.
+    resource_pack_sha1: String => "resource-pack-sha1",
+    /// `server-ip`: the IP address to bind to, or empty for all addresses.
+    server_ip: String => "server-ip",
+    /// `server-port`: the port to listen on.
+    server_port: u16 => "server-port",
+    /// `simulation-distance`: the radius in chunks around players in which entities are ticked.
+    simulation_distance: u32 => "simulation-distance",
+    /// `spawn-animals`: whether animals spawn.
+    spawn_animals: bool => "spawn-animals",
+    /// `spawn-monsters`: whether monsters spawn.
+    spawn_monsters: bool => "spawn-monsters",
+    /// `spawn-npcs`: whether villagers spawn.
+    spawn_npcs: bool => "spawn-npcs",
+    /// `spawn-protection`: the radius around the world spawn in which only operators can build.
+    spawn_protection: u32 => "spawn-protection",
+    /// `sync-chunk-writes`: whether chunks are written synchronously.
+    sync_chunk_writes: bool => "sync-chunk-writes",
+    /// `text-filtering-config`: the configuration of the chat filter.
+    text_filtering_config: String => "text-filtering-config",
+    /// `use-native-transport`: whether the Linux epoll transport is used.
+    use_native_transport: bool => "use-native-transport",
+    /// `view-distance`: the radius in chunks around players which are sent to clients.
+    view_distance: u32 => "view-distance",
+    /// `white-list`: whether only players on the whitelist can join.
+    white_list: bool => "white-list",
+}
+
+impl ServerProperties {
+    /// Reads a `server.properties` file in the Java `.properties` format.
+    ///
+    /// The file is read as UTF-8, falling back to ISO 8859-1 if it's not valid UTF-8, like vanilla.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::{
+    ///     game_mode::GameMode,
+    ///     server_properties::ServerProperties,
+    /// };
+    ///
+    /// let file = "#Minecraft server properties\ngamemode=creative\nmotd=\\u00A7cA \\\n    Minecraft Server\nserver-port : 25566\nsettings.paper\\:custom=yes\n";
+    /// let properties = ServerProperties::from_reader(file.as_bytes())?;
+    /// assert_eq!(properties.gamemode, Some(GameMode::Creative));
+    /// assert_eq!(properties.motd.as_deref(), Some("§cA Minecraft Server"));
+    /// assert_eq!(properties.server_port, Some(25566));
+    /// assert_eq!(properties.unknown["settings.paper:custom"], "yes");
+    ///
+    /// let mut buf = Vec::default();
+    /// properties.to_writer(&mut buf)?;
+    /// assert_eq!(String::from_utf8(buf)?, "#Minecraft server properties\ngamemode=creative\nmotd=§cA Minecraft Server\nserver-port=25566\nsettings.paper\\:custom=yes\n");
+    ///
+    /// // values which can't be parsed are kept as they are
+    /// let properties = ServerProperties::from_reader("enable-rcon=yes\nmax-players=\nonline-mode=false\n".as_bytes())?;
+    /// assert_eq!(properties.enable_rcon, None);
+    /// assert_eq!(properties.max_players, None);
+    /// assert_eq!(properties.online_mode, Some(false));
+    /// assert_eq!(properties.unknown["enable-rcon"], "yes");
+    /// assert_eq!(properties.unknown["max-players"], "");
+    /// let mut buf = Vec::default();
+    /// properties.to_writer(&mut buf)?;
+    /// assert_eq!(String::from_utf8(buf)?, "#Minecraft server properties\nenable-rcon=yes\nmax-players=\nonline-mode=false\n");
+    ///
+    /// // unpaired surrogates are replaced in place
+    /// let properties = ServerProperties::from_reader("level-name=\\uD83D\\uDE00\\uD83Dx\\u0041\\uD83D\\uD83D\\uDE00\n".as_bytes())?;
+    /// assert_eq!(properties.level_name.as_deref(), Some("😀\u{fffd}xA\u{fffd}😀"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_reader(mut reader: impl Read) -> Result<ServerProperties, ServerPropertiesError> {
+        let mut buf = Vec::default();
+        reader.read_to_end(&mut buf)?;
+        let text = String::from_utf8(buf).unwrap_or_else(|e| e.into_bytes().into_iter().map(char::from).collect());
+        let mut properties = ServerProperties::default();
+        for (key, value) in parse(&text)? {
+            properties.set(key, value);
+        }
+        Ok(properties)
+    }
+
+    /// Writes the properties in the Java `.properties` format, sorted by key like vanilla.
+    ///
+    /// Like vanilla, the file is written as UTF-8 and non-ASCII characters are not escaped.
+    pub fn to_writer(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "#Minecraft server properties")?;
+        for (key, value) in self.entries() {
+            writeln!(writer, "{}={}", escape(key, true), escape(&value, false))?;
+        }
+        Ok(())
+    }
+
+    /// The `motd` property as text with [legacy formatting codes](crate::legacy), which is how the server interprets it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::server_properties::ServerProperties;
+    ///
+    /// let properties = ServerProperties::from_reader("motd=§cTom §zHi §".as_bytes())?;
+    /// assert_eq!(properties.motd_chat().unwrap().to_legacy(false), "§cTom Hi ");
+    ///
+    /// // the raw value is written back as is
+    /// let mut buf = Vec::default();
+    /// properties.to_writer(&mut buf)?;
+    /// assert_eq!(String::from_utf8(buf)?, "#Minecraft server properties\nmotd=§cTom §zHi §\n");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn motd_chat(&self) -> Option<Chat> {
+        self.motd.as_deref().map(Chat::from_legacy)
+    }
+}
+
+/// Parses the key-value pairs of a Java `.properties` file, following the rules of `java.util.Properties.load`.
+fn parse(text: &str) -> Result<Vec<(String, String)>, ServerPropertiesError> {
+    let mut entries = Vec::default();
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim_start_matches([' ', '\t', '\x0c']);
+        if line.is_empty() || line.starts_with(['#', '!']) { continue }
+        // join continuation lines, which end in an odd number of backslashes
+        let mut logical = line.to_owned();
+        while logical.chars().rev().take_while(|&c| c == '\\').count() % 2 == 1 {
+            logical.pop();
+            match lines.next() {
+                Some(next) => logical.push_str(next.trim_start_matches([' ', '\t', '\x0c'])),
+                None => break,
+            }
+        }
+        let mut chars = logical.chars().peekable();
+        let mut raw_key = String::default();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    raw_key.push(c);
+                    if let Some(escaped) = chars.next() { raw_key.push(escaped) }
+                }
+                '=' | ':' | ' ' | '\t' | '\x0c' => {
+                    // skip whitespace and at most one separator
+                    let mut separator = c == '=' || c == ':';
+                    while let Some(&c) = chars.peek() {
+                        match c {
+                            ' ' | '\t' | '\x0c' => { chars.next(); }
+                            '=' | ':' if !separator => {
+                                separator = true;
+                                chars.next();
+                            }
+                            _ => break,
+                        }
+                    }
+                    break
+                }
+                _ => raw_key.push(c),
+            }
+        }
+        entries.push((unescape(&raw_key)?, unescape(&chars.collect::<String>())?));
+    }
+    Ok(entries)
+}
+
+fn unescape(s: &str) -> Result<String, ServerPropertiesError> {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    let mut pending_high_surrogate = None;
+    while let Some(c) = chars.next() {
+        // a high surrogate which isn't directly followed by another `\u` escape is unpaired
+        if pending_high_surrogate.is_some() && !(c == '\\' && chars.clone().next() == Some('u')) {
+            pending_high_surrogate = None;
+            unescaped.push(char::REPLACEMENT_CHARACTER);
+        }
+        if c != '\\' {
+            unescaped.push(c);
+            continue
+        }
+        match chars.next() {
+            Some('u') => {
+                let hex = chars.by_ref().take(4).collect::<String>();
+                if hex.len() != 4 { return Err(ServerPropertiesError::MalformedEscape) }
+                let unit = u16::from_str_radix(&hex, 16).map_err(|_| ServerPropertiesError::MalformedEscape)?;
+                // characters outside the BMP are escaped as surrogate pairs
+                if let Some(high) = pending_high_surrogate.take() {
+                    if (0xdc00..0xe000).contains(&unit) {
+                        unescaped.extend(char::decode_utf16([high, unit]).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)));
+                        continue
+                    }
+                    unescaped.push(char::REPLACEMENT_CHARACTER);
+                }
+                if (0xd800..0xdc00).contains(&unit) {
+                    pending_high_surrogate = Some(unit);
+                } else {
+                    unescaped.push(char::from_u32(u32::from(unit)).unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+                continue
+            }
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('f') => unescaped.push('\x0c'),
+            Some(c) => unescaped.push(c),
+            None => {}
+        }
+    }
+    if pending_high_surrogate.is_some() { unescaped.push(char::REPLACEMENT_CHARACTER) }
+    Ok(unescaped)
+}
+
+/// Escapes a key or value like `java.util.Properties.store` with a `Writer`, which leaves non-ASCII characters as they are.
+fn escape(s: &str, is_key: bool) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for (idx, c) in s.chars().enumerate() {
+        match c {
+            ' ' if is_key || idx == 0 => escaped.push_str("\\ "),
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\x0c' => escaped.push_str("\\f"),
+            '=' | ':' | '#' | '!' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
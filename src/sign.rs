@@ -0,0 +1,228 @@
+//! This module contains types for the text on [signs](https://minecraft.fandom.com/wiki/Sign).
+
+use {
+    std::fmt,
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::Error as _,
+        ser::SerializeStruct as _,
+    },
+    crate::{
+        chat::{
+            self,
+            Chat,
+        },
+        dye_color::DyeColor,
+        util::nbt_bool,
+    },
+};
+
+/// The maximum width of a line of sign text in pixels of the default font.
+pub const LINE_WIDTH: u32 = 90;
+
+/// The error returned by [`SignText::lines_from_chat`] if the text doesn't fit on a sign.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignTextOverflow(pub usize);
+
+impl fmt::Display for SignTextOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "text needs {} lines, but signs only have 4", self.0)
+    }
+}
+
+impl std::error::Error for SignTextOverflow {}
+
+/// (De)serializes the four lines of a sign side as JSON strings, as in block entity NBT.
+mod messages {
+    use {
+        serde::{
+            Deserialize,
+            Deserializer,
+            Serialize,
+            Serializer,
+        },
+        crate::chat::{
+            self,
+            Chat,
+        },
+    };
+
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    struct Line(#[serde(with = "chat::json_string")] Chat);
+
+    pub(super) fn serialize<S: Serializer>(lines: &[Chat; 4], serializer: S) -> Result<S::Ok, S::Error> {
+        lines.iter().map(|line| Line(line.clone())).collect::<Vec<_>>().serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[Chat; 4], D::Error> {
+        let [Line(line1), Line(line2), Line(line3), Line(line4)] = <[Line; 4]>::deserialize(deserializer)?;
+        Ok([line1, line2, line3, line4])
+    }
+}
+
+/// The text on one side of a sign.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SignText {
+    /// The four lines of text, from top to bottom.
+    #[serde(with = "messages")]
+    pub messages: [Chat; 4],
+    /// The color applied to the text with dye.
+    #[serde(default = "default_color")]
+    pub color: DyeColor,
+    /// Whether the text was made to glow with a glow ink sac.
+    #[serde(rename = "has_glowing_text", default, with = "nbt_bool")]
+    pub glowing: bool,
+}
+
+fn default_color() -> DyeColor { DyeColor::Black }
+
+impl Default for SignText {
+    fn default() -> SignText {
+        SignText {
+            messages: Default::default(),
+            color: default_color(),
+            glowing: false,
+        }
+    }
+}
+
+impl SignText {
+    /// Creates undyed, non-glowing sign text with the given lines.
+    pub fn new(messages: [Chat; 4]) -> SignText {
+        SignText { messages, ..SignText::default() }
+    }
+
+    /// Splits a text component into the four lines of a sign.
+    ///
+    /// The text is split at newlines and wrapped at spaces so each line is at most [`LINE_WIDTH`] pixels wide, using the character widths of the default font. Words too long for a line are broken up. Formatting is kept, but click and hover events are not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft::{
+    ///     chat::Chat,
+    ///     sign::SignText,
+    /// };
+    ///
+    /// let lines = SignText::lines_from_chat(&Chat::from("Welcome to the shopping district\nOpen daily"))?;
+    /// assert_eq!(lines.iter().map(|line| line.runs().map(|(text, _)| text).collect::<String>()).collect::<Vec<_>>(), ["Welcome to the", "shopping district", "Open daily", ""]);
+    /// assert!(SignText::lines_from_chat(&Chat::from("a\nb\nc\nd\ne")).is_err());
+    /// # Ok::<(), minecraft::sign::SignTextOverflow>(())
+    /// ```
+    pub fn lines_from_chat(chat: &Chat) -> Result<[Chat; 4], SignTextOverflow> {
+        let lines = chat.wrap_lines(LINE_WIDTH);
+        if lines.len() > 4 { return Err(SignTextOverflow(lines.len())) }
+        let mut messages = <[Chat; 4]>::default();
+        for (message, line) in messages.iter_mut().zip(lines) {
+            *message = line;
+        }
+        Ok(messages)
+    }
+}
+
+/// Which format a [`SignBlockEntity`] uses in NBT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SignFormat {
+    /// The format used before 1.20: `Text1` to `Text4`, `Color`, and `GlowingText`, with only a front side.
+    Legacy,
+    /// The format used since 1.20: `front_text`, `back_text`, and `is_waxed`.
+    #[default]
+    Sides,
+}
+
+/// The block entity data of a sign or hanging sign.
+///
+/// Deserializes from either [format](SignFormat), detecting which one is used, and serializes back to the same format unless `format` is changed. The back side and wax are lost when serializing to the legacy format.
+#[derive(Debug, Clone, Default)]
+pub struct SignBlockEntity {
+    /// The text on the front side.
+    pub front: SignText,
+    /// The text on the back side.
+    pub back: SignText,
+    /// Whether the sign was waxed with honeycomb, preventing edits.
+    pub waxed: bool,
+    /// The format to use when serializing.
+    pub format: SignFormat,
+}
+
+#[derive(Deserialize)]
+struct SignBlockEntityRepr {
+    front_text: Option<SignText>,
+    back_text: Option<SignText>,
+    #[serde(default, with = "nbt_bool")]
+    is_waxed: bool,
+    #[serde(rename = "Text1")]
+    text1: Option<serde_json::Value>,
+    #[serde(rename = "Text2")]
+    text2: Option<serde_json::Value>,
+    #[serde(rename = "Text3")]
+    text3: Option<serde_json::Value>,
+    #[serde(rename = "Text4")]
+    text4: Option<serde_json::Value>,
+    #[serde(rename = "Color")]
+    color: Option<DyeColor>,
+    #[serde(rename = "GlowingText", default, with = "nbt_bool")]
+    glowing_text: bool,
+}
+
+impl<'de> Deserialize<'de> for SignBlockEntity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<SignBlockEntity, D::Error> {
+        let repr = SignBlockEntityRepr::deserialize(deserializer)?;
+        Ok(if repr.front_text.is_some() || repr.back_text.is_some() {
+            SignBlockEntity {
+                front: repr.front_text.unwrap_or_default(),
+                back: repr.back_text.unwrap_or_default(),
+                waxed: repr.is_waxed,
+                format: SignFormat::Sides,
+            }
+        } else {
+            let line = |text: Option<serde_json::Value>| text.map_or_else(|| Ok(Chat::default()), |text| chat::json_string::deserialize(text).map_err(D::Error::custom));
+            SignBlockEntity {
+                front: SignText {
+                    messages: [line(repr.text1)?, line(repr.text2)?, line(repr.text3)?, line(repr.text4)?],
+                    color: repr.color.unwrap_or_else(default_color),
+                    glowing: repr.glowing_text,
+                },
+                back: SignText::default(),
+                waxed: false,
+                format: SignFormat::Legacy,
+            }
+        })
+    }
+}
+
+struct JsonLine<'a>(&'a Chat);
+
+impl Serialize for JsonLine<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        chat::json_string::serialize(self.0, serializer)
+    }
+}
+
+impl Serialize for SignBlockEntity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.format {
+            SignFormat::Legacy => {
+                let mut state = serializer.serialize_struct("SignBlockEntity", 6)?;
+                state.serialize_field("Text1", &JsonLine(&self.front.messages[0]))?;
+                state.serialize_field("Text2", &JsonLine(&self.front.messages[1]))?;
+                state.serialize_field("Text3", &JsonLine(&self.front.messages[2]))?;
+                state.serialize_field("Text4", &JsonLine(&self.front.messages[3]))?;
+                state.serialize_field("Color", &self.front.color)?;
+                state.serialize_field("GlowingText", &self.front.glowing)?;
+                state.end()
+            }
+            SignFormat::Sides => {
+                let mut state = serializer.serialize_struct("SignBlockEntity", 3)?;
+                state.serialize_field("front_text", &self.front)?;
+                state.serialize_field("back_text", &self.back)?;
+                state.serialize_field("is_waxed", &self.waxed)?;
+                state.end()
+            }
+        }
+    }
+}
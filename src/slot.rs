@@ -0,0 +1,391 @@
+//! This module contains types for [inventory](https://minecraft.fandom.com/wiki/Inventory) and equipment slots and the conversions between the different ways of numbering them.
+
+use {
+    std::{
+        convert::TryFrom,
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+};
+
+/// An armor slot in the player inventory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)] // variants are obvious
+pub enum ArmorSlot {
+    Feet,
+    Legs,
+    Chest,
+    Head,
+}
+
+impl ArmorSlot {
+    /// All armor slots, in NBT slot order (feet first).
+    pub const ALL: [ArmorSlot; 4] = [ArmorSlot::Feet, ArmorSlot::Legs, ArmorSlot::Chest, ArmorSlot::Head];
+
+    /// The lowercase name used for equipment slots, e.g. `feet`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ArmorSlot::Feet => "feet",
+            ArmorSlot::Legs => "legs",
+            ArmorSlot::Chest => "chest",
+            ArmorSlot::Head => "head",
+        }
+    }
+
+    /// The index of this slot counting from the feet, as in the NBT slot number minus 100.
+    fn index(&self) -> u8 {
+        match self {
+            ArmorSlot::Feet => 0,
+            ArmorSlot::Legs => 1,
+            ArmorSlot::Chest => 2,
+            ArmorSlot::Head => 3,
+        }
+    }
+}
+
+impl fmt::Display for ArmorSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A kind of container window, which determines how slots are numbered in window packets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowKind {
+    /// The player's own inventory screen, window ID 0, which includes the 2×2 crafting grid, armor, and offhand.
+    PlayerInventory,
+    /// A chest-like window (`generic_9x1` to `generic_9x6`) with the given number of rows, followed by the player's main inventory and hotbar.
+    Generic {
+        /// The number of rows of 9 slots in the container, e.g. 3 for a single chest.
+        rows: u8,
+    },
+}
+
+/// A slot in the player inventory.
+///
+/// The [`Ord`] impl walks the inventory in NBT slot order, except that the offhand comes last.
+///
+/// # Examples
+///
+/// ```
+/// use minecraft::slot::{
+///     ArmorSlot,
+///     PlayerSlot,
+///     WindowKind,
+/// };
+///
+/// assert_eq!(PlayerSlot::Offhand.to_nbt_slot(), Some(-106));
+/// assert_eq!(PlayerSlot::Armor(ArmorSlot::Feet).to_nbt_slot(), Some(100));
+/// assert_eq!(PlayerSlot::Hotbar(0).to_nbt_slot(), Some(0));
+/// assert_eq!(PlayerSlot::Hotbar(0).to_window_index(WindowKind::PlayerInventory), Some(36));
+/// assert_eq!(PlayerSlot::Hotbar(8).to_window_index(WindowKind::PlayerInventory), Some(44));
+/// assert_eq!(PlayerSlot::from_nbt_slot(9), Some(PlayerSlot::Main(0)));
+///
+/// // indices outside the inventory don't have slot numbers
+/// assert!(!PlayerSlot::Main(119).is_valid());
+/// assert_eq!(PlayerSlot::Main(119).to_nbt_slot(), None);
+/// assert_eq!(PlayerSlot::Hotbar(200).to_nbt_slot(), None);
+/// assert_eq!(PlayerSlot::Hotbar(9).to_window_index(WindowKind::PlayerInventory), None);
+/// assert_eq!(PlayerSlot::Main(27).to_window_index(WindowKind::Generic { rows: 3 }), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PlayerSlot {
+    /// A hotbar slot, from 0 (leftmost) to 8.
+    Hotbar(u8),
+    /// A slot in the main inventory above the hotbar, from 0 (top left) to 26 (bottom right).
+    Main(u8),
+    /// An armor slot.
+    Armor(ArmorSlot),
+    /// The offhand slot.
+    Offhand,
+}
+
+impl PlayerSlot {
+    /// The number of slots in the player inventory.
+    pub const COUNT: usize = 9 + 27 + 4 + 1;
+
+    /// Iterates over all slots of the player inventory in [`Ord`] order.
+    pub fn all() -> impl Iterator<Item = PlayerSlot> {
+        (0..9).map(PlayerSlot::Hotbar)
+            .chain((0..27).map(PlayerSlot::Main))
+            .chain(ArmorSlot::ALL.iter().copied().map(PlayerSlot::Armor))
+            .chain(std::iter::once(PlayerSlot::Offhand))
+    }
+
+    /// Whether the index of a hotbar or main inventory slot is in range, i.e. 0–8 or 0–26, respectively.
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            PlayerSlot::Hotbar(idx) => idx < 9,
+            PlayerSlot::Main(idx) => idx < 27,
+            PlayerSlot::Armor(_) | PlayerSlot::Offhand => true,
+        }
+    }
+
+    /// The `Slot` byte used in the `Inventory` list of player NBT.
+    ///
+    /// The hotbar is 0–8, the main inventory 9–35, armor 100–103 (feet first), and the offhand −106. Returns `None` if the index is out of range, see [`PlayerSlot::is_valid`].
+    pub fn to_nbt_slot(&self) -> Option<i8> {
+        if !self.is_valid() { return None }
+        Some(match *self {
+            PlayerSlot::Hotbar(idx) => idx as i8,
+            PlayerSlot::Main(idx) => 9 + idx as i8,
+            PlayerSlot::Armor(slot) => 100 + slot.index() as i8,
+            PlayerSlot::Offhand => -106,
+        })
+    }
+
+    /// Looks up a slot by its `Slot` byte in player NBT. Returns `None` for bytes not used by the player inventory.
+    pub fn from_nbt_slot(slot: i8) -> Option<PlayerSlot> {
+        Some(match slot {
+            0..=8 => PlayerSlot::Hotbar(slot as u8),
+            9..=35 => PlayerSlot::Main(slot as u8 - 9),
+            100..=103 => PlayerSlot::Armor(ArmorSlot::ALL[(slot - 100) as usize]),
+            -106 => PlayerSlot::Offhand,
+            _ => return None,
+        })
+    }
+
+    /// The slot number used for this slot in window packets for the given kind of window.
+    ///
+    /// Returns `None` if the slot isn't part of that window, e.g. armor in a chest window, or if the index is out of range, see [`PlayerSlot::is_valid`].
+    pub fn to_window_index(&self, window: WindowKind) -> Option<u16> {
+        if !self.is_valid() { return None }
+        let inventory_start = match window {
+            WindowKind::PlayerInventory => 9,
+            WindowKind::Generic { rows } => 9 * u16::from(rows),
+        };
+        match (*self, window) {
+            (PlayerSlot::Main(idx), _) => Some(inventory_start + u16::from(idx)),
+            (PlayerSlot::Hotbar(idx), _) => Some(inventory_start + 27 + u16::from(idx)),
+            (PlayerSlot::Armor(slot), WindowKind::PlayerInventory) => Some(8 - u16::from(slot.index())),
+            (PlayerSlot::Offhand, WindowKind::PlayerInventory) => Some(45),
+            (PlayerSlot::Armor(_), WindowKind::Generic { .. }) | (PlayerSlot::Offhand, WindowKind::Generic { .. }) => None,
+        }
+    }
+
+    /// Looks up the player inventory slot for a slot number in window packets for the given kind of window.
+    ///
+    /// Returns `None` for slots which aren't part of the player inventory, such as the crafting grid or the container's own slots.
+    pub fn from_window_index(index: u16, window: WindowKind) -> Option<PlayerSlot> {
+        let inventory_start = match window {
+            WindowKind::PlayerInventory => match index {
+                5..=8 => return Some(PlayerSlot::Armor(ArmorSlot::ALL[usize::from(8 - index)])),
+                45 => return Some(PlayerSlot::Offhand),
+                _ => 9,
+            },
+            WindowKind::Generic { rows } => 9 * u16::from(rows),
+        };
+        let idx = u8::try_from(index.checked_sub(inventory_start)?).ok()?;
+        match idx {
+            0..=26 => Some(PlayerSlot::Main(idx)),
+            27..=35 => Some(PlayerSlot::Hotbar(idx - 27)),
+            _ => None,
+        }
+    }
+}
+
+/// One of the two hands of a player or mob.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[allow(missing_docs)] // variants are obvious
+pub enum Hand {
+    #[serde(rename = "mainhand")]
+    Main,
+    #[serde(rename = "offhand")]
+    Off,
+}
+
+impl Hand {
+    /// The name used in commands and NBT, e.g. `mainhand`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Hand::Main => "mainhand",
+            Hand::Off => "offhand",
+        }
+    }
+
+    /// The numeric ID used in packets like Use Item.
+    pub fn id(&self) -> i32 {
+        match self {
+            Hand::Main => 0,
+            Hand::Off => 1,
+        }
+    }
+
+    /// Looks up a hand by the numeric ID used in packets.
+    pub fn from_id(id: i32) -> Option<Hand> {
+        match id {
+            0 => Some(Hand::Main),
+            1 => Some(Hand::Off),
+            _ => None,
+        }
+    }
+}
+
+/// The error returned when parsing a [`Hand`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandParseError(pub String);
+
+impl fmt::Display for HandParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown hand: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for HandParseError {}
+
+impl FromStr for Hand {
+    type Err = HandParseError;
+
+    fn from_str(s: &str) -> Result<Hand, HandParseError> {
+        match s {
+            "mainhand" => Ok(Hand::Main),
+            "offhand" => Ok(Hand::Off),
+            _ => Err(HandParseError(s.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Whether an [`EquipmentSlot`] holds items in hand or is worn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EquipmentSlotType {
+    /// The main hand or offhand.
+    Hand,
+    /// Armor worn by players and humanoid mobs.
+    HumanoidArmor,
+    /// The body slot of animals like horses and wolves, added in 1.20.5.
+    AnimalArmor,
+}
+
+/// A slot in which an entity can hold or wear an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)] // variants are obvious
+pub enum EquipmentSlot {
+    MainHand,
+    OffHand,
+    Feet,
+    Legs,
+    Chest,
+    Head,
+    Body,
+}
+
+impl EquipmentSlot {
+    /// All equipment slots, in ID order.
+    pub const ALL: [EquipmentSlot; 7] = [EquipmentSlot::MainHand, EquipmentSlot::OffHand, EquipmentSlot::Feet, EquipmentSlot::Legs, EquipmentSlot::Chest, EquipmentSlot::Head, EquipmentSlot::Body];
+
+    /// The name used in commands and NBT, e.g. `mainhand`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EquipmentSlot::MainHand => "mainhand",
+            EquipmentSlot::OffHand => "offhand",
+            EquipmentSlot::Feet => "feet",
+            EquipmentSlot::Legs => "legs",
+            EquipmentSlot::Chest => "chest",
+            EquipmentSlot::Head => "head",
+            EquipmentSlot::Body => "body",
+        }
+    }
+
+    /// The numeric ID used in the Set Equipment packet.
+    pub fn id(&self) -> u8 {
+        match self {
+            EquipmentSlot::MainHand => 0,
+            EquipmentSlot::OffHand => 1,
+            EquipmentSlot::Feet => 2,
+            EquipmentSlot::Legs => 3,
+            EquipmentSlot::Chest => 4,
+            EquipmentSlot::Head => 5,
+            EquipmentSlot::Body => 6,
+        }
+    }
+
+    /// Looks up an equipment slot by the numeric ID used in the Set Equipment packet.
+    pub fn from_id(id: u8) -> Option<EquipmentSlot> {
+        EquipmentSlot::ALL.get(usize::from(id)).copied()
+    }
+
+    /// Whether this slot holds items in hand or is worn.
+    pub fn slot_type(&self) -> EquipmentSlotType {
+        match self {
+            EquipmentSlot::MainHand | EquipmentSlot::OffHand => EquipmentSlotType::Hand,
+            EquipmentSlot::Feet | EquipmentSlot::Legs | EquipmentSlot::Chest | EquipmentSlot::Head => EquipmentSlotType::HumanoidArmor,
+            EquipmentSlot::Body => EquipmentSlotType::AnimalArmor,
+        }
+    }
+
+    /// The slot in the player inventory corresponding to this equipment slot.
+    ///
+    /// The main hand is the hotbar slot with the given index, which is stored as `SelectedItemSlot` in player NBT. Returns `None` for the body slot, which players don't have, and for the main hand if the index is greater than 8.
+    pub fn to_player_slot(&self, selected_hotbar_slot: u8) -> Option<PlayerSlot> {
+        Some(match self {
+            EquipmentSlot::MainHand if selected_hotbar_slot < 9 => PlayerSlot::Hotbar(selected_hotbar_slot),
+            EquipmentSlot::MainHand => return None,
+            EquipmentSlot::OffHand => PlayerSlot::Offhand,
+            EquipmentSlot::Feet => PlayerSlot::Armor(ArmorSlot::Feet),
+            EquipmentSlot::Legs => PlayerSlot::Armor(ArmorSlot::Legs),
+            EquipmentSlot::Chest => PlayerSlot::Armor(ArmorSlot::Chest),
+            EquipmentSlot::Head => PlayerSlot::Armor(ArmorSlot::Head),
+            EquipmentSlot::Body => return None,
+        })
+    }
+}
+
+impl From<Hand> for EquipmentSlot {
+    fn from(hand: Hand) -> EquipmentSlot {
+        match hand {
+            Hand::Main => EquipmentSlot::MainHand,
+            Hand::Off => EquipmentSlot::OffHand,
+        }
+    }
+}
+
+impl From<ArmorSlot> for EquipmentSlot {
+    fn from(slot: ArmorSlot) -> EquipmentSlot {
+        match slot {
+            ArmorSlot::Feet => EquipmentSlot::Feet,
+            ArmorSlot::Legs => EquipmentSlot::Legs,
+            ArmorSlot::Chest => EquipmentSlot::Chest,
+            ArmorSlot::Head => EquipmentSlot::Head,
+        }
+    }
+}
+
+/// The error returned when parsing an [`EquipmentSlot`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EquipmentSlotParseError(pub String);
+
+impl fmt::Display for EquipmentSlotParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown equipment slot: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for EquipmentSlotParseError {}
+
+impl FromStr for EquipmentSlot {
+    type Err = EquipmentSlotParseError;
+
+    fn from_str(s: &str) -> Result<EquipmentSlot, EquipmentSlotParseError> {
+        EquipmentSlot::ALL.iter()
+            .find(|slot| slot.name() == s)
+            .copied()
+            .ok_or_else(|| EquipmentSlotParseError(s.to_owned()))
+    }
+}
+
+impl fmt::Display for EquipmentSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,244 @@
+//! This module contains a parser for [stringified NBT](https://minecraft.fandom.com/wiki/NBT_format#SNBT_format) (SNBT), the text form of NBT used in commands and older JSON formats.
+//!
+//! Parsed data is represented as [`serde_json::Value`] so it can be deserialized into this crate's types. Numeric type suffixes are dropped in the process, and `true`/`false` become the bytes 1 and 0.
+
+use {
+    std::fmt,
+    serde_json::{
+        Map,
+        Number,
+        Value,
+    },
+};
+
+/// An error that can occur when parsing SNBT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnbtError {
+    /// The byte offset in the input at which the error occurred.
+    pub position: usize,
+    /// What was expected at that position.
+    pub expected: &'static str,
+}
+
+impl fmt::Display for SnbtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid SNBT at position {}: expected {}", self.position, self.expected)
+    }
+}
+
+impl std::error::Error for SnbtError {}
+
+/// The maximum nesting depth of compounds and lists, as in vanilla's NBT reader.
+pub const MAX_DEPTH: usize = 512;
+
+/// Parses an SNBT value.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::snbt::{
+///     self,
+///     MAX_DEPTH,
+/// };
+///
+/// assert_eq!(snbt::from_str("{id:\"minecraft:stone\",Count:2b,tag:{Enchantments:[I;1,2]}}")?, serde_json::json!({
+///     "id": "minecraft:stone",
+///     "Count": 2,
+///     "tag": {"Enchantments": [1, 2]},
+/// }));
+/// // lists of non-ASCII text
+/// assert_eq!(snbt::from_str("[\"a€\", 'ü']")?, serde_json::json!(["a€", "ü"]));
+/// assert!(snbt::from_str("[a€]").is_err());
+/// assert!(snbt::from_str("[€]").is_err());
+///
+/// // deeply nested input is an error rather than a stack overflow
+/// let nested = |depth| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+/// assert!(snbt::from_str(&nested(MAX_DEPTH)).is_ok());
+/// assert_eq!(snbt::from_str(&nested(MAX_DEPTH + 1)).unwrap_err().expected, "at most 512 levels of nesting");
+/// assert!(snbt::from_str(&"[".repeat(200_000)).is_err());
+/// # Ok::<(), snbt::SnbtError>(())
+/// ```
+pub fn from_str(s: &str) -> Result<Value, SnbtError> {
+    let mut parser = Parser { input: s, pos: 0, depth: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos < s.len() { return Err(parser.error("end of input")) }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// The number of compounds and lists currently open.
+    depth: usize,
+}
+
+fn is_unquoted_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+')
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, expected: &'static str) -> SnbtError {
+        SnbtError { position: self.pos, expected }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.pos = self.input.len() - self.rest().trim_start().len();
+    }
+
+    fn expect(&mut self, c: char, expected: &'static str) -> Result<(), SnbtError> {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, SnbtError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.nested(Parser::compound),
+            Some('[') => self.nested(Parser::list),
+            Some('"') | Some('\'') => self.quoted_string().map(Value::String),
+            Some(_) => {
+                let token = self.unquoted_string()?;
+                Ok(parse_primitive(token))
+            }
+            None => Err(self.error("a value")),
+        }
+    }
+
+    /// Parses a compound or list, keeping track of the nesting depth.
+    fn nested(&mut self, parse: impl FnOnce(&mut Parser<'a>) -> Result<Value, SnbtError>) -> Result<Value, SnbtError> {
+        if self.depth >= MAX_DEPTH { return Err(self.error("at most 512 levels of nesting")) }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn unquoted_string(&mut self) -> Result<&'a str, SnbtError> {
+        let rest = self.rest();
+        let len = rest.find(|c| !is_unquoted_char(c)).unwrap_or(rest.len());
+        if len == 0 { return Err(self.error("a value")) }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    fn quoted_string(&mut self) -> Result<String, SnbtError> {
+        let quote = self.peek().ok_or_else(|| self.error("a quoted string"))?;
+        self.pos += 1;
+        let mut s = String::default();
+        let mut chars = self.rest().char_indices();
+        while let Some((i, c)) = chars.next() {
+            if c == quote {
+                self.pos += i + 1;
+                return Ok(s)
+            } else if c == '\\' {
+                match chars.next() {
+                    Some((_, escaped)) => s.push(escaped),
+                    None => break,
+                }
+            } else {
+                s.push(c);
+            }
+        }
+        self.pos = self.input.len();
+        Err(self.error("a closing quote"))
+    }
+
+    fn key(&mut self) -> Result<String, SnbtError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('"') | Some('\'') => self.quoted_string(),
+            _ => self.unquoted_string().map(str::to_owned).map_err(|_| self.error("a key")),
+        }
+    }
+
+    fn compound(&mut self) -> Result<Value, SnbtError> {
+        self.expect('{', "'{'")?;
+        let mut map = Map::default();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Value::Object(map))
+        }
+        loop {
+            let key = self.key()?;
+            self.expect(':', "':'")?;
+            let value = self.value()?;
+            map.insert(key, value);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some('}') => {
+                    self.pos += 1;
+                    return Ok(Value::Object(map))
+                }
+                _ => return Err(self.error("',' or '}'")),
+            }
+        }
+    }
+
+    fn list(&mut self) -> Result<Value, SnbtError> {
+        self.expect('[', "'['")?;
+        // typed arrays like [I; 1, 2, 3]
+        let rest = self.rest();
+        if rest.starts_with("B;") || rest.starts_with("I;") || rest.starts_with("L;") {
+            self.pos += 2;
+        }
+        let mut items = Vec::default();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(Value::Array(items))
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {
+                    self.pos += 1;
+                    return Ok(Value::Array(items))
+                }
+                _ => return Err(self.error("',' or ']'")),
+            }
+        }
+    }
+}
+
+/// Interprets an unquoted token as a number or boolean if possible, or as a string otherwise.
+fn parse_primitive(token: &str) -> Value {
+    match token {
+        "true" => return Value::from(1),
+        "false" => return Value::from(0),
+        _ => {}
+    }
+    let (digits, suffix) = match token.chars().last() {
+        Some(c) if matches!(c.to_ascii_lowercase(), 'b' | 's' | 'l' | 'f' | 'd') => (&token[..token.len() - 1], Some(c.to_ascii_lowercase())),
+        _ => (token, None),
+    };
+    match suffix {
+        Some('b') | Some('s') | Some('l') | None => if let Ok(n) = digits.parse::<i64>() {
+            return Value::from(n)
+        },
+        _ => {}
+    }
+    if matches!(suffix, Some('f') | Some('d')) || (suffix.is_none() && digits.contains('.')) {
+        if let Some(n) = digits.parse::<f64>().ok().and_then(Number::from_f64) {
+            return Value::Number(n)
+        }
+    }
+    Value::String(token.to_owned())
+}
@@ -0,0 +1,218 @@
+//! This module contains types for [sounds](https://minecraft.fandom.com/wiki/Sounds.json), as played using the `/playsound` command.
+
+use {
+    std::{
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::{
+        identifier::Identifier,
+        position::Vec3,
+    },
+};
+
+/// The volume slider which controls a sound, also called its source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)] // variants are obvious
+pub enum SoundCategory {
+    #[default]
+    Master,
+    Music,
+    Record,
+    Weather,
+    Block,
+    Hostile,
+    Neutral,
+    Player,
+    Ambient,
+    Voice,
+}
+
+impl SoundCategory {
+    /// All sound categories, in ID order.
+    pub const ALL: [SoundCategory; 10] = [
+        SoundCategory::Master,
+        SoundCategory::Music,
+        SoundCategory::Record,
+        SoundCategory::Weather,
+        SoundCategory::Block,
+        SoundCategory::Hostile,
+        SoundCategory::Neutral,
+        SoundCategory::Player,
+        SoundCategory::Ambient,
+        SoundCategory::Voice,
+    ];
+
+    /// The lowercase name used in commands, e.g. `master`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SoundCategory::Master => "master",
+            SoundCategory::Music => "music",
+            SoundCategory::Record => "record",
+            SoundCategory::Weather => "weather",
+            SoundCategory::Block => "block",
+            SoundCategory::Hostile => "hostile",
+            SoundCategory::Neutral => "neutral",
+            SoundCategory::Player => "player",
+            SoundCategory::Ambient => "ambient",
+            SoundCategory::Voice => "voice",
+        }
+    }
+}
+
+/// The error returned when parsing a [`SoundCategory`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SoundCategoryParseError(pub String);
+
+impl fmt::Display for SoundCategoryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown sound category: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for SoundCategoryParseError {}
+
+impl FromStr for SoundCategory {
+    type Err = SoundCategoryParseError;
+
+    fn from_str(s: &str) -> Result<SoundCategory, SoundCategoryParseError> {
+        SoundCategory::ALL.iter()
+            .find(|category| category.name() == s)
+            .copied()
+            .ok_or_else(|| SoundCategoryParseError(s.to_owned()))
+    }
+}
+
+impl fmt::Display for SoundCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A sound event, identified by its namespaced ID, e.g. `minecraft:entity.player.levelup`.
+///
+/// Constants are provided for some vanilla sounds which are commonly played by plugins and command-based systems.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Sound(pub Identifier);
+
+#[allow(missing_docs)] // constants are obvious
+impl Sound {
+    pub const UI_BUTTON_CLICK: Sound = Sound(Identifier::minecraft("ui.button.click"));
+    pub const UI_TOAST_CHALLENGE_COMPLETE: Sound = Sound(Identifier::minecraft("ui.toast.challenge_complete"));
+    pub const UI_TOAST_IN: Sound = Sound(Identifier::minecraft("ui.toast.in"));
+    pub const UI_TOAST_OUT: Sound = Sound(Identifier::minecraft("ui.toast.out"));
+    pub const ENTITY_PLAYER_LEVELUP: Sound = Sound(Identifier::minecraft("entity.player.levelup"));
+    pub const ENTITY_EXPERIENCE_ORB_PICKUP: Sound = Sound(Identifier::minecraft("entity.experience_orb.pickup"));
+    pub const ENTITY_ITEM_PICKUP: Sound = Sound(Identifier::minecraft("entity.item.pickup"));
+    pub const ENTITY_ARROW_HIT_PLAYER: Sound = Sound(Identifier::minecraft("entity.arrow.hit_player"));
+    pub const ENTITY_VILLAGER_YES: Sound = Sound(Identifier::minecraft("entity.villager.yes"));
+    pub const ENTITY_VILLAGER_NO: Sound = Sound(Identifier::minecraft("entity.villager.no"));
+    pub const ENTITY_ENDERMAN_TELEPORT: Sound = Sound(Identifier::minecraft("entity.enderman.teleport"));
+    pub const ENTITY_ENDER_DRAGON_GROWL: Sound = Sound(Identifier::minecraft("entity.ender_dragon.growl"));
+    pub const ENTITY_WITHER_SPAWN: Sound = Sound(Identifier::minecraft("entity.wither.spawn"));
+    pub const ENTITY_FIREWORK_ROCKET_LAUNCH: Sound = Sound(Identifier::minecraft("entity.firework_rocket.launch"));
+    pub const ENTITY_FIREWORK_ROCKET_BLAST: Sound = Sound(Identifier::minecraft("entity.firework_rocket.blast"));
+    pub const BLOCK_ANVIL_LAND: Sound = Sound(Identifier::minecraft("block.anvil.land"));
+    pub const BLOCK_BELL_USE: Sound = Sound(Identifier::minecraft("block.bell.use"));
+    pub const BLOCK_CHEST_OPEN: Sound = Sound(Identifier::minecraft("block.chest.open"));
+    pub const BLOCK_NOTE_BLOCK_HARP: Sound = Sound(Identifier::minecraft("block.note_block.harp"));
+    pub const BLOCK_NOTE_BLOCK_BASS: Sound = Sound(Identifier::minecraft("block.note_block.bass"));
+    pub const BLOCK_NOTE_BLOCK_BASEDRUM: Sound = Sound(Identifier::minecraft("block.note_block.basedrum"));
+    pub const BLOCK_NOTE_BLOCK_SNARE: Sound = Sound(Identifier::minecraft("block.note_block.snare"));
+    pub const BLOCK_NOTE_BLOCK_HAT: Sound = Sound(Identifier::minecraft("block.note_block.hat"));
+    pub const BLOCK_NOTE_BLOCK_BELL: Sound = Sound(Identifier::minecraft("block.note_block.bell"));
+    pub const BLOCK_NOTE_BLOCK_FLUTE: Sound = Sound(Identifier::minecraft("block.note_block.flute"));
+    pub const BLOCK_NOTE_BLOCK_CHIME: Sound = Sound(Identifier::minecraft("block.note_block.chime"));
+    pub const BLOCK_NOTE_BLOCK_GUITAR: Sound = Sound(Identifier::minecraft("block.note_block.guitar"));
+    pub const BLOCK_NOTE_BLOCK_XYLOPHONE: Sound = Sound(Identifier::minecraft("block.note_block.xylophone"));
+    pub const BLOCK_NOTE_BLOCK_IRON_XYLOPHONE: Sound = Sound(Identifier::minecraft("block.note_block.iron_xylophone"));
+    pub const BLOCK_NOTE_BLOCK_COW_BELL: Sound = Sound(Identifier::minecraft("block.note_block.cow_bell"));
+    pub const BLOCK_NOTE_BLOCK_DIDGERIDOO: Sound = Sound(Identifier::minecraft("block.note_block.didgeridoo"));
+    pub const BLOCK_NOTE_BLOCK_BIT: Sound = Sound(Identifier::minecraft("block.note_block.bit"));
+    pub const BLOCK_NOTE_BLOCK_BANJO: Sound = Sound(Identifier::minecraft("block.note_block.banjo"));
+    pub const BLOCK_NOTE_BLOCK_PLING: Sound = Sound(Identifier::minecraft("block.note_block.pling"));
+}
+
+impl From<Identifier> for Sound {
+    fn from(id: Identifier) -> Sound {
+        Sound(id)
+    }
+}
+
+impl From<Sound> for Identifier {
+    fn from(Sound(id): Sound) -> Identifier {
+        id
+    }
+}
+
+impl fmt::Display for Sound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// The lowest pitch at which sounds can be played.
+pub const MIN_PITCH: f32 = 0.5;
+/// The highest pitch at which sounds can be played.
+pub const MAX_PITCH: f32 = 2.0;
+
+/// The pitch at which a note block plays the given note, from 0 (F♯3 for harp) to 24 (F♯5), i.e. the number of times it has been clicked.
+///
+/// Notes above 24 are treated as 24.
+pub fn pitch_for_note(note: u8) -> f32 {
+    2.0f32.powf((f32::from(note.min(24)) - 12.0) / 12.0)
+}
+
+/// A sound with the settings it is played with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoundInstance {
+    /// Which sound is played.
+    pub sound: Sound,
+    /// The volume slider which controls the sound.
+    pub category: SoundCategory,
+    /// The volume, where 1 is normal. Values above 1 don't make the sound louder but make it audible from further away, 16 blocks per unit.
+    pub volume: f32,
+    /// The pitch, from [`MIN_PITCH`] to [`MAX_PITCH`], where 1 is normal.
+    pub pitch: f32,
+}
+
+impl SoundInstance {
+    /// Creates a sound instance with normal volume and pitch.
+    pub fn new(sound: Sound, category: SoundCategory) -> SoundInstance {
+        SoundInstance {
+            volume: 1.0,
+            pitch: 1.0,
+            sound, category,
+        }
+    }
+
+    /// Changes the volume. Negative values are treated as 0.
+    pub fn with_volume(mut self, volume: f32) -> SoundInstance {
+        self.volume = volume.max(0.0);
+        self
+    }
+
+    /// Changes the pitch, clamped to the range from [`MIN_PITCH`] to [`MAX_PITCH`].
+    pub fn with_pitch(mut self, pitch: f32) -> SoundInstance {
+        self.pitch = pitch.clamp(MIN_PITCH, MAX_PITCH);
+        self
+    }
+
+    /// The `/playsound` command (without leading slash) which plays this sound to the given target, e.g. `@a`.
+    ///
+    /// The sound is played at `pos`, or at the position of each targeted player if `None`. Players out of range of the sound hear it at `min_volume` from their direction instead, if given.
+    pub fn to_command(&self, target: &str, pos: Option<Vec3>, min_volume: Option<f32>) -> String {
+        let pos = pos.map_or_else(|| String::from("~ ~ ~"), |Vec3 { x, y, z }| format!("{} {} {}", x, y, z));
+        let mut command = format!("playsound {} {} {} {} {} {}", self.sound, self.category, target, pos, self.volume.max(0.0), self.pitch.clamp(MIN_PITCH, MAX_PITCH));
+        if let Some(min_volume) = min_volume {
+            command.push_str(&format!(" {}", min_volume.clamp(0.0, 1.0)));
+        }
+        command
+    }
+}
@@ -0,0 +1,165 @@
+//! This module contains types for [player statistics](https://minecraft.fandom.com/wiki/Statistics) as stored in `stats/<uuid>.json` in the world directory.
+
+use {
+    std::collections::BTreeMap,
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::{
+        identifier::Identifier,
+        ticks::Ticks,
+    },
+};
+
+registry_enum! {
+    /// A category of statistics, e.g. blocks mined or items crafted.
+    #[allow(missing_docs)] // variants are obvious
+    pub enum StatCategory {
+        Mined => "mined",
+        Crafted => "crafted",
+        Used => "used",
+        Broken => "broken",
+        PickedUp => "picked_up",
+        Dropped => "dropped",
+        Killed => "killed",
+        KilledBy => "killed_by",
+        Custom => "custom",
+    }
+}
+
+/// The `minecraft:custom` statistic for the time played, renamed from `play_one_minute` in 1.17.
+pub const PLAY_TIME: Identifier = Identifier::minecraft("play_time");
+/// The `minecraft:custom` statistic for the number of deaths.
+pub const DEATHS: Identifier = Identifier::minecraft("deaths");
+/// The `minecraft:custom` statistic for the number of jumps.
+pub const JUMP: Identifier = Identifier::minecraft("jump");
+/// The `minecraft:custom` statistic for the distance walked.
+pub const WALK_ONE_CM: Identifier = Identifier::minecraft("walk_one_cm");
+/// The `minecraft:custom` statistic for the distance flown with elytra.
+pub const AVIATE_ONE_CM: Identifier = Identifier::minecraft("aviate_one_cm");
+
+/// The number of centimeters in a block, for converting distance statistics.
+pub const CM_PER_BLOCK: f64 = 100.0;
+
+/// The contents of a player's statistics file.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::{
+///     stats::{
+///         self,
+///         StatCategory,
+///         Statistics,
+///     },
+///     ticks::Ticks,
+/// };
+///
+/// let json = r#"{"stats":{"minecraft:custom":{"minecraft:jump":10,"minecraft:play_time":72000,"minecraft:walk_one_cm":25050}},"DataVersion":3953}"#;
+/// let before = serde_json::from_str::<Statistics>(json)?;
+/// assert_eq!(serde_json::to_string(&before)?, json);
+/// assert_eq!(before.play_time(), Ticks::MINUTE * 60);
+/// assert_eq!(before.distance_blocks(&stats::WALK_ONE_CM), 250.5);
+///
+/// let mut after = before.clone();
+/// after.add(StatCategory::Custom, stats::JUMP, 5);
+/// after.add(StatCategory::Mined, "minecraft:stone".parse().unwrap(), 3);
+/// let session = after.diff(&before);
+/// assert_eq!(session.jumps(), 5);
+/// assert_eq!(session.get(&StatCategory::Mined, &"minecraft:stone".parse().unwrap()), 3);
+/// assert_eq!(session.play_time(), Ticks::ZERO);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct Statistics {
+    /// The values of the statistics by category.
+    #[serde(default)]
+    pub stats: BTreeMap<StatCategory, BTreeMap<Identifier, i64>>,
+    /// The data version of the game which wrote the file.
+    #[serde(rename = "DataVersion", default, skip_serializing_if = "Option::is_none")]
+    pub data_version: Option<i32>,
+}
+
+impl Statistics {
+    /// The value of the given statistic, or 0 if it's not recorded.
+    pub fn get(&self, category: &StatCategory, stat: &Identifier) -> i64 {
+        self.stats.get(category).and_then(|stats| stats.get(stat)).copied().unwrap_or(0)
+    }
+
+    /// The value of the given statistic in the `minecraft:custom` category, or 0 if it's not recorded.
+    pub fn custom(&self, stat: &Identifier) -> i64 {
+        self.get(&StatCategory::Custom, stat)
+    }
+
+    /// Adds to the value of the given statistic.
+    pub fn add(&mut self, category: StatCategory, stat: Identifier, value: i64) -> &mut Statistics {
+        *self.stats.entry(category).or_default().entry(stat).or_default() += value;
+        self
+    }
+
+    /// The time played, also accepting the name used before 1.17.
+    pub fn play_time(&self) -> Ticks {
+        Ticks(self.custom(&PLAY_TIME) + self.custom(&Identifier::minecraft("play_one_minute")))
+    }
+
+    /// The number of deaths.
+    pub fn deaths(&self) -> i64 {
+        self.custom(&DEATHS)
+    }
+
+    /// The number of jumps.
+    pub fn jumps(&self) -> i64 {
+        self.custom(&JUMP)
+    }
+
+    /// The value of a distance statistic like [`WALK_ONE_CM`] in blocks, which are one meter.
+    pub fn distance_blocks(&self, stat: &Identifier) -> f64 {
+        self.custom(stat) as f64 / CM_PER_BLOCK
+    }
+
+    /// The total distance travelled by any means in centimeters, i.e. the sum of all `minecraft:custom` statistics ending in `_one_cm`.
+    pub fn total_distance_cm(&self) -> i64 {
+        self.stats.get(&StatCategory::Custom).map_or(0, |stats| stats.iter()
+            .filter(|(stat, _)| stat.is_vanilla() && stat.path().ends_with("_one_cm"))
+            .map(|(_, value)| value)
+            .sum()
+        )
+    }
+
+    /// Adds the values of all statistics in `other` to this one, e.g. to aggregate statistics of multiple players or worlds.
+    ///
+    /// The data version is kept unless this one has none.
+    pub fn merge(&mut self, other: &Statistics) {
+        for (category, stats) in &other.stats {
+            for (stat, value) in stats {
+                self.add(category.clone(), stat.clone(), *value);
+            }
+        }
+        if self.data_version.is_none() { self.data_version = other.data_version }
+    }
+
+    /// The changes from `earlier` to this one, e.g. the statistics gained during a play session.
+    ///
+    /// Statistics which did not change are omitted.
+    pub fn diff(&self, earlier: &Statistics) -> Statistics {
+        let mut diff = Statistics {
+            stats: BTreeMap::default(),
+            data_version: self.data_version,
+        };
+        for (category, stats) in &self.stats {
+            for (stat, value) in stats {
+                let delta = value - earlier.get(category, stat);
+                if delta != 0 { diff.add(category.clone(), stat.clone(), delta); }
+            }
+        }
+        for (category, stats) in &earlier.stats {
+            for (stat, value) in stats {
+                if self.stats.get(category).is_none_or(|stats| !stats.contains_key(stat)) && *value != 0 {
+                    diff.add(category.clone(), stat.clone(), -value);
+                }
+            }
+        }
+        diff
+    }
+}
@@ -0,0 +1,215 @@
+//! This module contains types for [tag](https://minecraft.fandom.com/wiki/Tag) files in data packs.
+
+use {
+    std::{
+        collections::{
+            BTreeMap,
+            BTreeSet,
+        },
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::Error as _,
+        ser::SerializeStruct as _,
+    },
+    crate::identifier::{
+        Identifier,
+        IdentifierParseError,
+    },
+};
+
+/// A reference in a tag's `values` list, either to a registry entry or to another tag.
+///
+/// The string form is the ID, prefixed with `#` for tags.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TagValue {
+    /// A single registry entry, e.g. `minecraft:stone`.
+    Element(Identifier),
+    /// All entries of another tag, e.g. `#minecraft:logs`.
+    Tag(Identifier),
+}
+
+impl FromStr for TagValue {
+    type Err = IdentifierParseError;
+
+    fn from_str(s: &str) -> Result<TagValue, IdentifierParseError> {
+        Ok(if let Some(tag) = s.strip_prefix('#') {
+            TagValue::Tag(tag.parse()?)
+        } else {
+            TagValue::Element(s.parse()?)
+        })
+    }
+}
+
+impl fmt::Display for TagValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TagValue::Element(id) => id.fmt(f),
+            TagValue::Tag(id) => write!(f, "#{}", id),
+        }
+    }
+}
+
+impl Serialize for TagValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TagValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<TagValue, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+}
+
+/// An entry in a tag's `values` list.
+///
+/// Serializes as the plain [`TagValue`] string if required, and as `{"id": …, "required": false}` otherwise. Deserialization accepts both forms, with `required` defaulting to `true`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TagEntry {
+    /// The referenced entry or tag.
+    pub id: TagValue,
+    /// Whether the tag fails to load if the referenced entry or tag doesn't exist. If `false`, the entry is skipped instead.
+    pub required: bool,
+}
+
+impl From<TagValue> for TagEntry {
+    /// Creates a required entry.
+    fn from(id: TagValue) -> TagEntry {
+        TagEntry { id, required: true }
+    }
+}
+
+impl Serialize for TagEntry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.required {
+            self.id.serialize(serializer)
+        } else {
+            let mut state = serializer.serialize_struct("TagEntry", 2)?;
+            state.serialize_field("id", &self.id)?;
+            state.serialize_field("required", &self.required)?;
+            state.end()
+        }
+    }
+}
+
+fn default_true() -> bool { true }
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TagEntryRepr {
+    Id(TagValue),
+    Object {
+        id: TagValue,
+        #[serde(default = "default_true")]
+        required: bool,
+    },
+}
+
+impl<'de> Deserialize<'de> for TagEntry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<TagEntry, D::Error> {
+        Ok(match TagEntryRepr::deserialize(deserializer)? {
+            TagEntryRepr::Id(id) => TagEntry { id, required: true },
+            TagEntryRepr::Object { id, required } => TagEntry { id, required },
+        })
+    }
+}
+
+/// The contents of a tag file, e.g. `data/minecraft/tags/block/logs.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct TagFile {
+    /// Whether this file replaces the entries from lower-priority data packs instead of adding to them.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub replace: bool,
+    /// The entries of the tag.
+    pub values: Vec<TagEntry>,
+}
+
+/// An error that can occur in [`resolve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagError {
+    /// A required tag reference pointed to a tag which doesn't exist.
+    MissingTag(Identifier),
+    /// Tags reference each other in a cycle. The first and last tags in the list are the same.
+    Cycle(Vec<Identifier>),
+}
+
+impl fmt::Display for TagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TagError::MissingTag(id) => write!(f, "reference to missing tag #{}", id),
+            TagError::Cycle(cycle) => {
+                write!(f, "cyclic tag references: ")?;
+                for (idx, id) in cycle.iter().enumerate() {
+                    if idx > 0 { write!(f, " → ")? }
+                    write!(f, "#{}", id)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for TagError {}
+
+/// Returns the registry entries of the tag `name`, with references to other tags in `tags` flattened.
+///
+/// Entries are returned in order of first appearance without duplicates. Optional references to missing tags are skipped. Since the registry isn't known here, element entries are not checked for existence.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use minecraft::{
+///     identifier::Identifier,
+///     tag::{
+///         TagError,
+///         TagFile,
+///         resolve,
+///     },
+/// };
+///
+/// let mut tags = BTreeMap::default();
+/// tags.insert(Identifier::minecraft("a"), serde_json::from_str::<TagFile>(r##"{"values": ["minecraft:stone", "#minecraft:b", {"id": "#minecraft:missing", "required": false}]}"##)?);
+/// tags.insert(Identifier::minecraft("b"), serde_json::from_str::<TagFile>(r#"{"values": ["dirt", "stone"]}"#)?);
+/// assert_eq!(resolve(&tags, &Identifier::minecraft("a"))?, vec![Identifier::minecraft("stone"), Identifier::minecraft("dirt")]);
+///
+/// tags.insert(Identifier::minecraft("b"), serde_json::from_str::<TagFile>(r##"{"values": ["#a"]}"##)?);
+/// assert_eq!(resolve(&tags, &Identifier::minecraft("a")), Err(TagError::Cycle(vec![Identifier::minecraft("a"), Identifier::minecraft("b"), Identifier::minecraft("a")])));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn resolve(tags: &BTreeMap<Identifier, TagFile>, name: &Identifier) -> Result<Vec<Identifier>, TagError> {
+    fn visit(tags: &BTreeMap<Identifier, TagFile>, file: &TagFile, path: &mut Vec<Identifier>, seen: &mut BTreeSet<Identifier>, entries: &mut Vec<Identifier>) -> Result<(), TagError> {
+        for entry in &file.values {
+            match &entry.id {
+                TagValue::Element(id) => if seen.insert(id.clone()) { entries.push(id.clone()) },
+                TagValue::Tag(id) => {
+                    if let Some(start) = path.iter().position(|tag| tag == id) {
+                        let mut cycle = path[start..].to_vec();
+                        cycle.push(id.clone());
+                        return Err(TagError::Cycle(cycle))
+                    }
+                    match tags.get(id) {
+                        Some(file) => {
+                            path.push(id.clone());
+                            visit(tags, file, path, seen, entries)?;
+                            path.pop();
+                        }
+                        None => if entry.required { return Err(TagError::MissingTag(id.clone())) },
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    let file = tags.get(name).ok_or_else(|| TagError::MissingTag(name.clone()))?;
+    let mut entries = Vec::default();
+    visit(tags, file, &mut vec![name.clone()], &mut BTreeSet::default(), &mut entries)?;
+    Ok(entries)
+}
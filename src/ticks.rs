@@ -0,0 +1,169 @@
+//! This module contains the [`Ticks`] type, representing durations measured in [game ticks](https://minecraft.fandom.com/wiki/Tick).
+
+use {
+    std::{
+        fmt,
+        ops::{
+            Add,
+            AddAssign,
+            Div,
+            Mul,
+            Sub,
+            SubAssign,
+        },
+        str::FromStr,
+        time::Duration,
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+};
+
+/// A duration in game ticks, of which there are 20 per second at normal speed.
+///
+/// Arithmetic saturates instead of overflowing, since “infinite” durations like `i32::MAX` appear in real data. Serializes as a plain integer.
+///
+/// The [`FromStr`] and [`Display`](fmt::Display) impls use the syntax of the `time` command argument type, e.g. `5s`, `3d`, or `100t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Ticks(pub i64);
+
+impl Ticks {
+    /// A duration of zero.
+    pub const ZERO: Ticks = Ticks(0);
+    /// A single tick.
+    pub const TICK: Ticks = Ticks(1);
+    /// One second, i.e. 20 ticks.
+    pub const SECOND: Ticks = Ticks(20);
+    /// One minute, i.e. 1200 ticks.
+    pub const MINUTE: Ticks = Ticks(1200);
+    /// One Minecraft day, i.e. 24000 ticks or 20 real-time minutes.
+    pub const DAY: Ticks = Ticks(24000);
+
+    /// The real-time duration at the normal rate of 20 ticks per second.
+    ///
+    /// Negative durations are clamped to zero.
+    pub fn to_duration(self) -> Duration {
+        Duration::from_millis((self.0.max(0) as u64).saturating_mul(50))
+    }
+
+    /// The number of whole seconds in this duration.
+    pub fn as_secs(self) -> i64 {
+        self.0 / 20
+    }
+}
+
+impl From<Duration> for Ticks {
+    /// Converts a real-time duration to the nearest number of ticks, with half-tick (25ms) durations rounding up.
+    ///
+    /// Durations too long to represent saturate to `i64::MAX` ticks.
+    fn from(duration: Duration) -> Ticks {
+        let ticks = (duration.as_millis() + 25) / 50;
+        Ticks(if ticks > i64::MAX as u128 { i64::MAX } else { ticks as i64 })
+    }
+}
+
+impl Add for Ticks {
+    type Output = Ticks;
+
+    fn add(self, rhs: Ticks) -> Ticks {
+        Ticks(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl AddAssign for Ticks {
+    fn add_assign(&mut self, rhs: Ticks) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Ticks {
+    type Output = Ticks;
+
+    fn sub(self, rhs: Ticks) -> Ticks {
+        Ticks(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl SubAssign for Ticks {
+    fn sub_assign(&mut self, rhs: Ticks) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Ticks {
+    type Output = Ticks;
+
+    fn mul(self, rhs: i64) -> Ticks {
+        Ticks(self.0.saturating_mul(rhs))
+    }
+}
+
+impl Div<i64> for Ticks {
+    type Output = Ticks;
+
+    /// # Panics
+    ///
+    /// If `rhs` is zero.
+    fn div(self, rhs: i64) -> Ticks {
+        Ticks(self.0.saturating_div(rhs))
+    }
+}
+
+/// An error that can occur when parsing [`Ticks`] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TicksParseError {
+    /// The number part was missing or not a valid number.
+    InvalidNumber(String),
+    /// The duration was negative.
+    Negative,
+}
+
+impl fmt::Display for TicksParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TicksParseError::InvalidNumber(s) => write!(f, "invalid duration: {:?}", s),
+            TicksParseError::Negative => write!(f, "duration must not be negative"),
+        }
+    }
+}
+
+impl std::error::Error for TicksParseError {}
+
+impl FromStr for Ticks {
+    type Err = TicksParseError;
+
+    /// Parses a number with an optional unit suffix: `t` for ticks (the default), `s` for seconds, or `d` for Minecraft days.
+    ///
+    /// Fractional numbers are allowed and rounded to the nearest tick.
+    fn from_str(s: &str) -> Result<Ticks, TicksParseError> {
+        let (number, unit) = match s.chars().last() {
+            Some('t') => (&s[..s.len() - 1], Ticks::TICK),
+            Some('s') => (&s[..s.len() - 1], Ticks::SECOND),
+            Some('d') => (&s[..s.len() - 1], Ticks::DAY),
+            _ => (s, Ticks::TICK),
+        };
+        if let Ok(number) = number.parse::<i64>() {
+            if number < 0 { return Err(TicksParseError::Negative) }
+            Ok(unit * number)
+        } else {
+            let number = number.parse::<f64>().ok().filter(|number| number.is_finite()).ok_or_else(|| TicksParseError::InvalidNumber(s.to_owned()))?;
+            if number < 0.0 { return Err(TicksParseError::Negative) }
+            Ok(Ticks((number * unit.0 as f64).round() as i64))
+        }
+    }
+}
+
+impl fmt::Display for Ticks {
+    /// Formats using the largest unit which represents the duration exactly.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 != 0 && self.0 % Ticks::DAY.0 == 0 {
+            write!(f, "{}d", self.0 / Ticks::DAY.0)
+        } else if self.0 != 0 && self.0 % Ticks::SECOND.0 == 0 {
+            write!(f, "{}s", self.0 / Ticks::SECOND.0)
+        } else {
+            write!(f, "{}t", self.0)
+        }
+    }
+}
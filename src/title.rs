@@ -0,0 +1,108 @@
+//! This module contains types for [titles](https://minecraft.fandom.com/wiki/Commands/title), the large text shown in the middle of the screen, and the action bar.
+
+use {
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::{
+        chat::Chat,
+        ticks::Ticks,
+    },
+};
+
+/// How long a title fades in, stays on screen, and fades out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct TitleTimes {
+    /// How long the title takes to fade in.
+    pub fade_in: Ticks,
+    /// How long the title is shown at full opacity.
+    pub stay: Ticks,
+    /// How long the title takes to fade out.
+    pub fade_out: Ticks,
+}
+
+impl TitleTimes {
+    /// Creates title timings from the given durations.
+    pub fn new(fade_in: Ticks, stay: Ticks, fade_out: Ticks) -> TitleTimes {
+        TitleTimes { fade_in, stay, fade_out }
+    }
+
+    /// The total time the title is visible.
+    pub fn total(&self) -> Ticks {
+        self.fade_in + self.stay + self.fade_out
+    }
+}
+
+impl Default for TitleTimes {
+    /// The vanilla defaults: 10 ticks fade in, 70 ticks stay, 20 ticks fade out.
+    fn default() -> TitleTimes {
+        TitleTimes::new(Ticks(10), Ticks(70), Ticks(20))
+    }
+}
+
+/// A title, subtitle, and action bar message to be shown together, along with optional timings.
+///
+/// Each part is optional. A subtitle is only shown along with a title.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Title {
+    /// The large text in the middle of the screen.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<Chat>,
+    /// The smaller text below the title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<Chat>,
+    /// The text above the hotbar.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actionbar: Option<Chat>,
+    /// The timings for the title, or `None` to keep those currently set for the player.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub times: Option<TitleTimes>,
+}
+
+impl Title {
+    /// Sets the title.
+    pub fn with_title(mut self, title: impl Into<Chat>) -> Title {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the subtitle.
+    pub fn with_subtitle(mut self, subtitle: impl Into<Chat>) -> Title {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    /// Sets the action bar text.
+    pub fn with_actionbar(mut self, actionbar: impl Into<Chat>) -> Title {
+        self.actionbar = Some(actionbar.into());
+        self
+    }
+
+    /// Sets the timings.
+    pub fn with_times(mut self, times: TitleTimes) -> Title {
+        self.times = Some(times);
+        self
+    }
+
+    /// The `/title` commands (without leading slashes) which show this title to the given target, e.g. `@a`.
+    ///
+    /// The timings and subtitle are sent before the title, since showing the title uses the timings and subtitle which are set at that point.
+    pub fn to_commands(&self, target: &str) -> Vec<String> {
+        let json = |chat: &Chat| chat.to_json().unwrap_or_else(|_| String::from("\"\""));
+        let mut commands = Vec::default();
+        if let Some(TitleTimes { fade_in, stay, fade_out }) = self.times {
+            commands.push(format!("title {} times {} {} {}", target, fade_in.0, stay.0, fade_out.0));
+        }
+        if let Some(ref subtitle) = self.subtitle {
+            commands.push(format!("title {} subtitle {}", target, json(subtitle)));
+        }
+        if let Some(ref title) = self.title {
+            commands.push(format!("title {} title {}", target, json(title)));
+        }
+        if let Some(ref actionbar) = self.actionbar {
+            commands.push(format!("title {} actionbar {}", target, json(actionbar)));
+        }
+        commands
+    }
+}
@@ -0,0 +1,99 @@
+//! This module contains the [`UserCache`] type, representing the `usercache.json` file of a server, which caches the UUIDs of player names.
+
+use {
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    uuid::Uuid,
+    crate::server_lists::Timestamp,
+};
+
+/// The maximum number of entries vanilla keeps in the user cache.
+pub const MAX_ENTRIES: usize = 1000;
+
+/// An entry in `usercache.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserCacheEntry {
+    /// The player's name at the time of caching.
+    pub name: String,
+    /// The player's UUID.
+    pub uuid: Uuid,
+    /// When the entry should no longer be used. Vanilla sets this to one month after the lookup.
+    pub expires_on: Timestamp,
+}
+
+impl UserCacheEntry {
+    /// Whether the entry has expired at the given time.
+    pub fn is_expired(&self, now: &Timestamp) -> bool {
+        now.unix_seconds() >= self.expires_on.unix_seconds()
+    }
+}
+
+/// The contents of `usercache.json`, ordered from most to least recently used like vanilla writes it.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::user_cache::{
+///     UserCache,
+///     UserCacheEntry,
+/// };
+///
+/// let json = r#"[{"name":"Notch","uuid":"069a79f4-44e9-4726-a5be-fca90e38aaf5","expiresOn":"2024-07-13 18:04:22 +0200"}]"#;
+/// let mut cache = serde_json::from_str::<UserCache>(json)?;
+/// assert_eq!(serde_json::to_string(&cache)?, json);
+/// let entry = cache.by_name("notch").unwrap().clone();
+/// assert!(!entry.is_expired(&"2024-07-13 16:04:21 +0000".parse()?));
+/// assert!(entry.is_expired(&"2024-07-13 16:04:22 +0000".parse()?));
+///
+/// // inserting an entry with the same UUID replaces the old one
+/// cache.insert(UserCacheEntry { name: format!("Notch2"), ..entry });
+/// assert_eq!(cache.0.len(), 1);
+/// assert!(cache.by_name("Notch").is_none());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct UserCache(pub Vec<UserCacheEntry>);
+
+impl UserCache {
+    /// Looks up the entry for the given player name, case-insensitively like vanilla.
+    pub fn by_name(&self, name: &str) -> Option<&UserCacheEntry> {
+        self.0.iter().find(|entry| entry.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Looks up the entry for the given UUID.
+    pub fn by_uuid(&self, uuid: Uuid) -> Option<&UserCacheEntry> {
+        self.0.iter().find(|entry| entry.uuid == uuid)
+    }
+
+    /// Adds an entry as the most recently used, replacing any entries with the same name or UUID.
+    ///
+    /// If this brings the number of entries above [`MAX_ENTRIES`], the least recently used ones are removed.
+    pub fn insert(&mut self, entry: UserCacheEntry) {
+        self.0.retain(|existing| existing.uuid != entry.uuid && !existing.name.eq_ignore_ascii_case(&entry.name));
+        self.0.insert(0, entry);
+        self.0.truncate(MAX_ENTRIES);
+    }
+
+    /// Marks the entry for the given UUID as the most recently used, as vanilla does when it's looked up.
+    ///
+    /// Returns `false` if there is no such entry.
+    pub fn touch(&mut self, uuid: Uuid) -> bool {
+        match self.0.iter().position(|entry| entry.uuid == uuid) {
+            Some(idx) => {
+                let entry = self.0.remove(idx);
+                self.0.insert(0, entry);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes all entries which have expired at the given time.
+    pub fn remove_expired(&mut self, now: &Timestamp) {
+        self.0.retain(|entry| !entry.is_expired(now));
+    }
+}
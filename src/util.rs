@@ -0,0 +1,383 @@
+//! Helpers shared by multiple modules.
+
+use std::{
+    convert::TryFrom,
+    fmt,
+    io,
+    str,
+};
+
+/// The roman numeral for `n` as used for enchantment and effect levels, or `None` outside of 1–10, where vanilla falls back to the number.
+pub(crate) fn roman_numeral(n: u32) -> Option<&'static str> {
+    ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X"].get(usize::try_from(n).ok()?.checked_sub(1)?).copied()
+}
+
+/// Guesses an English name from a registry path, e.g. `luck_of_the_sea` becomes `Luck of the Sea`.
+pub(crate) fn title_case(path: &str) -> String {
+    path.split('_').enumerate().map(|(i, word)| match word {
+        "of" | "the" if i > 0 => word.to_owned(),
+        _ => {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+        }
+    }).collect::<Vec<_>>().join(" ")
+}
+
+/// The squared Euclidean distance between two RGB colors, for finding the nearest color in a palette.
+pub(crate) fn color_distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter().zip(&b).map(|(&a, &b)| u32::from(a.abs_diff(b)).pow(2)).sum()
+}
+
+/// The advance width in pixels of a character in the default font, including the gap to the next character.
+///
+/// Characters outside the widths known here, including non-ASCII characters, are assumed to be as wide as most letters.
+pub(crate) fn char_width(c: char, bold: bool) -> u32 {
+    let width = match c {
+        ' ' => return 4,
+        '!' | '\'' | ',' | '.' | ':' | ';' | 'i' | '|' => 2,
+        '`' | 'l' => 3,
+        'I' | '[' | ']' | 't' => 4,
+        '"' | '(' | ')' | '*' | '<' | '>' | 'f' | 'k' | '{' | '}' => 5,
+        '@' | '~' => 7,
+        _ => 6,
+    };
+    if bold { width + 1 } else { width }
+}
+
+/// The number of days since 1970-01-01 of a date in the proleptic Gregorian calendar, see <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+pub(crate) fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The date `days` days after 1970-01-01 as year, month, and day, the inverse of [`days_from_civil`].
+pub(crate) fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u8;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u8;
+    (year_of_era + era * 400 + i64::from(month <= 2), month, day)
+}
+
+/// Adapts a [`fmt::Write`] such as a [`fmt::Formatter`] or [`String`] to [`io::Write`], so serializers can write into it directly.
+///
+/// Writes are buffered so the inner writer is called with larger strings, which also allows characters to be split across writes. [`io::Write::flush`] must be called at the end. Bytes must be valid UTF-8, and errors from the inner writer are returned as [`io::ErrorKind::Other`].
+pub(crate) struct FmtWriter<W: fmt::Write> {
+    inner: W,
+    buf: [u8; 1024],
+    len: usize,
+}
+
+impl<W: fmt::Write> FmtWriter<W> {
+    pub(crate) fn new(inner: W) -> FmtWriter<W> {
+        FmtWriter { inner, buf: [0; 1024], len: 0 }
+    }
+
+    /// Writes the buffered bytes to the inner writer, except for an incomplete character at the end.
+    fn drain(&mut self) -> io::Result<()> {
+        let FmtWriter { inner, buf, len } = self;
+        let valid = match str::from_utf8(&buf[..*len]) {
+            Ok(valid) => valid,
+            Err(e) if e.error_len().is_none() => str::from_utf8(&buf[..e.valid_up_to()]).expect("checked to be valid"),
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        };
+        inner.write_str(valid).map_err(|fmt::Error| io::Error::other("formatter error"))?;
+        let valid_len = valid.len();
+        buf.copy_within(valid_len..*len, 0);
+        *len -= valid_len;
+        Ok(())
+    }
+}
+
+impl<W: fmt::Write> io::Write for FmtWriter<W> {
+    fn write(&mut self, mut bytes: &[u8]) -> io::Result<usize> {
+        let total = bytes.len();
+        if let Some(free) = self.buf.get_mut(self.len..self.len + total) {
+            free.copy_from_slice(bytes);
+            self.len += total;
+            return Ok(total)
+        }
+        while !bytes.is_empty() {
+            let n = bytes.len().min(self.buf.len() - self.len);
+            self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+            self.len += n;
+            bytes = &bytes[n..];
+            if self.len == self.buf.len() { self.drain()? }
+        }
+        Ok(total)
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.write(bytes).map(|_| ())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.drain()?;
+        if self.len > 0 { return Err(io::Error::new(io::ErrorKind::InvalidData, "incomplete UTF-8 character")) }
+        Ok(())
+    }
+}
+
+/// An [`io::Write`] which discards the bytes written to it and counts them.
+#[derive(Default)]
+pub(crate) struct CountingWriter(pub(crate) usize);
+
+impl io::Write for CountingWriter {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.0 += bytes.len();
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+/// (De)serializes a `bool` which may be stored as a byte, as in NBT converted to JSON.
+///
+/// Serializes as a JSON boolean. Deserializes from a boolean or an integer, where any nonzero value is `true`.
+pub(crate) mod nbt_bool {
+    use {
+        std::fmt,
+        serde::{
+            Deserializer,
+            Serializer,
+            de::{
+                self,
+                Visitor,
+            },
+        },
+    };
+
+    struct NbtBoolVisitor;
+
+    impl<'de> Visitor<'de> for NbtBoolVisitor {
+        type Value = bool;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a boolean or a byte")
+        }
+
+        fn visit_bool<E: de::Error>(self, v: bool) -> Result<bool, E> { Ok(v) }
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<bool, E> { Ok(v != 0) }
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<bool, E> { Ok(v != 0) }
+    }
+
+    pub(crate) fn serialize<S: Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(*value)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+        deserializer.deserialize_any(NbtBoolVisitor)
+    }
+}
+
+/// (De)serializes a `u8` which may be stored as a signed byte, as in NBT.
+///
+/// Serializes as an unsigned integer. Deserializes from any integer from −128 to 255, where negative values wrap around like vanilla's `& 0xFF`, so `-1b` is 255.
+pub(crate) mod unsigned_byte {
+    use {
+        std::{
+            convert::TryFrom as _,
+            fmt,
+        },
+        serde::{
+            Deserializer,
+            Serializer,
+            de::{
+                self,
+                Visitor,
+            },
+        },
+    };
+
+    struct UnsignedByteVisitor;
+
+    impl<'de> Visitor<'de> for UnsignedByteVisitor {
+        type Value = u8;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a signed or unsigned byte")
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<u8, E> {
+            i8::try_from(v).map(|v| v as u8).or_else(|_| u8::try_from(v)).map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<u8, E> {
+            u8::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+        }
+    }
+
+    pub(crate) fn serialize<S: Serializer>(value: &u8, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(*value)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u8, D::Error> {
+        deserializer.deserialize_any(UnsignedByteVisitor)
+    }
+}
+
+/// (De)serializes a UUID as four 32-bit integers, most significant first, as in NBT since 1.16.
+///
+/// Deserialization also accepts the hyphenated string form.
+pub(crate) mod uuid_ints {
+    use {
+        std::fmt,
+        serde::{
+            Deserializer,
+            Serializer,
+            de::{
+                self,
+                SeqAccess,
+                Visitor,
+            },
+            ser::SerializeSeq as _,
+        },
+        uuid::Uuid,
+    };
+
+    struct UuidIntsVisitor;
+
+    impl<'de> Visitor<'de> for UuidIntsVisitor {
+        type Value = Uuid;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a UUID as a string or an array of four integers")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Uuid, E> {
+            v.parse().map_err(E::custom)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Uuid, A::Error> {
+            let mut value = 0u128;
+            for idx in 0..4 {
+                let part = seq.next_element::<i32>()?.ok_or_else(|| de::Error::invalid_length(idx, &self))?;
+                value = value << 32 | u128::from(part as u32);
+            }
+            if seq.next_element::<de::IgnoredAny>()?.is_some() { return Err(de::Error::invalid_length(5, &self)) }
+            Ok(Uuid::from_u128(value))
+        }
+    }
+
+    pub(crate) fn serialize<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = uuid.as_u128();
+        let mut seq = serializer.serialize_seq(Some(4))?;
+        for shift in [96, 64, 32, 0] {
+            seq.serialize_element(&((value >> shift) as u32 as i32))?;
+        }
+        seq.end()
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+        deserializer.deserialize_any(UuidIntsVisitor)
+    }
+}
+
+/// Deserializes an optional `i32` which may also be given as a numeric string, as in item data converted from NBT.
+pub(crate) mod lenient_i32 {
+    use {
+        std::{
+            convert::TryFrom as _,
+            fmt,
+        },
+        serde::{
+            Deserializer,
+            de::{
+                self,
+                Unexpected,
+                Visitor,
+            },
+        },
+    };
+
+    struct LenientI32Visitor;
+
+    impl<'de> Visitor<'de> for LenientI32Visitor {
+        type Value = Option<i32>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a 32-bit integer or a numeric string")
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Option<i32>, E> {
+            i32::try_from(v).map(Some).map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Option<i32>, E> {
+            i32::try_from(v).map(Some).map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Option<i32>, E> {
+            v.trim().parse().map(Some).map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Option<i32>, E> { Ok(None) }
+        fn visit_unit<E: de::Error>(self) -> Result<Option<i32>, E> { Ok(None) }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<i32>, D::Error> {
+        deserializer.deserialize_any(LenientI32Visitor)
+    }
+}
+
+/// Standard base64 with padding, as used by Java's `Base64` for binary data in JSON, e.g. chat signatures.
+pub(crate) mod base64 {
+    use {
+        serde::{
+            Deserialize as _,
+            Deserializer,
+            Serializer,
+            de,
+        },
+    };
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    pub(crate) fn encode(bytes: &[u8]) -> String {
+        let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let value = chunk.iter().enumerate().fold(0u32, |value, (idx, &byte)| value | u32::from(byte) << (16 - 8 * idx));
+            for idx in 0..4 {
+                if idx <= chunk.len() {
+                    encoded.push(char::from(ALPHABET[(value >> (18 - 6 * idx) & 0x3f) as usize]));
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+        encoded
+    }
+
+    /// Decodes base64, with or without padding. Returns `None` if the input contains other characters.
+    pub(crate) fn decode(s: &str) -> Option<Vec<u8>> {
+        let s = s.trim_end_matches('=');
+        if s.len() % 4 == 1 { return None }
+        let mut decoded = Vec::with_capacity(s.len() * 3 / 4);
+        for chunk in s.as_bytes().chunks(4) {
+            let mut value = 0u32;
+            for (idx, &c) in chunk.iter().enumerate() {
+                value |= (ALPHABET.iter().position(|&a| a == c)? as u32) << (18 - 6 * idx);
+            }
+            decoded.extend_from_slice(&value.to_be_bytes()[1..chunk.len()]);
+        }
+        Some(decoded)
+    }
+
+    pub(crate) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode(bytes))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        decode(&s).ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&s), &"a base64 string"))
+    }
+}
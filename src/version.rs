@@ -0,0 +1,78 @@
+//! This module contains the [`Version`] type, representing release versions of the game like `1.20.4`.
+
+use {
+    std::{
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::Error as _,
+    },
+};
+
+/// A release version number like `1.20.4`.
+///
+/// Snapshots and pre-releases are not supported. The string form omits the patch number if it's zero, like the game does, e.g. `1.21`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)] // fields are obvious
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    /// Creates a version from its components.
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Version {
+        Version { major, minor, patch }
+    }
+}
+
+/// The error returned when parsing a [`Version`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionParseError(pub String);
+
+impl fmt::Display for VersionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid version: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for VersionParseError {}
+
+impl FromStr for Version {
+    type Err = VersionParseError;
+
+    fn from_str(s: &str) -> Result<Version, VersionParseError> {
+        let mut parts = s.split('.').map(|part| part.parse::<u32>().map_err(|_| VersionParseError(s.to_owned())));
+        let major = parts.next().ok_or_else(|| VersionParseError(s.to_owned()))??;
+        let minor = parts.next().ok_or_else(|| VersionParseError(s.to_owned()))??;
+        let patch = parts.next().transpose()?.unwrap_or(0);
+        if parts.next().is_some() { return Err(VersionParseError(s.to_owned())) }
+        Ok(Version { major, minor, patch })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if self.patch != 0 { write!(f, ".{}", self.patch)? }
+        Ok(())
+    }
+}
+
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+}
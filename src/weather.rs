@@ -0,0 +1,156 @@
+//! This module contains types for [weather](https://minecraft.fandom.com/wiki/Weather).
+
+use {
+    std::{
+        fmt,
+        str::FromStr,
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::{
+        ticks::Ticks,
+        util::nbt_bool,
+    },
+};
+
+/// The kinds of weather, as used by the `/weather` command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)] // variants are obvious
+pub enum Weather {
+    Clear,
+    Rain,
+    Thunder,
+}
+
+impl Weather {
+    /// The argument for the `/weather` command.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weather::Clear => "clear",
+            Weather::Rain => "rain",
+            Weather::Thunder => "thunder",
+        }
+    }
+}
+
+/// The error returned when parsing a [`Weather`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeatherParseError(pub String);
+
+impl fmt::Display for WeatherParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown weather: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for WeatherParseError {}
+
+impl FromStr for Weather {
+    type Err = WeatherParseError;
+
+    fn from_str(s: &str) -> Result<Weather, WeatherParseError> {
+        match s {
+            "clear" => Ok(Weather::Clear),
+            "rain" => Ok(Weather::Rain),
+            "thunder" => Ok(Weather::Thunder),
+            _ => Err(WeatherParseError(s.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for Weather {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The weather-related fields of `level.dat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct WeatherState {
+    /// Whether it's raining (or snowing, depending on the biome).
+    #[serde(default, with = "nbt_bool")]
+    pub raining: bool,
+    /// The time until `raining` is toggled.
+    #[serde(rename = "rainTime", default)]
+    pub rain_time: Ticks,
+    /// Whether it's thundering. Only has an effect while it's also raining.
+    #[serde(default, with = "nbt_bool")]
+    pub thundering: bool,
+    /// The time until `thundering` is toggled.
+    #[serde(rename = "thunderTime", default)]
+    pub thunder_time: Ticks,
+    /// The time until the clear weather set by `/weather clear` ends. While this is positive, the other timers are paused.
+    #[serde(rename = "clearWeatherTime", default)]
+    pub clear_weather_time: Ticks,
+}
+
+impl WeatherState {
+    /// The state set by `/weather clear` with the given duration.
+    pub fn clear_for(duration: Ticks) -> WeatherState {
+        WeatherState {
+            raining: false,
+            rain_time: Ticks::ZERO,
+            thundering: false,
+            thunder_time: Ticks::ZERO,
+            clear_weather_time: duration,
+        }
+    }
+
+    /// The state set by `/weather rain` with the given duration.
+    pub fn rain_for(duration: Ticks) -> WeatherState {
+        WeatherState {
+            raining: true,
+            rain_time: duration,
+            thundering: false,
+            thunder_time: duration,
+            clear_weather_time: Ticks::ZERO,
+        }
+    }
+
+    /// The state set by `/weather thunder` with the given duration.
+    pub fn thunder_for(duration: Ticks) -> WeatherState {
+        WeatherState {
+            raining: true,
+            rain_time: duration,
+            thundering: true,
+            thunder_time: duration,
+            clear_weather_time: Ticks::ZERO,
+        }
+    }
+
+    /// The current weather.
+    pub fn current(&self) -> Weather {
+        match (self.raining, self.thundering) {
+            (false, _) => Weather::Clear,
+            (true, false) => Weather::Rain,
+            (true, true) => Weather::Thunder,
+        }
+    }
+
+    /// The time until the visible weather may change.
+    ///
+    /// Toggling `thundering` while it's not raining doesn't change the weather, so only the rain timer counts in that case.
+    pub fn ticks_until_change(&self) -> Ticks {
+        if self.clear_weather_time > Ticks::ZERO {
+            self.clear_weather_time
+        } else if self.raining {
+            self.rain_time.min(self.thunder_time)
+        } else {
+            self.rain_time
+        }
+    }
+
+    /// The `/weather` command (without leading slash) which sets the current weather for the remaining duration.
+    pub fn to_command(&self) -> String {
+        let weather = self.current();
+        let duration = match weather {
+            Weather::Clear => if self.clear_weather_time > Ticks::ZERO { self.clear_weather_time } else { self.rain_time },
+            Weather::Rain => self.rain_time,
+            Weather::Thunder => self.rain_time.min(self.thunder_time),
+        };
+        format!("weather {} {}", weather, duration)
+    }
+}
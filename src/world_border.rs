@@ -0,0 +1,163 @@
+//! This module contains the [`WorldBorder`] type, representing the [world border](https://minecraft.fandom.com/wiki/World_border) settings.
+
+use {
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::position::{
+        BlockPos,
+        Vec3,
+    },
+};
+
+/// The world border settings, as stored in `level.dat`.
+///
+/// Missing fields deserialize to the vanilla defaults.
+///
+/// # Example
+///
+/// ```
+/// use minecraft::{
+///     position::{
+///         BlockPos,
+///         Vec3,
+///     },
+///     world_border::WorldBorder,
+/// };
+///
+/// let default = serde_json::from_str::<WorldBorder>("{}")?;
+/// assert_eq!(default, WorldBorder::default());
+/// assert_eq!(default.size, 5.9999968E7);
+/// assert_eq!((default.min_x(), default.max_x()), (-29_999_984.0, 29_999_984.0));
+/// assert!(default.contains(&BlockPos::new(29_999_983, 64, -29_999_984)));
+/// assert!(!default.contains(&BlockPos::new(29_999_984, 64, 0)));
+/// assert!(!default.contains(&BlockPos::new(0, 64, -29_999_985)));
+///
+/// // off-center, with the edges at x = 995.5 and 1005.5 and z = -305 and -295
+/// let border = WorldBorder { center_x: 1000.5, center_z: -300.0, size: 10.0, size_lerp_target: 10.0, ..WorldBorder::default() };
+/// assert!(border.contains(&BlockPos::new(995, 64, -305)));
+/// assert!(border.contains(&BlockPos::new(1005, 64, -296)));
+/// assert!(!border.contains(&BlockPos::new(994, 64, -300)));
+/// assert!(!border.contains(&BlockPos::new(1006, 64, -300)));
+/// assert!(!border.contains(&BlockPos::new(1000, 64, -306)));
+/// assert!(!border.contains(&BlockPos::new(1000, 64, -295)));
+/// assert_eq!(border.distance_to_border(&Vec3::new(1000.5, 64.0, -300.0)), 5.0);
+/// assert_eq!(border.distance_to_border(&Vec3::new(1000.5, 64.0, -296.0)), 1.0);
+/// assert_eq!(border.distance_to_border(&Vec3::new(995.0, 64.0, -300.0)), -0.5);
+/// assert_eq!(border.to_commands(), [
+///     "worldborder center 1000.5 -300",
+///     "worldborder set 10",
+///     "worldborder damage buffer 5",
+///     "worldborder damage amount 0.2",
+///     "worldborder warning distance 5",
+///     "worldborder warning time 15",
+/// ]);
+///
+/// let shrinking = WorldBorder { size_lerp_target: 5.0, size_lerp_time: 2500, ..border };
+/// assert!(shrinking.is_shrinking());
+/// assert_eq!(shrinking.to_commands()[2], "worldborder set 5 3");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WorldBorder {
+    /// The x coordinate of the center.
+    #[serde(rename = "BorderCenterX")]
+    pub center_x: f64,
+    /// The z coordinate of the center.
+    #[serde(rename = "BorderCenterZ")]
+    pub center_z: f64,
+    /// The side length of the square, in blocks.
+    #[serde(rename = "BorderSize")]
+    pub size: f64,
+    /// How far outside the border players can be before taking damage.
+    #[serde(rename = "BorderSafeZone")]
+    pub safe_zone: f64,
+    /// Damage per second per block outside the safe zone.
+    #[serde(rename = "BorderDamagePerBlock")]
+    pub damage_per_block: f64,
+    /// How close to the border a player must be for the screen to be tinted red.
+    #[serde(rename = "BorderWarningBlocks")]
+    pub warning_blocks: f64,
+    /// How many seconds before a shrinking border reaches a player the screen is tinted red.
+    #[serde(rename = "BorderWarningTime")]
+    pub warning_time: f64,
+    /// The size the border is moving towards.
+    #[serde(rename = "BorderSizeLerpTarget")]
+    pub size_lerp_target: f64,
+    /// The remaining time in milliseconds until the border reaches `size_lerp_target`.
+    #[serde(rename = "BorderSizeLerpTime")]
+    pub size_lerp_time: i64,
+}
+
+impl WorldBorder {
+    /// The default and maximum border size.
+    pub const MAX_SIZE: f64 = 59_999_968.0;
+
+    /// The smallest x coordinate inside the border.
+    pub fn min_x(&self) -> f64 { self.center_x - self.size / 2.0 }
+    /// The smallest z coordinate inside the border.
+    pub fn min_z(&self) -> f64 { self.center_z - self.size / 2.0 }
+    /// The largest x coordinate inside the border.
+    pub fn max_x(&self) -> f64 { self.center_x + self.size / 2.0 }
+    /// The largest z coordinate inside the border.
+    pub fn max_z(&self) -> f64 { self.center_z + self.size / 2.0 }
+
+    /// Whether any part of the given block is inside the border.
+    pub fn contains(&self, pos: &BlockPos) -> bool {
+        let x = f64::from(pos.x);
+        let z = f64::from(pos.z);
+        x + 1.0 > self.min_x() && x < self.max_x() && z + 1.0 > self.min_z() && z < self.max_z()
+    }
+
+    /// The horizontal distance from the given point to the nearest edge of the border, negative if the point is outside.
+    pub fn distance_to_border(&self, pos: &Vec3) -> f64 {
+        (pos.x - self.min_x())
+            .min(self.max_x() - pos.x)
+            .min(pos.z - self.min_z())
+            .min(self.max_z() - pos.z)
+    }
+
+    /// Whether the border is currently getting smaller.
+    pub fn is_shrinking(&self) -> bool {
+        self.size_lerp_time > 0 && self.size_lerp_target < self.size
+    }
+
+    /// Whether the border is currently getting larger.
+    pub fn is_growing(&self) -> bool {
+        self.size_lerp_time > 0 && self.size_lerp_target > self.size
+    }
+
+    /// The `/worldborder` commands (without leading slashes) which reproduce these settings on a server.
+    pub fn to_commands(&self) -> Vec<String> {
+        let mut commands = vec![
+            format!("worldborder center {} {}", self.center_x, self.center_z),
+            format!("worldborder set {}", self.size),
+        ];
+        if self.size_lerp_time > 0 && self.size_lerp_target != self.size {
+            commands.push(format!("worldborder set {} {}", self.size_lerp_target, (self.size_lerp_time + 999) / 1000));
+        }
+        commands.push(format!("worldborder damage buffer {}", self.safe_zone));
+        commands.push(format!("worldborder damage amount {}", self.damage_per_block));
+        commands.push(format!("worldborder warning distance {}", self.warning_blocks.round()));
+        commands.push(format!("worldborder warning time {}", self.warning_time.round()));
+        commands
+    }
+}
+
+impl Default for WorldBorder {
+    fn default() -> WorldBorder {
+        WorldBorder {
+            center_x: 0.0,
+            center_z: 0.0,
+            size: WorldBorder::MAX_SIZE,
+            safe_zone: 5.0,
+            damage_per_block: 0.2,
+            warning_blocks: 5.0,
+            warning_time: 15.0,
+            size_lerp_target: WorldBorder::MAX_SIZE,
+            size_lerp_time: 0,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! This module contains types for the [daylight cycle](https://minecraft.fandom.com/wiki/Daylight_cycle).
+
+use {
+    std::fmt,
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+};
+
+/// The number of ticks in a Minecraft day.
+pub const TICKS_PER_DAY: i64 = 24000;
+
+/// A tick counter like `DayTime` in `level.dat`, where 0 is sunrise (06:00) on the first day.
+///
+/// Serializes as a plain integer. Displays like `Day 134, 19:30`, where the day is counted from 0 like `/time query day` does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct DayTime(pub i64);
+
+/// A part of the day, as set by the `/time set` presets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayPhase {
+    /// From 1000 (`/time set day`) until noon.
+    Day,
+    /// From 6000 (`/time set noon`) until sunset.
+    Noon,
+    /// From 12000 until night.
+    Sunset,
+    /// From 13000 (`/time set night`) until midnight.
+    Night,
+    /// From 18000 (`/time set midnight`) until sunrise.
+    Midnight,
+    /// From 23000 until 1000 on the next day.
+    Sunrise,
+}
+
+impl DayPhase {
+    /// The time of day at which this phase starts.
+    pub fn start(&self) -> i64 {
+        match self {
+            DayPhase::Day => 1000,
+            DayPhase::Noon => 6000,
+            DayPhase::Sunset => 12000,
+            DayPhase::Night => 13000,
+            DayPhase::Midnight => 18000,
+            DayPhase::Sunrise => 23000,
+        }
+    }
+}
+
+impl DayTime {
+    /// The time within the current day, in `0..24000`.
+    pub fn time_of_day(&self) -> i64 {
+        self.0.rem_euclid(TICKS_PER_DAY)
+    }
+
+    /// The number of full days that have passed.
+    pub fn day_count(&self) -> i64 {
+        self.0.div_euclid(TICKS_PER_DAY)
+    }
+
+    /// The current part of the day.
+    pub fn phase(&self) -> DayPhase {
+        match self.time_of_day() {
+            0..=999 => DayPhase::Sunrise,
+            1000..=5999 => DayPhase::Day,
+            6000..=11999 => DayPhase::Noon,
+            12000..=12999 => DayPhase::Sunset,
+            13000..=17999 => DayPhase::Night,
+            18000..=22999 => DayPhase::Midnight,
+            _ => DayPhase::Sunrise,
+        }
+    }
+
+    /// Whether players can sleep in a bed at this time.
+    ///
+    /// Sleeping is possible from 12542 to 23459, or from 12010 to 23991 while it's raining. Thunderstorms allow sleeping at any time, which is not checked here.
+    pub fn is_night_for_sleeping(&self, raining: bool) -> bool {
+        let range = if raining { 12010..=23991 } else { 12542..=23459 };
+        range.contains(&self.time_of_day())
+    }
+
+    /// The time shown by an in-game clock, as hours and minutes.
+    pub fn clock_time(&self) -> (u8, u8) {
+        let time = self.time_of_day();
+        let hours = (time / 1000 + 6) % 24;
+        let minutes = time % 1000 * 60 / 1000;
+        (hours as u8, minutes as u8)
+    }
+
+    /// The time of day on day 0 corresponding to the given in-game clock time.
+    ///
+    /// Values out of range wrap around.
+    pub fn from_clock(hours: u8, minutes: u8) -> DayTime {
+        let minutes = i64::from(hours) * 60 + i64::from(minutes);
+        DayTime(((minutes - 6 * 60) * 1000 / 60).rem_euclid(TICKS_PER_DAY))
+    }
+}
+
+impl fmt::Display for DayTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (hours, minutes) = self.clock_time();
+        write!(f, "Day {}, {:02}:{:02}", self.day_count(), hours, minutes)
+    }
+}
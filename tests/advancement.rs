@@ -0,0 +1,72 @@
+//! Reads advancements in the form generated for the vanilla data pack of 1.21, covering a tab root, challenges with rewards, a hidden advancement, and alternative requirements.
+
+use minecraft::{
+    advancement::{
+        Advancement,
+        AdvancementDisplay,
+        AdvancementRewards,
+        FrameType,
+    },
+    identifier::Identifier,
+};
+
+fn read(file: &str) -> Advancement {
+    serde_json::from_str(file).expect("failed to read advancement")
+}
+
+fn display(advancement: &Advancement) -> &AdvancementDisplay {
+    advancement.display.as_ref().expect("missing display")
+}
+
+fn id(s: &str) -> Identifier {
+    s.parse().expect("invalid identifier")
+}
+
+#[test]
+fn root() {
+    let advancement = read(include_str!("fixtures/advancement.story.root.json"));
+    assert_eq!(advancement.parent, None);
+    assert!(advancement.sends_telemetry_event);
+    let display = display(&advancement);
+    assert_eq!(display.background, Some(id("minecraft:textures/gui/advancements/backgrounds/stone.png")));
+    assert_eq!(display.icon.id, id("minecraft:grass_block"));
+    assert_eq!(display.title.translate(), Some("advancements.story.root.title"));
+    assert_eq!(display.frame, FrameType::Task);
+    assert!(!display.show_toast);
+    assert!(!display.announce_to_chat);
+    assert!(!display.hidden);
+    assert_eq!(advancement.rewards, None);
+}
+
+#[test]
+fn challenge() {
+    let advancement = read(include_str!("fixtures/advancement.nether.return_to_sender.json"));
+    assert_eq!(advancement.parent, Some(id("minecraft:nether/root")));
+    assert_eq!(advancement.criteria["killed_ghast"]["trigger"], "minecraft:player_killed_entity");
+    let display = display(&advancement);
+    assert_eq!(display.frame, FrameType::Challenge);
+    assert_eq!(display.background, None);
+    assert!(display.show_toast);
+    assert!(display.announce_to_chat);
+    assert!(!display.hidden);
+    assert_eq!(advancement.rewards, Some(AdvancementRewards { experience: 50, ..AdvancementRewards::default() }));
+}
+
+#[test]
+fn hidden() {
+    let advancement = read(include_str!("fixtures/advancement.adventure.arbalistic.json"));
+    let display = display(&advancement);
+    assert!(display.hidden);
+    assert_eq!(display.frame, FrameType::Challenge);
+    assert_eq!(advancement.rewards.as_ref().map(|rewards| rewards.experience), Some(85));
+}
+
+#[test]
+fn requirements() {
+    let advancement = read(include_str!("fixtures/advancement.story.obtain_armor.json"));
+    assert_eq!(advancement.criteria.len(), 4);
+    assert_eq!(advancement.effective_requirements(), [["iron_helmet", "iron_chestplate", "iron_leggings", "iron_boots"]]);
+    // any one piece of armor completes the advancement
+    assert!(advancement.is_completed(|name| name == "iron_boots"));
+    assert!(!advancement.is_completed(|_| false));
+}
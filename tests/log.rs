@@ -0,0 +1,13 @@
+//! Parses a server log covering vanilla, Paper, and Forge prefixes, stack traces, and `[Not Secure]` chat, and compares each line to its expected parse.
+
+use minecraft::log::LogLine;
+
+#[test]
+fn latest_log() {
+    let log = include_str!("fixtures/latest.log");
+    let expected = include_str!("fixtures/latest.expected");
+    assert_eq!(log.lines().count(), expected.lines().count(), "fixture and expected parses have different lengths");
+    for (idx, (line, expected)) in log.lines().zip(expected.lines()).enumerate() {
+        assert_eq!(format!("{:?}", LogLine::parse(line)), expected, "line {}: {}", idx + 1, line);
+    }
+}
@@ -0,0 +1,83 @@
+//! Reads `pack.mcmeta` files of a vanilla data pack, an older resource pack, and a pack using the `min_format` and `max_format` fields added in 1.21.9, and checks what writing them back produces.
+
+use {
+    minecraft::pack::{
+        PackFormat,
+        PackMeta,
+        PackVersion,
+    },
+    serde_json::{
+        Value,
+        json,
+    },
+};
+
+/// Reads the file, writes it back, and checks that reading the output again gives the same output. Returns the parsed file and the output.
+fn round_trip(file: &str) -> (PackMeta, Value) {
+    let meta = serde_json::from_str::<PackMeta>(file).expect("failed to read pack.mcmeta");
+    let written = serde_json::to_value(&meta).expect("failed to write pack.mcmeta");
+    let reread = serde_json::from_value::<PackMeta>(written.clone()).expect("failed to read written pack.mcmeta");
+    assert_eq!(serde_json::to_value(&reread).expect("failed to write pack.mcmeta"), written);
+    (meta, written)
+}
+
+#[test]
+fn vanilla_data_pack() {
+    let (meta, written) = round_trip(include_str!("fixtures/pack.data.mcmeta"));
+    assert!(meta.pack.supports(18));
+    assert!(!meta.pack.supports(26));
+    // the `features` section is not represented
+    assert_eq!(written, json!({
+        "pack": {
+            "description": {"translate": "dataPack.bundle.description"},
+            "pack_format": 18,
+        },
+    }));
+}
+
+#[test]
+fn old_resource_pack() {
+    let (meta, written) = round_trip(include_str!("fixtures/pack.resource.mcmeta"));
+    assert!(meta.pack.supports(3));
+    assert_eq!(meta.pack.description.to_legacy(true), "§6Faithful 32x32 §7for 1.12");
+    // the `language` section is not represented
+    assert_eq!(written, json!({
+        "pack": {
+            "description": {"text": "§6Faithful 32x32 §7for 1.12"},
+            "pack_format": 3,
+        },
+    }));
+}
+
+#[test]
+fn min_max_format() {
+    let (meta, written) = round_trip(include_str!("fixtures/pack.overlays.mcmeta"));
+    assert_eq!(meta.pack.supported_formats, Some(PackFormat::Range { min: 81, max: 88 }));
+    assert_eq!(meta.pack.min_format, Some(PackVersion { major: 81, minor: 0 }));
+    assert_eq!(meta.pack.max_format, Some(PackVersion { major: 88, minor: 1 }));
+    assert!(meta.pack.supports(81));
+    assert!(meta.pack.supports(88));
+    assert!(!meta.pack.supports(89));
+    let overlays = meta.overlays.expect("missing overlays");
+    assert_eq!(overlays.entries[0].formats, Some(PackFormat::Range { min: 81, max: 81 }));
+    assert_eq!(overlays.entries[1].min_format, Some(PackVersion { major: 82, minor: 0 }));
+    // ranges are written as arrays, and versions with minor version 0 as a single number
+    assert_eq!(written, json!({
+        "pack": {
+            "description": {"text": "", "extra": [{"text": "Wurstmineberg", "color": "gold"}, {"text": " data pack"}]},
+            "pack_format": 81,
+            "supported_formats": [81, 88],
+            "min_format": 81,
+            "max_format": [88, 1],
+        },
+        "filter": {
+            "block": [{"namespace": "minecraft", "path": "recipe/.*_from_.*"}],
+        },
+        "overlays": {
+            "entries": [
+                {"formats": [81, 81], "directory": "overlay_1_21_7"},
+                {"formats": [82, 88], "min_format": 82, "max_format": [88, 1], "directory": "overlay_1_21_9"},
+            ],
+        },
+    }));
+}
@@ -0,0 +1,47 @@
+//! Reads `server.properties` files as written by vanilla and Paper 1.21 and checks that writing them back only drops the timestamp comment.
+
+use minecraft::{
+    difficulty::Difficulty,
+    server_properties::{
+        LevelSeed,
+        ServerProperties,
+    },
+};
+
+/// Reads the file, writes it back, and compares the result to the input without its second line, the timestamp comment.
+fn round_trip(file: &str) -> ServerProperties {
+    let properties = ServerProperties::from_reader(file.as_bytes()).expect("failed to read server.properties");
+    let mut buf = Vec::default();
+    properties.to_writer(&mut buf).expect("failed to write server.properties");
+    let expected = file.lines().enumerate().filter(|&(idx, _)| idx != 1).map(|(_, line)| format!("{}\n", line)).collect::<String>();
+    assert_eq!(String::from_utf8(buf).expect("server.properties is not UTF-8"), expected);
+    properties
+}
+
+#[test]
+fn vanilla() {
+    let properties = round_trip(include_str!("fixtures/server.properties"));
+    assert_eq!(properties.difficulty, Some(Difficulty::Easy));
+    assert_eq!(properties.level_type.as_deref(), Some("minecraft:normal"));
+    assert_eq!(properties.level_seed, Some(LevelSeed::Text(String::default())));
+    assert_eq!(properties.max_players, Some(20));
+    assert_eq!(properties.unknown.keys().collect::<Vec<_>>(), ["bug-report-link"]);
+}
+
+#[test]
+fn paper() {
+    let properties = round_trip(include_str!("fixtures/server.paper.properties"));
+    assert_eq!(properties.motd.as_deref(), Some("§6Wurstmineberg §7— §aPaper"));
+    assert_eq!(properties.motd_chat().map(|motd| motd.to_legacy(true)).as_deref(), Some("§6Wurstmineberg §7— §aPaper"));
+    assert_eq!(properties.level_seed, Some(LevelSeed::Text("wurstmineberg".to_owned())));
+    assert_eq!(properties.max_tick_time, Some(-1));
+    // values which can't be parsed are kept as they are
+    assert_eq!(properties.enable_rcon, None);
+    assert_eq!(properties.max_players, None);
+    assert_eq!(properties.unknown.iter().map(|(key, value)| (&**key, &**value)).collect::<Vec<_>>(), [
+        ("bug-report-link", ""),
+        ("debug", "false"),
+        ("enable-rcon", "yes"),
+        ("max-players", ""),
+    ]);
+}
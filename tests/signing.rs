@@ -0,0 +1,87 @@
+//! Reads the evidence from an exported chat report and checks the bytes each message was signed over.
+//!
+//! The fixture was generated with a throwaway key in `chat_report.pub.pem` rather than Mojang-issued session keys, so the signatures can be checked here.
+
+use {
+    std::convert::TryFrom as _,
+    rsa::{
+        RsaPublicKey,
+        pkcs1v15::{
+            Signature,
+            VerifyingKey,
+        },
+        pkcs8::DecodePublicKey as _,
+        signature::Verifier as _,
+    },
+    serde::Deserialize,
+    sha2::{
+        Digest as _,
+        Sha256,
+    },
+    minecraft::chat::signing::{
+        ReportedChatMessage,
+        signable_message_bytes,
+    },
+};
+
+#[derive(Deserialize)]
+struct Report {
+    report: ReportDetails,
+}
+
+#[derive(Deserialize)]
+struct ReportDetails {
+    evidence: Evidence,
+}
+
+#[derive(Deserialize)]
+struct Evidence {
+    messages: Vec<ReportedChatMessage>,
+}
+
+/// SHA-256 digests of the signable bytes of each message in the fixture.
+const DIGESTS: [&str; 4] = [
+    "267cffbbd4b32a44380b4fa608d730214b361f0a71d6dc621b3e77cd10c9c9f9",
+    "684b4446ab0a2d349c01c479c6b2cbcd85da4f7742c1825defe669816239a43a",
+    "9a36adaa0fe970464b77203a0724c2f465358d6d982a5945871db60f3e4f2042",
+    "e9606597759030eb14a2c46cda2aad4b8304af0d24bac880fc86db5843e5c030",
+];
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn messages() -> Vec<ReportedChatMessage> {
+    serde_json::from_str::<Report>(include_str!("fixtures/chat_report.json")).expect("failed to parse chat report").report.evidence.messages
+}
+
+#[test]
+fn messages_round_trip() {
+    let report = serde_json::from_str::<serde_json::Value>(include_str!("fixtures/chat_report.json")).expect("failed to parse chat report");
+    let messages = messages();
+    assert_eq!(messages.len(), 4);
+    for (message, json) in messages.iter().zip(report["report"]["evidence"]["messages"].as_array().expect("messages is not an array")) {
+        assert_eq!(&serde_json::to_value(message).expect("failed to serialize message"), json);
+    }
+}
+
+#[test]
+fn signable_bytes() {
+    let expected = include_str!("fixtures/chat_report.signable.hex");
+    let messages = messages();
+    assert_eq!(messages.len(), expected.lines().count());
+    for (idx, ((message, expected), digest)) in messages.iter().zip(expected.lines()).zip(&DIGESTS).enumerate() {
+        let bytes = signable_message_bytes(&message.link(), &message.body());
+        assert_eq!(hex(&bytes), expected, "message {}", idx);
+        assert_eq!(hex(&Sha256::digest(&bytes)), *digest, "message {}", idx);
+    }
+}
+
+#[test]
+fn signatures_verify() {
+    let key = VerifyingKey::<Sha256>::new(RsaPublicKey::from_public_key_pem(include_str!("fixtures/chat_report.pub.pem")).expect("failed to parse public key"));
+    for (idx, message) in messages().iter().enumerate() {
+        let signature = Signature::try_from(&message.message_signature.0[..]).expect("failed to parse signature");
+        key.verify(&signable_message_bytes(&message.link(), &message.body()), &signature).unwrap_or_else(|e| panic!("message {}: {}", idx, e));
+    }
+}